@@ -8,10 +8,19 @@ pub enum AvailableLanguage {
     Scala,
     Swift,
     Typescript,
-    #[cfg(feature = "go")]
     Go,
 }
 
+/// How errors and warnings found while parsing are reported.
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
+pub enum MessageFormat {
+    /// rustc style diagnostics on stderr
+    #[default]
+    Human,
+    /// One JSON object per line on stdout
+    Json,
+}
+
 #[derive(clap::Parser)]
 #[command(
     version,
@@ -50,7 +59,6 @@ pub struct Args {
     #[arg(long)]
     pub scala_module_name: Option<String>,
 
-    #[cfg(feature = "go")]
     /// Go package name
     #[arg(long)]
     pub go_package: Option<String>,
@@ -73,6 +81,10 @@ pub struct Args {
     /// Optional restrict to target_os
     #[arg(short, long, num_args = 1..)]
     pub target_os: Option<Vec<String>>,
+
+    /// Format of the errors and warnings found while parsing
+    #[arg(long, value_enum, default_value_t)]
+    pub message_format: MessageFormat,
 }
 
 #[derive(Debug, Clone, Copy, clap::Subcommand)]
@@ -11,6 +11,47 @@ use std::{
 
 const DEFAULT_CONFIG_FILE_NAME: &str = "typeshare.toml";
 
+/// How 64 bit integers are represented in a language. When it isn't set, the
+/// language's default is used: `reject` for Typescript and `number` otherwise.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Int64Strategy {
+    Reject,
+    Number,
+    BigInt,
+    String,
+}
+
+impl From<Int64Strategy> for typeshare_core::language::Int64Strategy {
+    fn from(strategy: Int64Strategy) -> Self {
+        match strategy {
+            Int64Strategy::Reject => Self::Reject,
+            Int64Strategy::Number => Self::Number,
+            Int64Strategy::BigInt => Self::BigInt,
+            Int64Strategy::String => Self::String,
+        }
+    }
+}
+
+/// The oldest Go release the generated code must compile with, written like `"1.18"`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(try_from = "String", into = "String")]
+pub struct GoVersion(pub typeshare_core::language::GoVersion);
+
+impl TryFrom<String> for GoVersion {
+    type Error = typeshare_core::language::InvalidGoVersion;
+
+    fn try_from(version: String) -> Result<Self, Self::Error> {
+        version.parse().map(Self)
+    }
+}
+
+impl From<GoVersion> for String {
+    fn from(version: GoVersion) -> Self {
+        version.0.to_string()
+    }
+}
+
 #[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(default)]
 pub struct KotlinParams {
@@ -18,6 +59,7 @@ pub struct KotlinParams {
     pub module_name: String,
     pub prefix: String,
     pub type_mappings: HashMap<String, String>,
+    pub int64_strategy: Option<Int64Strategy>,
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -26,6 +68,7 @@ pub struct ScalaParams {
     pub package: String,
     pub module_name: String,
     pub type_mappings: HashMap<String, String>,
+    pub int64_strategy: Option<Int64Strategy>,
 }
 
 #[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -37,22 +80,32 @@ pub struct SwiftParams {
     /// The constraints to apply to `CodableVoid`.
     pub codablevoid_constraints: Vec<String>,
     pub type_mappings: HashMap<String, String>,
+    pub int64_strategy: Option<Int64Strategy>,
+    /// The Swift module each crate is generated into, for multi file output.
+    pub module_names: HashMap<String, String>,
+    /// The Swift module that `Codable.swift` is added to, for multi file output.
+    pub codable_module: Option<String>,
 }
 
 #[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
 #[serde(default)]
 pub struct TypeScriptParams {
     pub type_mappings: HashMap<String, String>,
+    pub int64_strategy: Option<Int64Strategy>,
 }
 
 #[derive(Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
 #[serde(default)]
-#[cfg(feature = "go")]
 pub struct GoParams {
     pub package: String,
+    /// Import path of the Go module holding a package per crate, for multi file output.
+    pub module_root: String,
     pub uppercase_acronyms: Vec<String>,
     pub no_pointer_slice: bool,
     pub type_mappings: HashMap<String, String>,
+    pub int64_strategy: Option<Int64Strategy>,
+    /// Generic types are rejected when this is older than Go 1.18.
+    pub min_version: Option<GoVersion>,
 }
 
 /// The parameters that are used to configure the behaviour of typeshare
@@ -64,7 +117,6 @@ pub(crate) struct Config {
     pub typescript: TypeScriptParams,
     pub kotlin: KotlinParams,
     pub scala: ScalaParams,
-    #[cfg(feature = "go")]
     pub go: GoParams,
     #[serde(skip)]
     pub target_os: Vec<String>,
@@ -160,7 +212,6 @@ mod test {
         assert_eq!(config.kotlin.type_mappings["DateTime"], "String");
         assert_eq!(config.scala.type_mappings["DateTime"], "String");
         assert_eq!(config.typescript.type_mappings["DateTime"], "string");
-        #[cfg(feature = "go")]
         assert_eq!(config.go.type_mappings["DateTime"], "string");
     }
 
@@ -190,11 +241,36 @@ mod test {
         assert_eq!(config.swift.prefix, "test");
     }
     #[test]
-    #[cfg(feature = "go")]
     fn go_package_test() {
         let path = config_file_path("go_config.toml");
         let config = load_config(Some(&path)).unwrap();
 
         assert_eq!(config.go.package, "testPackage");
+        assert_eq!(
+            config.go.min_version.map(|v| v.0.to_string()).as_deref(),
+            Some("1.17")
+        );
+    }
+
+    #[test]
+    fn int64_strategy_test() {
+        let path = config_file_path("int64_config.toml");
+        let config = load_config(Some(&path)).unwrap();
+
+        assert_eq!(
+            config.typescript.int64_strategy,
+            Some(Int64Strategy::BigInt)
+        );
+        assert_eq!(config.swift.int64_strategy, Some(Int64Strategy::String));
+        assert_eq!(config.kotlin.int64_strategy, None);
+    }
+
+    #[test]
+    fn swift_modules_test() {
+        let path = config_file_path("swift_modules_config.toml");
+        let config = load_config(Some(&path)).unwrap();
+
+        assert_eq!(config.swift.module_names["core_types"], "CoreKit");
+        assert_eq!(config.swift.codable_module.as_deref(), Some("CoreKit"));
     }
 }
@@ -0,0 +1,255 @@
+//! Reporting of parse errors and warnings, either as rustc style diagnostics or
+//! as JSON for tools.
+use std::fmt::Write;
+
+use serde::Serialize;
+use typeshare_core::parser::{ErrorInfo, SourceLocation, WarningInfo};
+
+/// How serious a diagnostic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Error,
+    Warning,
+}
+
+/// Where a diagnostic was found in its file. Lines and columns start at 1.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl From<SourceLocation> for Span {
+    fn from(location: SourceLocation) -> Self {
+        Self {
+            line: location.line,
+            column: location.column,
+            end_line: location.end_line,
+            end_column: location.end_column,
+        }
+    }
+}
+
+/// A parse error or warning, ready to be reported.
+#[derive(Debug, Serialize)]
+pub struct Diagnostic<'a> {
+    pub level: Level,
+    /// The stable code of the error or warning, e.g. `E0004`.
+    pub code: &'static str,
+    pub message: String,
+    pub file: &'a str,
+    pub span: Option<Span>,
+    pub type_name: Option<&'a str>,
+    pub field_name: Option<&'a str>,
+}
+
+impl<'a> From<&'a ErrorInfo> for Diagnostic<'a> {
+    fn from(error: &'a ErrorInfo) -> Self {
+        Self {
+            level: Level::Error,
+            code: error.error.code(),
+            message: error.error.to_string(),
+            file: &error.file_name,
+            span: error.location.map(Into::into),
+            type_name: error.type_name.as_deref(),
+            field_name: error.field_name.as_deref(),
+        }
+    }
+}
+
+impl<'a> From<&'a WarningInfo> for Diagnostic<'a> {
+    fn from(warning: &'a WarningInfo) -> Self {
+        Self {
+            level: Level::Warning,
+            code: warning.warning.code(),
+            message: warning.warning.to_string(),
+            file: &warning.file_name,
+            span: warning.location.map(Into::into),
+            type_name: warning.type_name.as_deref(),
+            field_name: None,
+        }
+    }
+}
+
+impl Diagnostic<'_> {
+    /// Render the diagnostic the way rustc does, with the line of `source` it
+    /// was found on when the source is available.
+    pub fn render(&self, source: Option<&str>) -> String {
+        let mut output = String::new();
+        // Writing to a `String` can't fail
+        let _ = self.write_human(&mut output, source);
+        output
+    }
+
+    /// Render the diagnostic as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("diagnostics always serialize")
+    }
+
+    fn write_human(&self, output: &mut String, source: Option<&str>) -> std::fmt::Result {
+        let level = match self.level {
+            Level::Error => "error",
+            Level::Warning => "warning",
+        };
+        writeln!(output, "{level}[{}]: {}", self.code, self.message)?;
+
+        let Some(span) = self.span else {
+            return writeln!(output, " --> {}", self.file);
+        };
+
+        let line_number = span.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        writeln!(
+            output,
+            "{gutter}--> {}:{}:{}",
+            self.file, span.line, span.column
+        )?;
+
+        let line = source.and_then(|source| source.lines().nth(span.line.checked_sub(1)?));
+        if let Some(line) = line {
+            // Spans over several lines are underlined up to the end of the first one
+            let end_column = if span.end_line == span.line {
+                span.end_column
+            } else {
+                line.chars().count() + 1
+            };
+            // Keep tabs so the underline lines up with the code above it
+            let indent = line
+                .chars()
+                .take(span.column.saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+
+            writeln!(output, "{gutter} |")?;
+            writeln!(output, "{line_number} | {line}")?;
+            writeln!(
+                output,
+                "{gutter} | {indent}{}",
+                "^".repeat(end_column.saturating_sub(span.column).max(1))
+            )?;
+        }
+
+        match (self.type_name, self.field_name) {
+            (Some(type_name), Some(field_name)) => {
+                writeln!(output, "{gutter} |")?;
+                writeln!(
+                    output,
+                    "{gutter} = note: in `{field_name}` of `{type_name}`"
+                )?;
+            }
+            (Some(type_name), None) => {
+                writeln!(output, "{gutter} |")?;
+                writeln!(output, "{gutter} = note: in `{type_name}`")?;
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use typeshare_core::parser::{
+        ErrorInfo, ParseError, ParseWarning, SourceLocation, WarningInfo,
+    };
+
+    use super::Diagnostic;
+
+    fn error_info(location: Option<SourceLocation>, field_name: Option<&str>) -> ErrorInfo {
+        ErrorInfo {
+            file_name: "src/lib.rs".into(),
+            error: ParseError::UnsupportedType("Foo".into()),
+            location,
+            type_name: Some("User".into()),
+            field_name: field_name.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn renders_snippet() {
+        let source = "#[typeshare]\npub struct User {\n    pub name: Foo,\n}\n";
+        let error = error_info(
+            Some(SourceLocation {
+                line: 3,
+                column: 15,
+                end_line: 3,
+                end_column: 18,
+            }),
+            Some("name"),
+        );
+
+        assert_eq!(
+            Diagnostic::from(&error).render(Some(source)),
+            "error[E0004]: unsupported type encountered: Foo
+ --> src/lib.rs:3:15
+  |
+3 |     pub name: Foo,
+  |               ^^^
+  |
+  = note: in `name` of `User`
+"
+        );
+    }
+
+    #[test]
+    fn renders_without_source() {
+        let error = error_info(
+            Some(SourceLocation {
+                line: 12,
+                column: 12,
+                end_line: 12,
+                end_column: 16,
+            }),
+            None,
+        );
+
+        assert_eq!(
+            Diagnostic::from(&error).render(None),
+            "error[E0004]: unsupported type encountered: Foo
+  --> src/lib.rs:12:12
+   |
+   = note: in `User`
+"
+        );
+    }
+
+    #[test]
+    fn renders_error_json() {
+        let error = error_info(
+            Some(SourceLocation {
+                line: 3,
+                column: 15,
+                end_line: 3,
+                end_column: 18,
+            }),
+            Some("name"),
+        );
+
+        assert_eq!(
+            Diagnostic::from(&error).to_json(),
+            r#"{"level":"error","code":"E0004","message":"unsupported type encountered: Foo","file":"src/lib.rs","span":{"line":3,"column":15,"end_line":3,"end_column":18},"type_name":"User","field_name":"name"}"#
+        );
+    }
+
+    #[test]
+    fn renders_warning_json() {
+        let warning = WarningInfo {
+            file_name: "src/lib.rs".into(),
+            warning: ParseWarning::SerializedAsMismatch {
+                type_ident: "Id".into(),
+                serialized_as: "String".into(),
+            },
+            location: None,
+            type_name: Some("Id".into()),
+        };
+
+        assert_eq!(
+            Diagnostic::from(&warning).to_json(),
+            r#"{"level":"warning","code":"W0001","message":"Id is serialized as another type by serde than `serialized_as = \"String\"`","file":"src/lib.rs","span":null,"type_name":"Id","field_name":null}"#
+        );
+    }
+}
@@ -4,6 +4,7 @@
 
 mod args;
 mod config;
+mod diagnostics;
 mod parse;
 mod writer;
 
@@ -20,20 +21,19 @@ use flexi_logger::AdaptiveFormat;
 use ignore::{overrides::OverrideBuilder, types::TypesBuilder, WalkBuilder};
 use log::{error, info};
 use rayon::iter::ParallelBridge;
-#[cfg(feature = "go")]
-use typeshare_core::language::Go;
 use typeshare_core::{
     language::{
-        CrateName, GenericConstraints, Kotlin, Language, Scala, SupportedLanguage, Swift,
-        TypeScript,
+        CrateName, GenericConstraints, Go, Int64Strategy, Kotlin, Language, Scala,
+        SupportedLanguage, Swift, TypeScript,
     },
     parser::ParsedData,
 };
 
 use crate::{
-    args::{Args, Command},
+    args::{Args, Command, MessageFormat},
     config::Config,
-    parse::{all_types, parse_input, parser_inputs},
+    diagnostics::{Diagnostic, Level},
+    parse::{all_types, parse_input, parser_inputs, share_structs},
     writer::{write_generated, Output},
 };
 
@@ -79,7 +79,6 @@ fn main() -> anyhow::Result<()> {
             args::AvailableLanguage::Scala => SupportedLanguage::Scala,
             args::AvailableLanguage::Swift => SupportedLanguage::Swift,
             args::AvailableLanguage::Typescript => SupportedLanguage::TypeScript,
-            #[cfg(feature = "go")]
             args::AvailableLanguage::Go => SupportedLanguage::Go,
         },
     };
@@ -139,13 +138,18 @@ fn main() -> anyhow::Result<()> {
     // and implement a `ParallelVisitor` that builds up the mapping of parsed
     // data. That way both walking and parsing are in parallel.
     // https://docs.rs/ignore/latest/ignore/struct.WalkParallel.html
-    let crate_parsed_data = parse_input(
+    let mut crate_parsed_data = parse_input(
         parser_inputs(walker_builder, language_type, multi_file).par_bridge(),
         &ignored_types,
         multi_file,
         &target_os,
     )?;
 
+    // Flattened fields may refer to structs defined in other crates
+    if multi_file {
+        share_structs(&mut crate_parsed_data);
+    }
+
     // Collect all the types into a map of the file name they
     // belong too and the list of type names. Used for generating
     // imports in generated files.
@@ -155,7 +159,7 @@ fn main() -> anyhow::Result<()> {
         HashMap::new()
     };
 
-    check_parse_errors(&crate_parsed_data)?;
+    report_diagnostics(&crate_parsed_data, options.message_format)?;
 
     write_generated(
         destination,
@@ -184,6 +188,13 @@ fn language(
             ),
             multi_file,
             codablevoid_constraints: config.swift.codablevoid_constraints,
+            module_names: config.swift.module_names,
+            codable_module: config.swift.codable_module,
+            int64_strategy: config
+                .swift
+                .int64_strategy
+                .map(Into::into)
+                .unwrap_or_default(),
             ..Default::default()
         }),
         SupportedLanguage::Kotlin => Box::new(Kotlin {
@@ -191,30 +202,42 @@ fn language(
             module_name: config.kotlin.module_name,
             prefix: config.kotlin.prefix,
             type_mappings: config.kotlin.type_mappings,
+            int64_strategy: config
+                .kotlin
+                .int64_strategy
+                .map(Into::into)
+                .unwrap_or_default(),
             ..Default::default()
         }),
         SupportedLanguage::Scala => Box::new(Scala {
             package: config.scala.package,
             module_name: config.scala.module_name,
             type_mappings: config.scala.type_mappings,
+            int64_strategy: config
+                .scala
+                .int64_strategy
+                .map(Into::into)
+                .unwrap_or_default(),
             ..Default::default()
         }),
         SupportedLanguage::TypeScript => Box::new(TypeScript {
             type_mappings: config.typescript.type_mappings,
+            int64_strategy: config
+                .typescript
+                .int64_strategy
+                .map_or(Int64Strategy::Reject, Into::into),
             ..Default::default()
         }),
-        #[cfg(feature = "go")]
         SupportedLanguage::Go => Box::new(Go {
             package: config.go.package,
+            module_root: config.go.module_root,
             type_mappings: config.go.type_mappings,
             uppercase_acronyms: config.go.uppercase_acronyms,
             no_pointer_slice: config.go.no_pointer_slice,
+            int64_strategy: config.go.int64_strategy.map(Into::into).unwrap_or_default(),
+            min_version: config.go.min_version.map(|v| v.0).unwrap_or_default(),
             ..Default::default()
         }),
-        #[cfg(not(feature = "go"))]
-        SupportedLanguage::Go => {
-            panic!("go support is currently experimental and must be enabled as a feature flag for typeshare-cli")
-        }
     }
 }
 
@@ -244,12 +267,12 @@ fn override_configuration(mut config: Config, options: &Args) -> anyhow::Result<
         config.scala.module_name = scala_module_name.to_string();
     }
 
-    #[cfg(feature = "go")]
-    {
-        if let Some(go_package) = options.go_package.as_ref() {
-            config.go.package = go_package.to_string();
-        }
-        assert_go_package_present(&config)?;
+    if let Some(go_package) = options.go_package.as_ref() {
+        config.go.package = go_package.to_string();
+    }
+
+    if matches!(options.language, Some(args::AvailableLanguage::Go)) {
+        assert_go_package_present(&config, options.output.folder.is_some())?;
     }
 
     config.target_os = options.target_os.as_deref().unwrap_or_default().to_vec();
@@ -257,19 +280,28 @@ fn override_configuration(mut config: Config, options: &Args) -> anyhow::Result<
     Ok(config)
 }
 
-/// Prints out all parsing errors if any and returns Err.
-fn check_parse_errors(parsed_crates: &BTreeMap<CrateName, ParsedData>) -> anyhow::Result<()> {
-    let mut errors_encountered = false;
-    for data in parsed_crates
+/// Prints out all parsing errors and warnings in the given format and returns
+/// Err if there were any errors.
+fn report_diagnostics(
+    parsed_crates: &BTreeMap<CrateName, ParsedData>,
+    message_format: MessageFormat,
+) -> anyhow::Result<()> {
+    let errors = parsed_crates
+        .values()
+        .flat_map(|data| data.errors.iter().map(Diagnostic::from));
+    let warnings = parsed_crates
         .values()
-        .filter(|parsed_data| !parsed_data.errors.is_empty())
-    {
-        errors_encountered = true;
-        for error in &data.errors {
-            error!(
-                "Parsing error: \"{}\" in file \"{}\"",
-                error.error, error.file_name
-            );
+        .flat_map(|data| data.warnings.iter().map(Diagnostic::from));
+
+    let mut errors_encountered = false;
+    for diagnostic in warnings.chain(errors) {
+        errors_encountered |= diagnostic.level == Level::Error;
+        match message_format {
+            MessageFormat::Human => {
+                let source = std::fs::read_to_string(diagnostic.file).ok();
+                eprintln!("{}", diagnostic.render(source.as_deref()));
+            }
+            MessageFormat::Json => println!("{}", diagnostic.to_json()),
         }
     }
 
@@ -281,9 +313,15 @@ fn check_parse_errors(parsed_crates: &BTreeMap<CrateName, ParsedData>) -> anyhow
     }
 }
 
-#[cfg(feature = "go")]
-fn assert_go_package_present(config: &Config) -> anyhow::Result<()> {
-    if config.go.package.is_empty() {
+fn assert_go_package_present(config: &Config, multi_file: bool) -> anyhow::Result<()> {
+    // Each crate is its own package in the module when generating multiple files
+    if multi_file {
+        if config.go.module_root.is_empty() {
+            return Err(anyhow!(
+                "Please provide a module_root for the Go packages in the typeshare.toml"
+            ));
+        }
+    } else if config.go.package.is_empty() {
         return Err(anyhow!(
             "Please provide a package name in the typeshare.toml or using --go-package <package name>"
         ));
@@ -54,9 +54,11 @@ fn output_file_name(language_type: SupportedLanguage, crate_name: &CrateName) ->
 
     let snake_case = || format!("{crate_name}.{extension}");
     let pascal_case = || format!("{}.{extension}", crate_name.to_string().to_pascal_case());
+    // Go packages are directories
+    let package_dir = || format!("{crate_name}/{crate_name}.{extension}");
 
     match language_type {
-        SupportedLanguage::Go => snake_case(),
+        SupportedLanguage::Go => package_dir(),
         SupportedLanguage::Kotlin => snake_case(),
         SupportedLanguage::Scala => snake_case(),
         SupportedLanguage::Swift => pascal_case(),
@@ -86,6 +88,23 @@ pub fn all_types(file_mappings: &BTreeMap<CrateName, ParsedData>) -> CrateTypes
         )
 }
 
+/// Give each crate the structs of all the other crates, so its flattened
+/// fields can refer to a struct defined elsewhere.
+pub fn share_structs(file_mappings: &mut BTreeMap<CrateName, ParsedData>) {
+    let all_structs = file_mappings
+        .iter()
+        .map(|(crate_name, parsed_data)| (crate_name.clone(), parsed_data.structs.clone()))
+        .collect::<BTreeMap<_, _>>();
+
+    for (crate_name, parsed_data) in file_mappings.iter_mut() {
+        parsed_data.external_structs = all_structs
+            .iter()
+            .filter(|(other, _)| *other != crate_name)
+            .map(|(other, structs)| (other.clone(), structs.clone()))
+            .collect();
+    }
+}
+
 /// Collect all the parsed sources into a mapping of crate name to parsed data.
 pub fn parse_input(
     inputs: impl ParallelIterator<Item = ParserInput>,
@@ -0,0 +1,314 @@
+package proto
+
+import "encoding/json"
+
+type BestHockeyTeamsTypes string
+const (
+	BestHockeyTeamsTypeVariantPittsburghPenguins BestHockeyTeamsTypes = "PittsburghPenguins"
+	BestHockeyTeamsTypeVariantLies BestHockeyTeamsTypes = "Lies"
+)
+type BestHockeyTeams struct{ 
+	Type BestHockeyTeamsTypes `json:"type"`
+	content interface{}
+}
+
+func (b *BestHockeyTeams) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    BestHockeyTeamsTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	b.Type = enum.Tag
+	switch b.Type {
+	case BestHockeyTeamsTypeVariantPittsburghPenguins:
+		return nil
+	case BestHockeyTeamsTypeVariantLies:
+		var res string
+		b.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &b.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (b BestHockeyTeams) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    BestHockeyTeamsTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = b.Type
+    enum.Content = b.content
+    return json.Marshal(enum)
+}
+
+func (b BestHockeyTeams) Lies() string {
+	res, _ := b.content.(*string)
+	return *res
+}
+
+func NewBestHockeyTeamsTypeVariantPittsburghPenguins() BestHockeyTeams {
+    return BestHockeyTeams{
+        Type: BestHockeyTeamsTypeVariantPittsburghPenguins,
+    }
+}
+func NewBestHockeyTeamsTypeVariantLies(content string) BestHockeyTeams {
+    return BestHockeyTeams{
+        Type: BestHockeyTeamsTypeVariantLies,
+        content: &content,
+    }
+}
+
+type BestHockeyTeams1Types string
+const (
+	BestHockeyTeams1TypeVariantPittsburghPenguins BestHockeyTeams1Types = "PittsburghPenguins"
+	BestHockeyTeams1TypeVariantLies BestHockeyTeams1Types = "Lies"
+)
+type BestHockeyTeams1 struct{ 
+	Type BestHockeyTeams1Types `json:"type"`
+	content interface{}
+}
+
+func (b *BestHockeyTeams1) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    BestHockeyTeams1Types   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	b.Type = enum.Tag
+	switch b.Type {
+	case BestHockeyTeams1TypeVariantPittsburghPenguins:
+		return nil
+	case BestHockeyTeams1TypeVariantLies:
+		var res string
+		b.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &b.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (b BestHockeyTeams1) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    BestHockeyTeams1Types   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = b.Type
+    enum.Content = b.content
+    return json.Marshal(enum)
+}
+
+func (b BestHockeyTeams1) Lies() string {
+	res, _ := b.content.(*string)
+	return *res
+}
+
+func NewBestHockeyTeams1TypeVariantPittsburghPenguins() BestHockeyTeams1 {
+    return BestHockeyTeams1{
+        Type: BestHockeyTeams1TypeVariantPittsburghPenguins,
+    }
+}
+func NewBestHockeyTeams1TypeVariantLies(content string) BestHockeyTeams1 {
+    return BestHockeyTeams1{
+        Type: BestHockeyTeams1TypeVariantLies,
+        content: &content,
+    }
+}
+
+type BestHockeyTeams2Types string
+const (
+	BestHockeyTeams2TypeVariantPittsburghPenguins BestHockeyTeams2Types = "PittsburghPenguins"
+	BestHockeyTeams2TypeVariantLies BestHockeyTeams2Types = "Lies"
+)
+type BestHockeyTeams2 struct{ 
+	Type BestHockeyTeams2Types `json:"type"`
+	content interface{}
+}
+
+func (b *BestHockeyTeams2) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    BestHockeyTeams2Types   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	b.Type = enum.Tag
+	switch b.Type {
+	case BestHockeyTeams2TypeVariantPittsburghPenguins:
+		return nil
+	case BestHockeyTeams2TypeVariantLies:
+		var res string
+		b.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &b.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (b BestHockeyTeams2) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    BestHockeyTeams2Types   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = b.Type
+    enum.Content = b.content
+    return json.Marshal(enum)
+}
+
+func (b BestHockeyTeams2) Lies() string {
+	res, _ := b.content.(*string)
+	return *res
+}
+
+func NewBestHockeyTeams2TypeVariantPittsburghPenguins() BestHockeyTeams2 {
+    return BestHockeyTeams2{
+        Type: BestHockeyTeams2TypeVariantPittsburghPenguins,
+    }
+}
+func NewBestHockeyTeams2TypeVariantLies(content string) BestHockeyTeams2 {
+    return BestHockeyTeams2{
+        Type: BestHockeyTeams2TypeVariantLies,
+        content: &content,
+    }
+}
+
+type BestHockeyTeams3Types string
+const (
+	BestHockeyTeams3TypeVariantPittsburghPenguins BestHockeyTeams3Types = "PittsburghPenguins"
+	BestHockeyTeams3TypeVariantLies BestHockeyTeams3Types = "Lies"
+)
+type BestHockeyTeams3 struct{ 
+	Type BestHockeyTeams3Types `json:"type"`
+	content interface{}
+}
+
+func (b *BestHockeyTeams3) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    BestHockeyTeams3Types   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	b.Type = enum.Tag
+	switch b.Type {
+	case BestHockeyTeams3TypeVariantPittsburghPenguins:
+		return nil
+	case BestHockeyTeams3TypeVariantLies:
+		var res string
+		b.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &b.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (b BestHockeyTeams3) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    BestHockeyTeams3Types   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = b.Type
+    enum.Content = b.content
+    return json.Marshal(enum)
+}
+
+func (b BestHockeyTeams3) Lies() string {
+	res, _ := b.content.(*string)
+	return *res
+}
+
+func NewBestHockeyTeams3TypeVariantPittsburghPenguins() BestHockeyTeams3 {
+    return BestHockeyTeams3{
+        Type: BestHockeyTeams3TypeVariantPittsburghPenguins,
+    }
+}
+func NewBestHockeyTeams3TypeVariantLies(content string) BestHockeyTeams3 {
+    return BestHockeyTeams3{
+        Type: BestHockeyTeams3TypeVariantLies,
+        content: &content,
+    }
+}
+
+type BestHockeyTeams4Types string
+const (
+	BestHockeyTeams4TypeVariantPittsburghPenguins BestHockeyTeams4Types = "PittsburghPenguins"
+	BestHockeyTeams4TypeVariantLies BestHockeyTeams4Types = "Lies"
+)
+type BestHockeyTeams4 struct{ 
+	Type BestHockeyTeams4Types `json:"type"`
+	content interface{}
+}
+
+func (b *BestHockeyTeams4) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    BestHockeyTeams4Types   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	b.Type = enum.Tag
+	switch b.Type {
+	case BestHockeyTeams4TypeVariantPittsburghPenguins:
+		return nil
+	case BestHockeyTeams4TypeVariantLies:
+		var res string
+		b.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &b.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (b BestHockeyTeams4) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    BestHockeyTeams4Types   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = b.Type
+    enum.Content = b.content
+    return json.Marshal(enum)
+}
+
+func (b BestHockeyTeams4) Lies() string {
+	res, _ := b.content.(*string)
+	return *res
+}
+
+func NewBestHockeyTeams4TypeVariantPittsburghPenguins() BestHockeyTeams4 {
+    return BestHockeyTeams4{
+        Type: BestHockeyTeams4TypeVariantPittsburghPenguins,
+    }
+}
+func NewBestHockeyTeams4TypeVariantLies(content string) BestHockeyTeams4 {
+    return BestHockeyTeams4{
+        Type: BestHockeyTeams4TypeVariantLies,
+        content: &content,
+    }
+}
+
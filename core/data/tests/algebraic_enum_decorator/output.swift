@@ -21,10 +21,9 @@ public enum OPBestHockeyTeams: Codable {
 				self = .pittsburghPenguins
 				return
 			case .lies:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .lies(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .lies(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(OPBestHockeyTeams.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for OPBestHockeyTeams"))
@@ -63,10 +62,9 @@ public enum OPBestHockeyTeams1: Codable, Equatable {
 				self = .pittsburghPenguins
 				return
 			case .lies:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .lies(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .lies(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(OPBestHockeyTeams1.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for OPBestHockeyTeams1"))
@@ -105,10 +103,9 @@ public enum OPBestHockeyTeams2: Codable, Comparable, Equatable, Hashable {
 				self = .pittsburghPenguins
 				return
 			case .lies:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .lies(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .lies(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(OPBestHockeyTeams2.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for OPBestHockeyTeams2"))
@@ -147,10 +144,9 @@ public enum OPBestHockeyTeams3: Codable {
 				self = .pittsburghPenguins
 				return
 			case .lies:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .lies(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .lies(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(OPBestHockeyTeams3.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for OPBestHockeyTeams3"))
@@ -189,10 +185,9 @@ public enum OPBestHockeyTeams4: Codable, Equatable, Hashable {
 				self = .pittsburghPenguins
 				return
 			case .lies:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .lies(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .lies(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(OPBestHockeyTeams4.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for OPBestHockeyTeams4"))
@@ -61,20 +61,17 @@ public enum CoreAnonymousStructWithRename: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .list:
-				if let content = try? container.decode(CoreAnonymousStructWithRenameListInner.self, forKey: .content) {
-					self = .list(content)
-					return
-				}
+				let content = try container.decode(CoreAnonymousStructWithRenameListInner.self, forKey: .content)
+				self = .list(content)
+				return
 			case .longFieldNames:
-				if let content = try? container.decode(CoreAnonymousStructWithRenameLongFieldNamesInner.self, forKey: .content) {
-					self = .longFieldNames(content)
-					return
-				}
+				let content = try container.decode(CoreAnonymousStructWithRenameLongFieldNamesInner.self, forKey: .content)
+				self = .longFieldNames(content)
+				return
 			case .kebabCase:
-				if let content = try? container.decode(CoreAnonymousStructWithRenameKebabCaseInner.self, forKey: .content) {
-					self = .kebabCase(content)
-					return
-				}
+				let content = try container.decode(CoreAnonymousStructWithRenameKebabCaseInner.self, forKey: .content)
+				self = .kebabCase(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(CoreAnonymousStructWithRename.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for CoreAnonymousStructWithRename"))
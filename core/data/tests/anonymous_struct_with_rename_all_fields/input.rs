@@ -0,0 +1,19 @@
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(tag = "type", content = "content", rename_all_fields = "camelCase")]
+pub enum AnonymousStructWithRenameAllFields {
+    LongFieldNames {
+        // The `#[serde(rename_all_fields)]` attribute applied to the overall
+        // enum renames the fields of every struct variant.
+        some_long_field_name: String,
+        but_one_more: Vec<String>,
+    },
+    #[serde(rename_all = "kebab-case")]
+    KebabCase {
+        // The `#[serde(rename_all)]` attribute applied to this enum variant
+        // takes precedence over the one applied to the overall enum.
+        another_list: Vec<String>,
+        #[serde(rename = "renamed_field")]
+        some_field: bool,
+    },
+}
@@ -0,0 +1,82 @@
+package proto
+
+import "encoding/json"
+
+// Generated type representing the anonymous struct variant `LongFieldNames` of the `AnonymousStructWithRenameAllFields` Rust enum
+type AnonymousStructWithRenameAllFieldsLongFieldNamesInner struct {
+	SomeLongFieldName string `json:"someLongFieldName"`
+	ButOneMore []string `json:"butOneMore"`
+}
+// Generated type representing the anonymous struct variant `KebabCase` of the `AnonymousStructWithRenameAllFields` Rust enum
+type AnonymousStructWithRenameAllFieldsKebabCaseInner struct {
+	AnotherList []string `json:"another-list"`
+	SomeField bool `json:"renamed_field"`
+}
+type AnonymousStructWithRenameAllFieldsTypes string
+const (
+	AnonymousStructWithRenameAllFieldsTypeVariantLongFieldNames AnonymousStructWithRenameAllFieldsTypes = "LongFieldNames"
+	AnonymousStructWithRenameAllFieldsTypeVariantKebabCase AnonymousStructWithRenameAllFieldsTypes = "KebabCase"
+)
+type AnonymousStructWithRenameAllFields struct{ 
+	Type AnonymousStructWithRenameAllFieldsTypes `json:"type"`
+	content interface{}
+}
+
+func (a *AnonymousStructWithRenameAllFields) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    AnonymousStructWithRenameAllFieldsTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	a.Type = enum.Tag
+	switch a.Type {
+	case AnonymousStructWithRenameAllFieldsTypeVariantLongFieldNames:
+		var res AnonymousStructWithRenameAllFieldsLongFieldNamesInner
+		a.content = &res
+	case AnonymousStructWithRenameAllFieldsTypeVariantKebabCase:
+		var res AnonymousStructWithRenameAllFieldsKebabCaseInner
+		a.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &a.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (a AnonymousStructWithRenameAllFields) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    AnonymousStructWithRenameAllFieldsTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = a.Type
+    enum.Content = a.content
+    return json.Marshal(enum)
+}
+
+func (a AnonymousStructWithRenameAllFields) LongFieldNames() *AnonymousStructWithRenameAllFieldsLongFieldNamesInner {
+	res, _ := a.content.(*AnonymousStructWithRenameAllFieldsLongFieldNamesInner)
+	return res
+}
+func (a AnonymousStructWithRenameAllFields) KebabCase() *AnonymousStructWithRenameAllFieldsKebabCaseInner {
+	res, _ := a.content.(*AnonymousStructWithRenameAllFieldsKebabCaseInner)
+	return res
+}
+
+func NewAnonymousStructWithRenameAllFieldsTypeVariantLongFieldNames(content *AnonymousStructWithRenameAllFieldsLongFieldNamesInner) AnonymousStructWithRenameAllFields {
+    return AnonymousStructWithRenameAllFields{
+        Type: AnonymousStructWithRenameAllFieldsTypeVariantLongFieldNames,
+        content: content,
+    }
+}
+func NewAnonymousStructWithRenameAllFieldsTypeVariantKebabCase(content *AnonymousStructWithRenameAllFieldsKebabCaseInner) AnonymousStructWithRenameAllFields {
+    return AnonymousStructWithRenameAllFields{
+        Type: AnonymousStructWithRenameAllFieldsTypeVariantKebabCase,
+        content: content,
+    }
+}
+
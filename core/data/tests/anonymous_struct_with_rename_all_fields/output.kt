@@ -0,0 +1,31 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+/// Generated type representing the anonymous struct variant `LongFieldNames` of the `AnonymousStructWithRenameAllFields` Rust enum
+@Serializable
+data class AnonymousStructWithRenameAllFieldsLongFieldNamesInner (
+	val someLongFieldName: String,
+	val butOneMore: List<String>
+)
+
+/// Generated type representing the anonymous struct variant `KebabCase` of the `AnonymousStructWithRenameAllFields` Rust enum
+@Serializable
+data class AnonymousStructWithRenameAllFieldsKebabCaseInner (
+	@SerialName("another-list")
+	val another_list: List<String>,
+	@SerialName("renamed_field")
+	val renamed_field: Boolean
+)
+
+@Serializable
+sealed class AnonymousStructWithRenameAllFields {
+	@Serializable
+	@SerialName("LongFieldNames")
+	data class LongFieldNames(val content: AnonymousStructWithRenameAllFieldsLongFieldNamesInner): AnonymousStructWithRenameAllFields()
+	@Serializable
+	@SerialName("KebabCase")
+	data class KebabCase(val content: AnonymousStructWithRenameAllFieldsKebabCaseInner): AnonymousStructWithRenameAllFields()
+}
+
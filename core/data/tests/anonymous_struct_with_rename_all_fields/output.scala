@@ -0,0 +1,29 @@
+package com.agilebits
+
+package onepassword {
+
+// Generated type representing the anonymous struct variant `LongFieldNames` of the `AnonymousStructWithRenameAllFields` Rust enum
+case class AnonymousStructWithRenameAllFieldsLongFieldNamesInner (
+	someLongFieldName: String,
+	butOneMore: Vector[String]
+)
+
+// Generated type representing the anonymous struct variant `KebabCase` of the `AnonymousStructWithRenameAllFields` Rust enum
+case class AnonymousStructWithRenameAllFieldsKebabCaseInner (
+	another_list: Vector[String],
+	renamed_field: Boolean
+)
+
+sealed trait AnonymousStructWithRenameAllFields {
+	def serialName: String
+}
+object AnonymousStructWithRenameAllFields {
+	case class LongFieldNames(content: AnonymousStructWithRenameAllFieldsLongFieldNamesInner) extends AnonymousStructWithRenameAllFields {
+		val serialName: String = "LongFieldNames"
+	}
+	case class KebabCase(content: AnonymousStructWithRenameAllFieldsKebabCaseInner) extends AnonymousStructWithRenameAllFields {
+		val serialName: String = "KebabCase"
+	}
+}
+
+}
@@ -0,0 +1,71 @@
+import Foundation
+
+
+/// Generated type representing the anonymous struct variant `LongFieldNames` of the `AnonymousStructWithRenameAllFields` Rust enum
+public struct AnonymousStructWithRenameAllFieldsLongFieldNamesInner: Codable {
+	public let someLongFieldName: String
+	public let butOneMore: [String]
+
+	public init(someLongFieldName: String, butOneMore: [String]) {
+		self.someLongFieldName = someLongFieldName
+		self.butOneMore = butOneMore
+	}
+}
+
+/// Generated type representing the anonymous struct variant `KebabCase` of the `AnonymousStructWithRenameAllFields` Rust enum
+public struct AnonymousStructWithRenameAllFieldsKebabCaseInner: Codable {
+	public let another_list: [String]
+	public let renamed_field: Bool
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case another_list = "another-list",
+			renamed_field
+	}
+
+	public init(another_list: [String], renamed_field: Bool) {
+		self.another_list = another_list
+		self.renamed_field = renamed_field
+	}
+}
+public enum AnonymousStructWithRenameAllFields: Codable {
+	case longFieldNames(AnonymousStructWithRenameAllFieldsLongFieldNamesInner)
+	case kebabCase(AnonymousStructWithRenameAllFieldsKebabCaseInner)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case longFieldNames = "LongFieldNames",
+			kebabCase = "KebabCase"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case type, content
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
+			switch type {
+			case .longFieldNames:
+				let content = try container.decode(AnonymousStructWithRenameAllFieldsLongFieldNamesInner.self, forKey: .content)
+				self = .longFieldNames(content)
+				return
+			case .kebabCase:
+				let content = try container.decode(AnonymousStructWithRenameAllFieldsKebabCaseInner.self, forKey: .content)
+				self = .kebabCase(content)
+				return
+			}
+		}
+		throw DecodingError.typeMismatch(AnonymousStructWithRenameAllFields.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for AnonymousStructWithRenameAllFields"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .longFieldNames(let content):
+			try container.encode(CodingKeys.longFieldNames, forKey: .type)
+			try container.encode(content, forKey: .content)
+		case .kebabCase(let content):
+			try container.encode(CodingKeys.kebabCase, forKey: .type)
+			try container.encode(content, forKey: .content)
+		}
+	}
+}
@@ -0,0 +1,10 @@
+export type AnonymousStructWithRenameAllFields = 
+	| { type: "LongFieldNames", content: {
+	someLongFieldName: string;
+	butOneMore: string[];
+}}
+	| { type: "KebabCase", content: {
+	"another-list": string[];
+	renamed_field: boolean;
+}};
+
@@ -34,35 +34,29 @@ public enum OPAdvancedColors: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .t) {
 			switch type {
 			case .string:
-				if let content = try? container.decode(String.self, forKey: .c) {
-					self = .string(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .c)
+				self = .string(content)
+				return
 			case .number:
-				if let content = try? container.decode(Int32.self, forKey: .c) {
-					self = .number(content)
-					return
-				}
+				let content = try container.decode(Int32.self, forKey: .c)
+				self = .number(content)
+				return
 			case .numberArray:
-				if let content = try? container.decode([Int32].self, forKey: .c) {
-					self = .numberArray(content)
-					return
-				}
+				let content = try container.decode([Int32].self, forKey: .c)
+				self = .numberArray(content)
+				return
 			case .reallyCoolType:
-				if let content = try? container.decode(OPItemDetailsFieldValue.self, forKey: .c) {
-					self = .reallyCoolType(content)
-					return
-				}
+				let content = try container.decode(OPItemDetailsFieldValue.self, forKey: .c)
+				self = .reallyCoolType(content)
+				return
 			case .arrayReallyCoolType:
-				if let content = try? container.decode([OPItemDetailsFieldValue].self, forKey: .c) {
-					self = .arrayReallyCoolType(content)
-					return
-				}
+				let content = try container.decode([OPItemDetailsFieldValue].self, forKey: .c)
+				self = .arrayReallyCoolType(content)
+				return
 			case .dictionaryReallyCoolType:
-				if let content = try? container.decode([String: OPItemDetailsFieldValue].self, forKey: .c) {
-					self = .dictionaryReallyCoolType(content)
-					return
-				}
+				let content = try container.decode([String: OPItemDetailsFieldValue].self, forKey: .c)
+				self = .dictionaryReallyCoolType(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(OPAdvancedColors.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for OPAdvancedColors"))
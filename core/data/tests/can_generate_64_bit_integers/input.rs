@@ -0,0 +1,7 @@
+#[typeshare]
+pub struct FileInfo {
+    pub size: u64,
+    pub offset: i64,
+    pub blocks: Vec<usize>,
+    pub delta: Option<isize>,
+}
@@ -0,0 +1,10 @@
+package proto
+
+import "encoding/json"
+
+type FileInfo struct {
+	Size uint64 `json:"size"`
+	Offset int64 `json:"offset"`
+	Blocks []int `json:"blocks"`
+	Delta *int `json:"delta,omitempty"`
+}
@@ -0,0 +1,13 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Serializable
+data class FileInfo (
+	val size: ULong,
+	val offset: Long,
+	val blocks: List<ULong>,
+	val delta: Long? = null
+)
+
@@ -0,0 +1,20 @@
+package com.agilebits
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Long
+
+}
+package onepassword {
+
+case class FileInfo (
+	size: ULong,
+	offset: Long,
+	blocks: Vector[ULong],
+	delta: Option[Long] = None
+)
+
+}
@@ -0,0 +1,15 @@
+import Foundation
+
+public struct FileInfo: Codable {
+	public let size: UInt64
+	public let offset: Int64
+	public let blocks: [UInt]
+	public let delta: Int?
+
+	public init(size: UInt64, offset: Int64, blocks: [UInt], delta: Int?) {
+		self.size = size
+		self.offset = offset
+		self.blocks = blocks
+		self.delta = delta
+	}
+}
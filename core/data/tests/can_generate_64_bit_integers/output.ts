@@ -0,0 +1,7 @@
+export interface FileInfo {
+	size: bigint;
+	offset: bigint;
+	blocks: bigint[];
+	delta?: bigint;
+}
+
@@ -32,30 +32,25 @@ public enum OPAdvancedColors: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .string:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .string(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .string(content)
+				return
 			case .number:
-				if let content = try? container.decode(Int32.self, forKey: .content) {
-					self = .number(content)
-					return
-				}
+				let content = try container.decode(Int32.self, forKey: .content)
+				self = .number(content)
+				return
 			case .unsignedNumber:
-				if let content = try? container.decode(UInt32.self, forKey: .content) {
-					self = .unsignedNumber(content)
-					return
-				}
+				let content = try container.decode(UInt32.self, forKey: .content)
+				self = .unsignedNumber(content)
+				return
 			case .numberArray:
-				if let content = try? container.decode([Int32].self, forKey: .content) {
-					self = .numberArray(content)
-					return
-				}
+				let content = try container.decode([Int32].self, forKey: .content)
+				self = .numberArray(content)
+				return
 			case .reallyCoolType:
-				if let content = try? container.decode(OPItemDetailsFieldValue.self, forKey: .content) {
-					self = .reallyCoolType(content)
-					return
-				}
+				let content = try container.decode(OPItemDetailsFieldValue.self, forKey: .content)
+				self = .reallyCoolType(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(OPAdvancedColors.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for OPAdvancedColors"))
@@ -107,25 +102,21 @@ public enum OPAdvancedColors2: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .string:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .string(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .string(content)
+				return
 			case .number:
-				if let content = try? container.decode(Int32.self, forKey: .content) {
-					self = .number(content)
-					return
-				}
+				let content = try container.decode(Int32.self, forKey: .content)
+				self = .number(content)
+				return
 			case .numberArray:
-				if let content = try? container.decode([Int32].self, forKey: .content) {
-					self = .numberArray(content)
-					return
-				}
+				let content = try container.decode([Int32].self, forKey: .content)
+				self = .numberArray(content)
+				return
 			case .reallyCoolType:
-				if let content = try? container.decode(OPItemDetailsFieldValue.self, forKey: .content) {
-					self = .reallyCoolType(content)
-					return
-				}
+				let content = try container.decode(OPItemDetailsFieldValue.self, forKey: .content)
+				self = .reallyCoolType(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(OPAdvancedColors2.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for OPAdvancedColors2"))
@@ -21,10 +21,9 @@ public enum SomeEnum: Codable {
 				self = .a
 				return
 			case .c:
-				if let content = try? container.decode(Int32.self, forKey: .content) {
-					self = .c(content)
-					return
-				}
+				let content = try container.decode(Int32.self, forKey: .content)
+				self = .c(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(SomeEnum.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for SomeEnum"))
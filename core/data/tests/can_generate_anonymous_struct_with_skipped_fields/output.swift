@@ -41,15 +41,13 @@ public enum AutofilledBy: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .us:
-				if let content = try? container.decode(AutofilledByUsInner.self, forKey: .content) {
-					self = .us(content)
-					return
-				}
+				let content = try container.decode(AutofilledByUsInner.self, forKey: .content)
+				self = .us(content)
+				return
 			case .somethingElse:
-				if let content = try? container.decode(AutofilledBySomethingElseInner.self, forKey: .content) {
-					self = .somethingElse(content)
-					return
-				}
+				let content = try container.decode(AutofilledBySomethingElseInner.self, forKey: .content)
+				self = .somethingElse(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(AutofilledBy.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for AutofilledBy"))
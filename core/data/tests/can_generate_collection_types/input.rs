@@ -0,0 +1,20 @@
+#[typeshare]
+pub struct Inventory {
+    pub tags: HashSet<String>,
+    pub sorted_tags: BTreeSet<String>,
+    pub items: BTreeMap<String, Item>,
+    pub ordered_items: IndexMap<String, Item>,
+    pub categories: IndexSet<Category>,
+    pub history: VecDeque<Item>,
+}
+
+#[typeshare]
+pub struct Item {
+    pub name: String,
+}
+
+#[typeshare]
+pub enum Category {
+    Food,
+    Tools,
+}
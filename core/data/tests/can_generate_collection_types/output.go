@@ -0,0 +1,20 @@
+package proto
+
+import "encoding/json"
+
+type Item struct {
+	Name string `json:"name"`
+}
+type Category string
+const (
+	CategoryFood Category = "Food"
+	CategoryTools Category = "Tools"
+)
+type Inventory struct {
+	Tags []string `json:"tags"`
+	SortedTags []string `json:"sorted_tags"`
+	Items map[string]Item `json:"items"`
+	OrderedItems map[string]Item `json:"ordered_items"`
+	Categories []Category `json:"categories"`
+	History []Item `json:"history"`
+}
@@ -0,0 +1,28 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Serializable
+data class Item (
+	val name: String
+)
+
+@Serializable
+enum class Category(val string: String) {
+	@SerialName("Food")
+	Food("Food"),
+	@SerialName("Tools")
+	Tools("Tools"),
+}
+
+@Serializable
+data class Inventory (
+	val tags: Set<String>,
+	val sorted_tags: Set<String>,
+	val items: Map<String, Item>,
+	val ordered_items: Map<String, Item>,
+	val categories: Set<Category>,
+	val history: List<Item>
+)
+
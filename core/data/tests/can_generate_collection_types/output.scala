@@ -0,0 +1,30 @@
+package com.agilebits
+
+package onepassword {
+
+case class Inventory (
+	tags: Set[String],
+	sorted_tags: Set[String],
+	items: Map[String, Item],
+	ordered_items: Map[String, Item],
+	categories: Set[Category],
+	history: Vector[Item]
+)
+
+case class Item (
+	name: String
+)
+
+sealed trait Category {
+	def serialName: String
+}
+object Category {
+	case object Food extends Category {
+		val serialName: String = "Food"
+	}
+	case object Tools extends Category {
+		val serialName: String = "Tools"
+	}
+}
+
+}
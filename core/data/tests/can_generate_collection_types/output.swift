@@ -0,0 +1,32 @@
+import Foundation
+
+public struct Item: Codable {
+	public let name: String
+
+	public init(name: String) {
+		self.name = name
+	}
+}
+
+public enum Category: String, Codable {
+	case food = "Food"
+	case tools = "Tools"
+}
+
+public struct Inventory: Codable {
+	public let tags: Set<String>
+	public let sorted_tags: Set<String>
+	public let items: [String: Item]
+	public let ordered_items: [String: Item]
+	public let categories: Set<Category>
+	public let history: [Item]
+
+	public init(tags: Set<String>, sorted_tags: Set<String>, items: [String: Item], ordered_items: [String: Item], categories: Set<Category>, history: [Item]) {
+		self.tags = tags
+		self.sorted_tags = sorted_tags
+		self.items = items
+		self.ordered_items = ordered_items
+		self.categories = categories
+		self.history = history
+	}
+}
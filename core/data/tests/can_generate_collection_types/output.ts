@@ -0,0 +1,18 @@
+export interface Item {
+	name: string;
+}
+
+export enum Category {
+	Food = "Food",
+	Tools = "Tools",
+}
+
+export interface Inventory {
+	tags: string[];
+	sorted_tags: string[];
+	items: Record<string, Item>;
+	ordered_items: Record<string, Item>;
+	categories: Category[];
+	history: Item[];
+}
+
@@ -0,0 +1,20 @@
+/// The largest payload a client may send, in bytes
+#[typeshare]
+pub const MAX_PAYLOAD_SIZE: u32 = 1024;
+
+#[typeshare]
+pub const PROTOCOL_VERSION: &str = "2.1 \"stable\"";
+
+#[typeshare]
+pub const DEFAULT_TIMEOUT_SECS: f64 = 2.5;
+
+#[typeshare]
+pub const MIN_OFFSET: i32 = -16;
+
+#[typeshare]
+pub const COMPRESSION_ENABLED: bool = true;
+
+#[typeshare]
+pub struct Payload {
+    pub data: Vec<u8>,
+}
@@ -0,0 +1,18 @@
+package proto
+
+import "encoding/json"
+
+const CompressionEnabled bool = true
+
+const DefaultTimeoutSecs float64 = 2.5
+
+// The largest payload a client may send, in bytes
+const MaxPayloadSize uint32 = 1024
+
+const MinOffset int = -16
+
+const ProtocolVersion string = "2.1 \"stable\""
+
+type Payload struct {
+	Data []int `json:"data"`
+}
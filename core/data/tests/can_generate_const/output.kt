@@ -0,0 +1,21 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+const val COMPRESSION_ENABLED: Boolean = true
+
+const val DEFAULT_TIMEOUT_SECS: Double = 2.5
+
+/// The largest payload a client may send, in bytes
+const val MAX_PAYLOAD_SIZE: UInt = 1024u
+
+const val MIN_OFFSET: Int = -16
+
+const val PROTOCOL_VERSION: String = "2.1 \"stable\""
+
+@Serializable
+data class Payload (
+	val data: List<UByte>
+)
+
@@ -0,0 +1,28 @@
+package com.agilebits
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Long
+
+val COMPRESSION_ENABLED: Boolean = true
+
+val DEFAULT_TIMEOUT_SECS: Double = 2.5
+
+// The largest payload a client may send, in bytes
+val MAX_PAYLOAD_SIZE: UInt = 1024
+
+val MIN_OFFSET: Int = -16
+
+val PROTOCOL_VERSION: String = "2.1 \"stable\""
+
+}
+package onepassword {
+
+case class Payload (
+	data: Vector[UByte]
+)
+
+}
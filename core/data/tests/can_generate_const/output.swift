@@ -0,0 +1,20 @@
+import Foundation
+
+public let COMPRESSION_ENABLED: Bool = true
+
+public let DEFAULT_TIMEOUT_SECS: Double = 2.5
+
+/// The largest payload a client may send, in bytes
+public let MAX_PAYLOAD_SIZE: UInt32 = 1024
+
+public let MIN_OFFSET: Int32 = -16
+
+public let PROTOCOL_VERSION: String = "2.1 \"stable\""
+
+public struct Payload: Codable {
+	public let data: [UInt8]
+
+	public init(data: [UInt8]) {
+		self.data = data
+	}
+}
@@ -0,0 +1,15 @@
+export const COMPRESSION_ENABLED: boolean = true;
+
+export const DEFAULT_TIMEOUT_SECS: number = 2.5;
+
+/** The largest payload a client may send, in bytes */
+export const MAX_PAYLOAD_SIZE: number = 1024;
+
+export const MIN_OFFSET: number = -16;
+
+export const PROTOCOL_VERSION: string = "2.1 \"stable\"";
+
+export interface Payload {
+	data: number[];
+}
+
@@ -0,0 +1,39 @@
+/// A user of the service
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct User {
+    pub name: String,
+    /// The user's nickname
+    #[deprecated(since = "1.2.0", note = "Use `name` instead")]
+    pub nickname: String,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[deprecated]
+pub struct LegacyUser {
+    pub name: String,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub enum Theme {
+    Light,
+    Dark,
+    #[deprecated = "Use `Dark` instead"]
+    Dim,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
+pub enum Message {
+    Text(String),
+    /// A message with a number
+    #[deprecated(since = "2.0.0")]
+    Number(i32),
+}
+
+#[typeshare]
+#[deprecated(note = "Use `User` instead")]
+pub type Username = String;
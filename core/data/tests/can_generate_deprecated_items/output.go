@@ -0,0 +1,97 @@
+package proto
+
+import "encoding/json"
+
+// Deprecated: Use `User` instead
+type Username string
+
+// Deprecated:
+type LegacyUser struct {
+	Name string `json:"name"`
+}
+// A user of the service
+type User struct {
+	Name string `json:"name"`
+	// The user's nickname
+	//
+	// Deprecated: Use `name` instead (since 1.2.0)
+	Nickname string `json:"nickname"`
+}
+type MessageTypes string
+const (
+	MessageTypeVariantText MessageTypes = "Text"
+	// A message with a number
+	//
+	// Deprecated: Deprecated since 2.0.0
+	MessageTypeVariantNumber MessageTypes = "Number"
+)
+type Message struct{ 
+	Type MessageTypes `json:"type"`
+	content interface{}
+}
+
+func (m *Message) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    MessageTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	m.Type = enum.Tag
+	switch m.Type {
+	case MessageTypeVariantText:
+		var res string
+		m.content = &res
+	case MessageTypeVariantNumber:
+		var res int
+		m.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &m.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (m Message) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    MessageTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = m.Type
+    enum.Content = m.content
+    return json.Marshal(enum)
+}
+
+func (m Message) Text() string {
+	res, _ := m.content.(*string)
+	return *res
+}
+func (m Message) Number() int {
+	res, _ := m.content.(*int)
+	return *res
+}
+
+func NewMessageTypeVariantText(content string) Message {
+    return Message{
+        Type: MessageTypeVariantText,
+        content: &content,
+    }
+}
+func NewMessageTypeVariantNumber(content int) Message {
+    return Message{
+        Type: MessageTypeVariantNumber,
+        content: &content,
+    }
+}
+
+type Theme string
+const (
+	ThemeLight Theme = "Light"
+	ThemeDark Theme = "Dark"
+	// Deprecated: Use `Dark` instead
+	ThemeDim Theme = "Dim"
+)
@@ -0,0 +1,46 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Deprecated("Use `User` instead")
+typealias Username = String
+
+@Deprecated("")
+@Serializable
+data class LegacyUser (
+	val name: String
+)
+
+/// A user of the service
+@Serializable
+data class User (
+	val name: String,
+	/// The user's nickname
+	@Deprecated("Use `name` instead (since 1.2.0)")
+	val nickname: String
+)
+
+@Serializable
+sealed class Message {
+	@Serializable
+	@SerialName("Text")
+	data class Text(val content: String): Message()
+	/// A message with a number
+	@Deprecated("Deprecated since 2.0.0")
+	@Serializable
+	@SerialName("Number")
+	data class Number(val content: Int): Message()
+}
+
+@Serializable
+enum class Theme(val string: String) {
+	@SerialName("Light")
+	Light("Light"),
+	@SerialName("Dark")
+	Dark("Dark"),
+	@Deprecated("Use `Dark` instead")
+	@SerialName("Dim")
+	Dim("Dim"),
+}
+
@@ -0,0 +1,54 @@
+package com.agilebits
+
+package object onepassword {
+
+@deprecated("Use `User` instead", "")
+type Username = String
+
+}
+package onepassword {
+
+@deprecated
+case class LegacyUser (
+	name: String
+)
+
+// A user of the service
+case class User (
+	name: String,
+	// The user's nickname
+	@deprecated("Use `name` instead", "1.2.0")
+	nickname: String
+)
+
+sealed trait Message {
+	def serialName: String
+}
+object Message {
+	case class Text(content: String) extends Message {
+		val serialName: String = "Text"
+	}
+	// A message with a number
+	@deprecated("", "2.0.0")
+	case class Number(content: Int) extends Message {
+		val serialName: String = "Number"
+	}
+}
+
+sealed trait Theme {
+	def serialName: String
+}
+object Theme {
+	case object Light extends Theme {
+		val serialName: String = "Light"
+	}
+	case object Dark extends Theme {
+		val serialName: String = "Dark"
+	}
+	@deprecated("Use `Dark` instead", "")
+	case object Dim extends Theme {
+		val serialName: String = "Dim"
+	}
+}
+
+}
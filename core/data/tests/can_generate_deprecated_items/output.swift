@@ -0,0 +1,78 @@
+import Foundation
+
+@available(*, deprecated, message: "Use `User` instead")
+public typealias Username = String
+
+@available(*, deprecated)
+public struct LegacyUser: Codable {
+	public let name: String
+
+	public init(name: String) {
+		self.name = name
+	}
+}
+
+/// A user of the service
+public struct User: Codable {
+	public let name: String
+	/// The user's nickname
+	@available(*, deprecated, message: "Use `name` instead (since 1.2.0)")
+	public let nickname: String
+
+	public init(name: String, nickname: String) {
+		self.name = name
+		self.nickname = nickname
+	}
+}
+
+public enum Message: Codable {
+	case text(String)
+	/// A message with a number
+	@available(*, deprecated, message: "Deprecated since 2.0.0")
+	case number(Int32)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case text = "Text",
+			number = "Number"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case type, content
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
+			switch type {
+			case .text:
+				let content = try container.decode(String.self, forKey: .content)
+				self = .text(content)
+				return
+			case .number:
+				let content = try container.decode(Int32.self, forKey: .content)
+				self = .number(content)
+				return
+			}
+		}
+		throw DecodingError.typeMismatch(Message.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Message"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .text(let content):
+			try container.encode(CodingKeys.text, forKey: .type)
+			try container.encode(content, forKey: .content)
+		case .number(let content):
+			try container.encode(CodingKeys.number, forKey: .type)
+			try container.encode(content, forKey: .content)
+		}
+	}
+}
+
+public enum Theme: String, Codable {
+	case light = "Light"
+	case dark = "Dark"
+	@available(*, deprecated, message: "Use `Dark` instead")
+	case dim = "Dim"
+}
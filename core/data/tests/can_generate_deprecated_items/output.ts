@@ -0,0 +1,33 @@
+/** @deprecated Use `User` instead */
+export type Username = string;
+
+/** @deprecated */
+export interface LegacyUser {
+	name: string;
+}
+
+/** A user of the service */
+export interface User {
+	name: string;
+	/**
+	 * The user's nickname
+	 * @deprecated Use `name` instead (since 1.2.0)
+	 */
+	nickname: string;
+}
+
+export type Message = 
+	| { type: "Text", content: string }
+	/**
+	 * A message with a number
+	 * @deprecated Deprecated since 2.0.0
+	 */
+	| { type: "Number", content: number };
+
+export enum Theme {
+	Light = "Light",
+	Dark = "Dark",
+	/** @deprecated Use `Dark` instead */
+	Dim = "Dim",
+}
+
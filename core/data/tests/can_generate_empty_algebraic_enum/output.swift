@@ -22,10 +22,9 @@ public enum OPAddress: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .fixedAddress:
-				if let content = try? container.decode(OPAddressDetails.self, forKey: .content) {
-					self = .fixedAddress(content)
-					return
-				}
+				let content = try container.decode(OPAddressDetails.self, forKey: .content)
+				self = .fixedAddress(content)
+				return
 			case .noFixedAddress:
 				self = .noFixedAddress
 				return
@@ -0,0 +1,16 @@
+#[typeshare]
+pub struct ItemDetails {
+    pub name: String,
+}
+
+/// An event using serde's default enum representation.
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub enum Event {
+    /// Nothing happened
+    Idle,
+    Renamed(String),
+    Added(ItemDetails),
+    Removed(Option<ItemDetails>),
+    Moved { from: u32, to: u32 },
+}
@@ -0,0 +1,125 @@
+package proto
+
+import "encoding/json"
+import "fmt"
+
+type ItemDetails struct {
+	Name string `json:"name"`
+}
+// Generated type representing the anonymous struct variant `Moved` of the `Event` Rust enum
+type EventMovedInner struct {
+	From uint32 `json:"from"`
+	To uint32 `json:"to"`
+}
+// An event using serde's default enum representation.
+type EventTypes string
+const (
+	// Nothing happened
+	EventTypeVariantIdle EventTypes = "idle"
+	EventTypeVariantRenamed EventTypes = "renamed"
+	EventTypeVariantAdded EventTypes = "added"
+	EventTypeVariantRemoved EventTypes = "removed"
+	EventTypeVariantMoved EventTypes = "moved"
+)
+type Event struct{ 
+	Type EventTypes `json:"type"`
+	content interface{}
+}
+
+func (e *Event) UnmarshalJSON(data []byte) error {
+	var tag EventTypes
+	if err := json.Unmarshal(data, &tag); err == nil {
+		e.Type = tag
+		e.content = nil
+		return nil
+	}
+
+	var enum map[EventTypes]json.RawMessage
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+	if len(enum) != 1 {
+		return fmt.Errorf("expected a single variant for Event, got %d", len(enum))
+	}
+
+	for tag, content := range enum {
+		e.Type = tag
+		switch e.Type {
+		case EventTypeVariantIdle:
+			return nil
+		case EventTypeVariantRenamed:
+			var res string
+			e.content = &res
+		case EventTypeVariantAdded:
+			var res ItemDetails
+			e.content = &res
+		case EventTypeVariantRemoved:
+			var res *ItemDetails
+			e.content = &res
+		case EventTypeVariantMoved:
+			var res EventMovedInner
+			e.content = &res
+
+		}
+		if err := json.Unmarshal(content, &e.content); err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+func (e Event) MarshalJSON() ([]byte, error) {
+	if e.content == nil {
+		return json.Marshal(e.Type)
+	}
+	return json.Marshal(map[EventTypes]interface{}{e.Type: e.content})
+}
+
+func (e Event) Renamed() string {
+	res, _ := e.content.(*string)
+	return *res
+}
+func (e Event) Added() *ItemDetails {
+	res, _ := e.content.(*ItemDetails)
+	return res
+}
+func (e Event) Removed() *ItemDetails {
+	res, _ := e.content.(**ItemDetails)
+	return *res
+}
+func (e Event) Moved() *EventMovedInner {
+	res, _ := e.content.(*EventMovedInner)
+	return res
+}
+
+func NewEventTypeVariantIdle() Event {
+    return Event{
+        Type: EventTypeVariantIdle,
+    }
+}
+func NewEventTypeVariantRenamed(content string) Event {
+    return Event{
+        Type: EventTypeVariantRenamed,
+        content: &content,
+    }
+}
+func NewEventTypeVariantAdded(content *ItemDetails) Event {
+    return Event{
+        Type: EventTypeVariantAdded,
+        content: content,
+    }
+}
+func NewEventTypeVariantRemoved(content *ItemDetails) Event {
+    return Event{
+        Type: EventTypeVariantRemoved,
+        content: &content,
+    }
+}
+func NewEventTypeVariantMoved(content *EventMovedInner) Event {
+    return Event{
+        Type: EventTypeVariantMoved,
+        content: content,
+    }
+}
+
@@ -0,0 +1,80 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class ItemDetails (
+	val name: String
+)
+
+/// Generated type representing the anonymous struct variant `Moved` of the `Event` Rust enum
+@Serializable
+data class EventMovedInner (
+	val from: UInt,
+	val to: UInt
+)
+
+/// An event using serde's default enum representation.
+@Serializable(with = EventSerializer::class)
+sealed class Event {
+	/// Nothing happened
+	@Serializable
+	@SerialName("idle")
+	object Idle: Event()
+	@Serializable
+	@SerialName("renamed")
+	data class Renamed(val content: String): Event()
+	@Serializable
+	@SerialName("added")
+	data class Added(val content: ItemDetails): Event()
+	@Serializable
+	@SerialName("removed")
+	data class Removed(val content: ItemDetails?): Event()
+	@Serializable
+	@SerialName("moved")
+	data class Moved(val content: EventMovedInner): Event()
+}
+
+object EventSerializer : KSerializer<Event> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Event")
+
+	override fun serialize(encoder: Encoder, value: Event) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is Event.Idle -> JsonPrimitive("idle")
+			is Event.Renamed -> buildJsonObject { put("renamed", output.json.encodeToJsonElement(serializer<String>(), value.content)) }
+			is Event.Added -> buildJsonObject { put("added", output.json.encodeToJsonElement(serializer<ItemDetails>(), value.content)) }
+			is Event.Removed -> buildJsonObject { put("removed", output.json.encodeToJsonElement(serializer<ItemDetails?>(), value.content)) }
+			is Event.Moved -> buildJsonObject { put("moved", output.json.encodeToJsonElement(EventMovedInner.serializer(), value.content)) }
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Event {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		if (element is JsonPrimitive && element.isString) {
+			when (element.content) {
+				"idle" -> return Event.Idle
+			}
+		}
+		if (element is JsonObject && element.size == 1) {
+			val (key, content) = element.entries.first()
+			when (key) {
+				"renamed" -> return Event.Renamed(input.json.decodeFromJsonElement(serializer<String>(), content))
+				"added" -> return Event.Added(input.json.decodeFromJsonElement(serializer<ItemDetails>(), content))
+				"removed" -> return Event.Removed(input.json.decodeFromJsonElement(serializer<ItemDetails?>(), content))
+				"moved" -> return Event.Moved(input.json.decodeFromJsonElement(EventMovedInner.serializer(), content))
+			}
+		}
+		throw SerializationException("Unknown variant for Event: $element")
+	}
+}
+
@@ -0,0 +1,46 @@
+package com.agilebits
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Long
+
+}
+package onepassword {
+
+case class ItemDetails (
+	name: String
+)
+
+// Generated type representing the anonymous struct variant `Moved` of the `Event` Rust enum
+case class EventMovedInner (
+	from: UInt,
+	to: UInt
+)
+
+// An event using serde's default enum representation.
+sealed trait Event {
+	def serialName: String
+}
+object Event {
+	// Nothing happened
+	case object Idle extends Event {
+		val serialName: String = "idle"
+	}
+	case class Renamed(content: String) extends Event {
+		val serialName: String = "renamed"
+	}
+	case class Added(content: ItemDetails) extends Event {
+		val serialName: String = "added"
+	}
+	case class Removed(content: Option[ItemDetails]) extends Event {
+		val serialName: String = "removed"
+	}
+	case class Moved(content: EventMovedInner) extends Event {
+		val serialName: String = "moved"
+	}
+}
+
+}
@@ -0,0 +1,93 @@
+import Foundation
+
+public struct ItemDetails: Codable {
+	public let name: String
+
+	public init(name: String) {
+		self.name = name
+	}
+}
+
+
+/// Generated type representing the anonymous struct variant `Moved` of the `Event` Rust enum
+public struct EventMovedInner: Codable {
+	public let from: UInt32
+	public let to: UInt32
+
+	public init(from: UInt32, to: UInt32) {
+		self.from = from
+		self.to = to
+	}
+}
+/// An event using serde's default enum representation.
+public enum Event: Codable {
+	/// Nothing happened
+	case idle
+	case renamed(String)
+	case added(ItemDetails)
+	case removed(ItemDetails?)
+	case moved(EventMovedInner)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case idle,
+			renamed,
+			added,
+			removed,
+			moved
+	}
+
+	public init(from decoder: Decoder) throws {
+		if let container = try? decoder.singleValueContainer(), let type = try? container.decode(CodingKeys.self) {
+			switch type {
+			case .idle:
+				self = .idle
+				return
+			default:
+				break
+			}
+		}
+		if let container = try? decoder.container(keyedBy: CodingKeys.self), let type = container.allKeys.first {
+			switch type {
+			case .renamed:
+				let content = try container.decode(String.self, forKey: .renamed)
+				self = .renamed(content)
+				return
+			case .added:
+				let content = try container.decode(ItemDetails.self, forKey: .added)
+				self = .added(content)
+				return
+			case .removed:
+				let content = try container.decode(ItemDetails?.self, forKey: .removed)
+				self = .removed(content)
+				return
+			case .moved:
+				let content = try container.decode(EventMovedInner.self, forKey: .moved)
+				self = .moved(content)
+				return
+			default:
+				break
+			}
+		}
+		throw DecodingError.typeMismatch(Event.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Event"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		switch self {
+		case .idle:
+			var container = encoder.singleValueContainer()
+			try container.encode(CodingKeys.idle)
+		case .renamed(let content):
+			var container = encoder.container(keyedBy: CodingKeys.self)
+			try container.encode(content, forKey: .renamed)
+		case .added(let content):
+			var container = encoder.container(keyedBy: CodingKeys.self)
+			try container.encode(content, forKey: .added)
+		case .removed(let content):
+			var container = encoder.container(keyedBy: CodingKeys.self)
+			try container.encode(content, forKey: .removed)
+		case .moved(let content):
+			var container = encoder.container(keyedBy: CodingKeys.self)
+			try container.encode(content, forKey: .moved)
+		}
+	}
+}
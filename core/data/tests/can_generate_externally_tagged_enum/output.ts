@@ -0,0 +1,16 @@
+export interface ItemDetails {
+	name: string;
+}
+
+/** An event using serde's default enum representation. */
+export type Event = 
+	/** Nothing happened */
+	| "idle"
+	| { renamed: string }
+	| { added: ItemDetails }
+	| { removed: ItemDetails | null }
+	| { moved: {
+	from: number;
+	to: number;
+}};
+
@@ -0,0 +1,39 @@
+#[typeshare]
+pub struct Metadata {
+    pub created_by: String,
+    pub version: u32,
+}
+
+#[typeshare]
+pub struct Audit {
+    pub reviewed: bool,
+}
+
+/// A document with its metadata serialized inline.
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct Document {
+    pub title: String,
+    pub body: Option<String>,
+    #[serde(flatten)]
+    pub metadata: Metadata,
+    #[serde(flatten)]
+    pub audit: Option<Audit>,
+}
+
+#[typeshare]
+pub struct MetadataOnly {
+    #[serde(flatten)]
+    pub metadata: Metadata,
+}
+
+#[typeshare]
+#[serde(tag = "type", content = "content")]
+pub enum Event {
+    Created {
+        id: String,
+        #[serde(flatten)]
+        metadata: Metadata,
+    },
+    Deleted,
+}
@@ -0,0 +1,88 @@
+package proto
+
+import "encoding/json"
+
+type Audit struct {
+	Reviewed bool `json:"reviewed"`
+}
+type Metadata struct {
+	CreatedBy string `json:"created_by"`
+	Version uint32 `json:"version"`
+}
+// A document with its metadata serialized inline.
+type Document struct {
+	Title string `json:"title"`
+	Body *string `json:"body,omitempty"`
+	Metadata
+	*Audit
+}
+type MetadataOnly struct {
+	Metadata
+}
+// Generated type representing the anonymous struct variant `Created` of the `Event` Rust enum
+type EventCreatedInner struct {
+	Id string `json:"id"`
+	Metadata
+}
+type EventTypes string
+const (
+	EventTypeVariantCreated EventTypes = "Created"
+	EventTypeVariantDeleted EventTypes = "Deleted"
+)
+type Event struct{ 
+	Type EventTypes `json:"type"`
+	content interface{}
+}
+
+func (e *Event) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    EventTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	e.Type = enum.Tag
+	switch e.Type {
+	case EventTypeVariantCreated:
+		var res EventCreatedInner
+		e.content = &res
+	case EventTypeVariantDeleted:
+		return nil
+
+	}
+	if err := json.Unmarshal(enum.Content, &e.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (e Event) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    EventTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = e.Type
+    enum.Content = e.content
+    return json.Marshal(enum)
+}
+
+func (e Event) Created() *EventCreatedInner {
+	res, _ := e.content.(*EventCreatedInner)
+	return res
+}
+
+func NewEventTypeVariantCreated(content *EventCreatedInner) Event {
+    return Event{
+        Type: EventTypeVariantCreated,
+        content: content,
+    }
+}
+func NewEventTypeVariantDeleted() Event {
+    return Event{
+        Type: EventTypeVariantDeleted,
+    }
+}
+
@@ -0,0 +1,50 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+@Serializable
+data class Audit (
+	val reviewed: Boolean
+)
+
+/// A document with its metadata serialized inline.
+@Serializable
+data class Document (
+	val title: String,
+	val body: String? = null,
+	val created_by: String,
+	val version: UInt,
+	val reviewed: Boolean? = null
+)
+
+@Serializable
+data class Metadata (
+	val created_by: String,
+	val version: UInt
+)
+
+@Serializable
+data class MetadataOnly (
+	val created_by: String,
+	val version: UInt
+)
+
+/// Generated type representing the anonymous struct variant `Created` of the `Event` Rust enum
+@Serializable
+data class EventCreatedInner (
+	val id: String,
+	val created_by: String,
+	val version: UInt
+)
+
+@Serializable
+sealed class Event {
+	@Serializable
+	@SerialName("Created")
+	data class Created(val content: EventCreatedInner): Event()
+	@Serializable
+	@SerialName("Deleted")
+	object Deleted: Event()
+}
+
@@ -0,0 +1,55 @@
+package com.agilebits
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Long
+
+}
+package onepassword {
+
+case class Audit (
+	reviewed: Boolean
+)
+
+// A document with its metadata serialized inline.
+case class Document (
+	title: String,
+	body: Option[String] = None,
+	created_by: String,
+	version: UInt,
+	reviewed: Option[Boolean] = None
+)
+
+case class Metadata (
+	created_by: String,
+	version: UInt
+)
+
+case class MetadataOnly (
+	created_by: String,
+	version: UInt
+)
+
+// Generated type representing the anonymous struct variant `Created` of the `Event` Rust enum
+case class EventCreatedInner (
+	id: String,
+	created_by: String,
+	version: UInt
+)
+
+sealed trait Event {
+	def serialName: String
+}
+object Event {
+	case class Created(content: EventCreatedInner) extends Event {
+		val serialName: String = "Created"
+	}
+	case object Deleted extends Event {
+		val serialName: String = "Deleted"
+	}
+}
+
+}
@@ -0,0 +1,139 @@
+import Foundation
+
+public struct Audit: Codable {
+	public let reviewed: Bool
+
+	public init(reviewed: Bool) {
+		self.reviewed = reviewed
+	}
+}
+
+public struct Metadata: Codable {
+	public let created_by: String
+	public let version: UInt32
+
+	public init(created_by: String, version: UInt32) {
+		self.created_by = created_by
+		self.version = version
+	}
+}
+
+/// A document with its metadata serialized inline.
+public struct Document: Codable {
+	public let title: String
+	public let body: String?
+	public let metadata: Metadata
+	public let audit: Audit?
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case title,
+			body
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		self.title = try container.decode(String.self, forKey: .title)
+		self.body = try container.decodeIfPresent(String.self, forKey: .body)
+		self.metadata = try Metadata(from: decoder)
+		self.audit = try? Audit(from: decoder)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: CodingKeys.self)
+		try container.encode(self.title, forKey: .title)
+		try container.encodeIfPresent(self.body, forKey: .body)
+		try self.metadata.encode(to: encoder)
+		try self.audit?.encode(to: encoder)
+	}
+
+	public init(title: String, body: String?, metadata: Metadata, audit: Audit?) {
+		self.title = title
+		self.body = body
+		self.metadata = metadata
+		self.audit = audit
+	}
+}
+
+public struct MetadataOnly: Codable {
+	public let metadata: Metadata
+
+	public init(from decoder: Decoder) throws {
+		self.metadata = try Metadata(from: decoder)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		try self.metadata.encode(to: encoder)
+	}
+
+	public init(metadata: Metadata) {
+		self.metadata = metadata
+	}
+}
+
+
+/// Generated type representing the anonymous struct variant `Created` of the `Event` Rust enum
+public struct EventCreatedInner: Codable {
+	public let id: String
+	public let metadata: Metadata
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case id
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		self.id = try container.decode(String.self, forKey: .id)
+		self.metadata = try Metadata(from: decoder)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: CodingKeys.self)
+		try container.encode(self.id, forKey: .id)
+		try self.metadata.encode(to: encoder)
+	}
+
+	public init(id: String, metadata: Metadata) {
+		self.id = id
+		self.metadata = metadata
+	}
+}
+public enum Event: Codable {
+	case created(EventCreatedInner)
+	case deleted
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case created = "Created",
+			deleted = "Deleted"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case type, content
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
+			switch type {
+			case .created:
+				let content = try container.decode(EventCreatedInner.self, forKey: .content)
+				self = .created(content)
+				return
+			case .deleted:
+				self = .deleted
+				return
+			}
+		}
+		throw DecodingError.typeMismatch(Event.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Event"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .created(let content):
+			try container.encode(CodingKeys.created, forKey: .type)
+			try container.encode(content, forKey: .content)
+		case .deleted:
+			try container.encode(CodingKeys.deleted, forKey: .type)
+		}
+	}
+}
@@ -0,0 +1,23 @@
+export interface Audit {
+	reviewed: boolean;
+}
+
+export interface Metadata {
+	created_by: string;
+	version: number;
+}
+
+/** A document with its metadata serialized inline. */
+export type Document = {
+	title: string;
+	body?: string;
+} & Metadata & Partial<Audit>;
+
+export type MetadataOnly = {} & Metadata;
+
+export type Event = 
+	| { type: "Created", content: {
+	id: string;
+} & Metadata}
+	| { type: "Deleted", content?: undefined };
+
@@ -0,0 +1,39 @@
+#[typeshare]
+pub struct Labels {
+    pub owner: String,
+}
+
+/// A resource with properties that aren't known in advance.
+#[typeshare]
+pub struct Resource {
+    pub name: String,
+    pub revision: Option<u32>,
+    #[serde(flatten)]
+    pub labels: Labels,
+    #[serde(flatten)]
+    pub properties: HashMap<String, String>,
+}
+
+#[typeshare]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u32,
+}
+
+#[typeshare]
+pub struct ResourcePage {
+    pub cursor: Option<String>,
+    #[serde(flatten)]
+    pub page: Page<Resource>,
+}
+
+#[typeshare]
+#[serde(tag = "type")]
+pub enum Change {
+    Renamed {
+        name: String,
+        #[serde(flatten)]
+        properties: BTreeMap<String, String>,
+    },
+    Deleted,
+}
@@ -0,0 +1,199 @@
+package proto
+
+import "encoding/json"
+
+type Labels struct {
+	Owner string `json:"owner"`
+}
+type Page[T any] struct {
+	Items []T `json:"items"`
+	Total uint32 `json:"total"`
+}
+// A resource with properties that aren't known in advance.
+type Resource struct {
+	Name string `json:"name"`
+	Revision *uint32 `json:"revision,omitempty"`
+	Labels
+	Properties map[string]string `json:"-"`
+}
+
+// The fields of Resource, without its JSON encoding
+type resourceFields Resource
+
+func (r *Resource) UnmarshalJSON(data []byte) error {
+	if err := json.Unmarshal(data, (*resourceFields)(r)); err != nil {
+		return err
+	}
+	var rest map[string]json.RawMessage
+	if err := json.Unmarshal(data, &rest); err != nil {
+		return err
+	}
+	// The keys of the other fields don't belong in the flattened maps
+	for _, key := range []string{"name", "revision", "owner"} {
+		delete(rest, key)
+	}
+	restData, err := json.Marshal(rest)
+	if err != nil {
+		return err
+	}
+	if err := json.Unmarshal(restData, &r.Properties); err != nil {
+		return err
+	}
+	return nil
+}
+
+func (r Resource) MarshalJSON() ([]byte, error) {
+	object := map[string]json.RawMessage{}
+	for _, entries := range []interface{}{r.Properties} {
+		data, err := json.Marshal(entries)
+		if err != nil {
+			return nil, err
+		}
+		if err := json.Unmarshal(data, &object); err != nil {
+			return nil, err
+		}
+	}
+	data, err := json.Marshal(resourceFields(r))
+	if err != nil {
+		return nil, err
+	}
+	if err := json.Unmarshal(data, &object); err != nil {
+		return nil, err
+	}
+	return json.Marshal(object)
+}
+type ResourcePage struct {
+	Cursor *string `json:"cursor,omitempty"`
+	Page[Resource]
+}
+// Generated type representing the anonymous struct variant `Renamed` of the `Change` Rust enum
+type ChangeRenamedInner struct {
+	Name string `json:"name"`
+	Properties map[string]string `json:"-"`
+}
+
+// The fields of ChangeRenamedInner, without its JSON encoding
+type changeRenamedInnerFields ChangeRenamedInner
+
+func (c *ChangeRenamedInner) UnmarshalJSON(data []byte) error {
+	if err := json.Unmarshal(data, (*changeRenamedInnerFields)(c)); err != nil {
+		return err
+	}
+	var rest map[string]json.RawMessage
+	if err := json.Unmarshal(data, &rest); err != nil {
+		return err
+	}
+	// The keys of the other fields don't belong in the flattened maps
+	for _, key := range []string{"name"} {
+		delete(rest, key)
+	}
+	restData, err := json.Marshal(rest)
+	if err != nil {
+		return err
+	}
+	if err := json.Unmarshal(restData, &c.Properties); err != nil {
+		return err
+	}
+	return nil
+}
+
+func (c ChangeRenamedInner) MarshalJSON() ([]byte, error) {
+	object := map[string]json.RawMessage{}
+	for _, entries := range []interface{}{c.Properties} {
+		data, err := json.Marshal(entries)
+		if err != nil {
+			return nil, err
+		}
+		if err := json.Unmarshal(data, &object); err != nil {
+			return nil, err
+		}
+	}
+	data, err := json.Marshal(changeRenamedInnerFields(c))
+	if err != nil {
+		return nil, err
+	}
+	if err := json.Unmarshal(data, &object); err != nil {
+		return nil, err
+	}
+	return json.Marshal(object)
+}
+type ChangeTypes string
+const (
+	ChangeTypeVariantRenamed ChangeTypes = "Renamed"
+	ChangeTypeVariantDeleted ChangeTypes = "Deleted"
+)
+type Change struct{ 
+	Type ChangeTypes `json:"type"`
+	content interface{}
+}
+
+func (c *Change) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    ChangeTypes   `json:"type"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	c.Type = enum.Tag
+	switch c.Type {
+	case ChangeTypeVariantRenamed:
+		var res ChangeRenamedInner
+		c.content = &res
+	case ChangeTypeVariantDeleted:
+		return nil
+
+	}
+	// The tag isn't part of the content, which may collect unknown keys
+	var fields map[string]json.RawMessage
+	if err := json.Unmarshal(data, &fields); err != nil {
+		return err
+	}
+	delete(fields, "type")
+	content, err := json.Marshal(fields)
+	if err != nil {
+		return err
+	}
+	if err := json.Unmarshal(content, &c.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (c Change) MarshalJSON() ([]byte, error) {
+	fields := map[string]json.RawMessage{}
+	if c.content != nil {
+		content, err := json.Marshal(c.content)
+		if err != nil {
+			return nil, err
+		}
+		if err := json.Unmarshal(content, &fields); err != nil {
+			return nil, err
+		}
+	}
+	tag, err := json.Marshal(c.Type)
+	if err != nil {
+		return nil, err
+	}
+	fields["type"] = tag
+	return json.Marshal(fields)
+}
+
+func (c Change) Renamed() *ChangeRenamedInner {
+	res, _ := c.content.(*ChangeRenamedInner)
+	return res
+}
+
+func NewChangeTypeVariantRenamed(content *ChangeRenamedInner) Change {
+    return Change{
+        Type: ChangeTypeVariantRenamed,
+        content: content,
+    }
+}
+func NewChangeTypeVariantDeleted() Change {
+    return Change{
+        Type: ChangeTypeVariantDeleted,
+    }
+}
+
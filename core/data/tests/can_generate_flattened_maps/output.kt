@@ -0,0 +1,124 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Labels (
+	val owner: String
+)
+
+@Serializable
+data class Page<T> (
+	val items: List<T>,
+	val total: UInt
+)
+
+/// A resource with properties that aren't known in advance.
+@Serializable(with = ResourceSerializer::class)
+data class Resource (
+	val name: String,
+	val revision: UInt? = null,
+	val owner: String,
+	val properties: HashMap<String, String>
+)
+
+object ResourceSerializer : KSerializer<Resource> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Resource")
+
+	override fun serialize(encoder: Encoder, value: Resource) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonObject {
+			put("name", output.json.encodeToJsonElement(serializer<String>(), value.name))
+			value.revision?.let { put("revision", output.json.encodeToJsonElement(serializer<UInt?>(), it)) }
+			put("owner", output.json.encodeToJsonElement(serializer<String>(), value.owner))
+			output.json.encodeToJsonElement(serializer<HashMap<String, String>>(), value.properties).jsonObject.forEach { (key, element) -> put(key, element) }
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Resource {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement().jsonObject
+		val rest = JsonObject(element - setOf<String>("name", "revision", "owner"))
+		return Resource(
+			name = input.json.decodeFromJsonElement(serializer<String>(), element["name"] ?: throw SerializationException("Missing field name of Resource")),
+			revision = element["revision"]?.let { input.json.decodeFromJsonElement(serializer<UInt?>(), it) },
+			owner = input.json.decodeFromJsonElement(serializer<String>(), element["owner"] ?: throw SerializationException("Missing field owner of Resource")),
+			properties = input.json.decodeFromJsonElement(serializer<HashMap<String, String>>(), rest),
+		)
+	}
+}
+
+@Serializable
+data class ResourcePage (
+	val cursor: String? = null,
+	val items: List<Resource>,
+	val total: UInt
+)
+
+@Serializable(with = ChangeSerializer::class)
+sealed class Change {
+	@Serializable(with = ChangeRenamedSerializer::class)
+	@SerialName("Renamed")
+	data class Renamed(
+		val name: String,
+		val properties: Map<String, String>
+	): Change()
+	@Serializable
+	@SerialName("Deleted")
+	object Deleted: Change()
+}
+
+object ChangeSerializer : KSerializer<Change> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Change")
+
+	override fun serialize(encoder: Encoder, value: Change) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is Change.Renamed -> JsonObject(mapOf("type" to JsonPrimitive("Renamed")) + output.json.encodeToJsonElement(Change.Renamed.serializer(), value).jsonObject)
+			is Change.Deleted -> buildJsonObject { put("type", "Deleted") }
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Change {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		when ((element as? JsonObject)?.get("type")?.jsonPrimitive?.contentOrNull) {
+			"Renamed" -> return input.json.decodeFromJsonElement(Change.Renamed.serializer(), JsonObject(element.jsonObject - "type"))
+			"Deleted" -> return Change.Deleted
+		}
+		throw SerializationException("Unknown variant for Change: $element")
+	}
+}
+
+object ChangeRenamedSerializer : KSerializer<Change.Renamed> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Change.Renamed")
+
+	override fun serialize(encoder: Encoder, value: Change.Renamed) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonObject {
+			put("name", output.json.encodeToJsonElement(serializer<String>(), value.name))
+			output.json.encodeToJsonElement(serializer<Map<String, String>>(), value.properties).jsonObject.forEach { (key, element) -> put(key, element) }
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Change.Renamed {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement().jsonObject
+		val rest = JsonObject(element - setOf<String>("name"))
+		return Change.Renamed(
+			name = input.json.decodeFromJsonElement(serializer<String>(), element["name"] ?: throw SerializationException("Missing field name of Change.Renamed")),
+			properties = input.json.decodeFromJsonElement(serializer<Map<String, String>>(), rest),
+		)
+	}
+}
+
@@ -0,0 +1,51 @@
+package com.agilebits
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Long
+
+}
+package onepassword {
+
+case class Labels (
+	owner: String
+)
+
+case class Page[T] (
+	items: Vector[T],
+	total: UInt
+)
+
+// A resource with properties that aren't known in advance.
+case class Resource (
+	name: String,
+	revision: Option[UInt] = None,
+	owner: String,
+	properties: Map[String, String]
+)
+
+case class ResourcePage (
+	cursor: Option[String] = None,
+	items: Vector[Resource],
+	total: UInt
+)
+
+sealed trait Change {
+	def serialName: String
+}
+object Change {
+	case class Renamed(
+		name: String,
+		properties: Map[String, String]
+	) extends Change {
+		val serialName: String = "Renamed"
+	}
+	case object Deleted extends Change {
+		val serialName: String = "Deleted"
+	}
+}
+
+}
@@ -0,0 +1,189 @@
+import Foundation
+
+public struct Labels: Codable {
+	public let owner: String
+
+	public init(owner: String) {
+		self.owner = owner
+	}
+}
+
+public struct Page<T: Codable>: Codable {
+	public let items: [T]
+	public let total: UInt32
+
+	public init(items: [T], total: UInt32) {
+		self.items = items
+		self.total = total
+	}
+}
+
+/// A resource with properties that aren't known in advance.
+public struct Resource: Codable {
+	public let name: String
+	public let revision: UInt32?
+	public let labels: Labels
+	public let properties: [String: String]
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case name,
+			revision
+	}
+
+	private static let fieldKeys: Set<String> = ["name", "revision", "owner"]
+
+	/// Any key of the JSON object, for the flattened maps
+	private struct AnyKey: CodingKey {
+		var stringValue: String
+		var intValue: Int? { nil }
+
+		init(stringValue: String) {
+			self.stringValue = stringValue
+		}
+
+		init?(intValue: Int) {
+			return nil
+		}
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		let rest = try decoder.container(keyedBy: AnyKey.self)
+		self.name = try container.decode(String.self, forKey: .name)
+		self.revision = try container.decodeIfPresent(UInt32.self, forKey: .revision)
+		self.labels = try Labels(from: decoder)
+		self.properties = try Dictionary(uniqueKeysWithValues: rest.allKeys.filter { !Self.fieldKeys.contains($0.stringValue) }.map { ($0.stringValue, try rest.decode(String.self, forKey: $0)) })
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: CodingKeys.self)
+		var rest = encoder.container(keyedBy: AnyKey.self)
+		try container.encode(self.name, forKey: .name)
+		try container.encodeIfPresent(self.revision, forKey: .revision)
+		try self.labels.encode(to: encoder)
+		for (key, value) in self.properties {
+			try rest.encode(value, forKey: AnyKey(stringValue: key))
+		}
+	}
+
+	public init(name: String, revision: UInt32?, labels: Labels, properties: [String: String]) {
+		self.name = name
+		self.revision = revision
+		self.labels = labels
+		self.properties = properties
+	}
+}
+
+public struct ResourcePage: Codable {
+	public let cursor: String?
+	public let page: Page<Resource>
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case cursor
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		self.cursor = try container.decodeIfPresent(String.self, forKey: .cursor)
+		self.page = try Page<Resource>(from: decoder)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: CodingKeys.self)
+		try container.encodeIfPresent(self.cursor, forKey: .cursor)
+		try self.page.encode(to: encoder)
+	}
+
+	public init(cursor: String?, page: Page<Resource>) {
+		self.cursor = cursor
+		self.page = page
+	}
+}
+
+
+/// Generated type representing the anonymous struct variant `Renamed` of the `Change` Rust enum
+public struct ChangeRenamedInner: Codable {
+	public let name: String
+	public let properties: [String: String]
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case name
+	}
+
+	private static let fieldKeys: Set<String> = ["name", "type"]
+
+	/// Any key of the JSON object, for the flattened maps
+	private struct AnyKey: CodingKey {
+		var stringValue: String
+		var intValue: Int? { nil }
+
+		init(stringValue: String) {
+			self.stringValue = stringValue
+		}
+
+		init?(intValue: Int) {
+			return nil
+		}
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		let rest = try decoder.container(keyedBy: AnyKey.self)
+		self.name = try container.decode(String.self, forKey: .name)
+		self.properties = try Dictionary(uniqueKeysWithValues: rest.allKeys.filter { !Self.fieldKeys.contains($0.stringValue) }.map { ($0.stringValue, try rest.decode(String.self, forKey: $0)) })
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: CodingKeys.self)
+		var rest = encoder.container(keyedBy: AnyKey.self)
+		try container.encode(self.name, forKey: .name)
+		for (key, value) in self.properties {
+			try rest.encode(value, forKey: AnyKey(stringValue: key))
+		}
+	}
+
+	public init(name: String, properties: [String: String]) {
+		self.name = name
+		self.properties = properties
+	}
+}
+public enum Change: Codable {
+	case renamed(ChangeRenamedInner)
+	case deleted
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case renamed = "Renamed",
+			deleted = "Deleted"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case type
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
+			switch type {
+			case .renamed:
+				let content = try ChangeRenamedInner(from: decoder)
+				self = .renamed(content)
+				return
+			case .deleted:
+				self = .deleted
+				return
+			}
+		}
+		throw DecodingError.typeMismatch(Change.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Change"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .renamed(let content):
+			try container.encode(CodingKeys.renamed, forKey: .type)
+			try content.encode(to: encoder)
+		case .deleted:
+			try container.encode(CodingKeys.deleted, forKey: .type)
+		}
+	}
+}
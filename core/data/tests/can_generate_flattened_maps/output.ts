@@ -0,0 +1,25 @@
+export interface Labels {
+	owner: string;
+}
+
+export interface Page<T> {
+	items: T[];
+	total: number;
+}
+
+/** A resource with properties that aren't known in advance. */
+export type Resource = {
+	name: string;
+	revision?: number;
+} & Labels & Record<string, string>;
+
+export type ResourcePage = {
+	cursor?: string;
+} & Page<Resource>;
+
+export type Change = 
+	| { type: "Renamed" } & {
+	name: string;
+} & Record<string, string>
+	| { type: "Deleted" };
+
@@ -18,15 +18,13 @@ public enum CoreGenericEnum<A: Codable, B: Codable>: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .variantA:
-				if let content = try? container.decode(A.self, forKey: .content) {
-					self = .variantA(content)
-					return
-				}
+				let content = try container.decode(A.self, forKey: .content)
+				self = .variantA(content)
+				return
 			case .variantB:
-				if let content = try? container.decode(B.self, forKey: .content) {
-					self = .variantB(content)
-					return
-				}
+				let content = try container.decode(B.self, forKey: .content)
+				self = .variantB(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(CoreGenericEnum.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for CoreGenericEnum"))
@@ -73,20 +71,17 @@ public enum CoreGenericEnumUsingGenericEnum<T: Codable>: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .variantC:
-				if let content = try? container.decode(CoreGenericEnum<T, T>.self, forKey: .content) {
-					self = .variantC(content)
-					return
-				}
+				let content = try container.decode(CoreGenericEnum<T, T>.self, forKey: .content)
+				self = .variantC(content)
+				return
 			case .variantD:
-				if let content = try? container.decode(CoreGenericEnum<String, [String: T]>.self, forKey: .content) {
-					self = .variantD(content)
-					return
-				}
+				let content = try container.decode(CoreGenericEnum<String, [String: T]>.self, forKey: .content)
+				self = .variantD(content)
+				return
 			case .variantE:
-				if let content = try? container.decode(CoreGenericEnum<String, UInt32>.self, forKey: .content) {
-					self = .variantE(content)
-					return
-				}
+				let content = try container.decode(CoreGenericEnum<String, UInt32>.self, forKey: .content)
+				self = .variantE(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(CoreGenericEnumUsingGenericEnum.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for CoreGenericEnumUsingGenericEnum"))
@@ -170,25 +165,21 @@ public enum CoreGenericEnumsUsingStructVariants<T: Codable, U: Codable>: Codable
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .variantF:
-				if let content = try? container.decode(CoreGenericEnumsUsingStructVariantsVariantFInner<T>.self, forKey: .content) {
-					self = .variantF(content)
-					return
-				}
+				let content = try container.decode(CoreGenericEnumsUsingStructVariantsVariantFInner<T>.self, forKey: .content)
+				self = .variantF(content)
+				return
 			case .variantG:
-				if let content = try? container.decode(CoreGenericEnumsUsingStructVariantsVariantGInner<T, U>.self, forKey: .content) {
-					self = .variantG(content)
-					return
-				}
+				let content = try container.decode(CoreGenericEnumsUsingStructVariantsVariantGInner<T, U>.self, forKey: .content)
+				self = .variantG(content)
+				return
 			case .variantH:
-				if let content = try? container.decode(CoreGenericEnumsUsingStructVariantsVariantHInner.self, forKey: .content) {
-					self = .variantH(content)
-					return
-				}
+				let content = try container.decode(CoreGenericEnumsUsingStructVariantsVariantHInner.self, forKey: .content)
+				self = .variantH(content)
+				return
 			case .variantI:
-				if let content = try? container.decode(CoreGenericEnumsUsingStructVariantsVariantIInner<T, U>.self, forKey: .content) {
-					self = .variantI(content)
-					return
-				}
+				let content = try container.decode(CoreGenericEnumsUsingStructVariantsVariantIInner<T, U>.self, forKey: .content)
+				self = .variantI(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(CoreGenericEnumsUsingStructVariants.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for CoreGenericEnumsUsingStructVariants"))
@@ -0,0 +1,6 @@
+#[typeshare]
+pub enum Payload<T> {
+    Empty,
+    Value(T),
+    Values(Vec<T>),
+}
@@ -0,0 +1,87 @@
+package proto
+
+import "encoding/json"
+import "fmt"
+
+type PayloadTypes string
+const (
+	PayloadTypeVariantEmpty PayloadTypes = "Empty"
+	PayloadTypeVariantValue PayloadTypes = "Value"
+	PayloadTypeVariantValues PayloadTypes = "Values"
+)
+type Payload[T any] struct{ 
+	Type PayloadTypes `json:"type"`
+	content interface{}
+}
+
+func (p *Payload[T]) UnmarshalJSON(data []byte) error {
+	var tag PayloadTypes
+	if err := json.Unmarshal(data, &tag); err == nil {
+		p.Type = tag
+		p.content = nil
+		return nil
+	}
+
+	var enum map[PayloadTypes]json.RawMessage
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+	if len(enum) != 1 {
+		return fmt.Errorf("expected a single variant for Payload, got %d", len(enum))
+	}
+
+	for tag, content := range enum {
+		p.Type = tag
+		switch p.Type {
+		case PayloadTypeVariantEmpty:
+			return nil
+		case PayloadTypeVariantValue:
+			var res T
+			p.content = &res
+		case PayloadTypeVariantValues:
+			var res []T
+			p.content = &res
+
+		}
+		if err := json.Unmarshal(content, &p.content); err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+func (p Payload[T]) MarshalJSON() ([]byte, error) {
+	if p.content == nil {
+		return json.Marshal(p.Type)
+	}
+	return json.Marshal(map[PayloadTypes]interface{}{p.Type: p.content})
+}
+
+func (p Payload[T]) Value() T {
+	res, _ := p.content.(*T)
+	return *res
+}
+func (p Payload[T]) Values() []T {
+	res, _ := p.content.(*[]T)
+	return *res
+}
+
+func NewPayloadTypeVariantEmpty[T any]() Payload[T] {
+    return Payload[T]{
+        Type: PayloadTypeVariantEmpty,
+    }
+}
+func NewPayloadTypeVariantValue[T any](content T) Payload[T] {
+    return Payload[T]{
+        Type: PayloadTypeVariantValue,
+        content: &content,
+    }
+}
+func NewPayloadTypeVariantValues[T any](content []T) Payload[T] {
+    return Payload[T]{
+        Type: PayloadTypeVariantValues,
+        content: &content,
+    }
+}
+
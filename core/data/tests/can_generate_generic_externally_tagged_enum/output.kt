@@ -0,0 +1,56 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable(with = PayloadSerializer::class)
+sealed class Payload<T> {
+	@Serializable
+	@SerialName("Empty")
+	object Empty: Payload<T>()
+	@Serializable
+	@SerialName("Value")
+	data class Value<T>(val content: T): Payload<T>()
+	@Serializable
+	@SerialName("Values")
+	data class Values<T>(val content: List<T>): Payload<T>()
+}
+
+class PayloadSerializer<T>(private val typeSerial0: KSerializer<T>) : KSerializer<Payload<T>> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Payload")
+
+	override fun serialize(encoder: Encoder, value: Payload<T>) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is Payload.Empty -> JsonPrimitive("Empty")
+			is Payload.Value -> buildJsonObject { put("Value", output.json.encodeToJsonElement(typeSerial0, value.content)) }
+			is Payload.Values -> buildJsonObject { put("Values", output.json.encodeToJsonElement(ListSerializer(typeSerial0), value.content)) }
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Payload<T> {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		if (element is JsonPrimitive && element.isString) {
+			when (element.content) {
+				"Empty" -> return Payload.Empty
+			}
+		}
+		if (element is JsonObject && element.size == 1) {
+			val (key, content) = element.entries.first()
+			when (key) {
+				"Value" -> return Payload.Value(input.json.decodeFromJsonElement(typeSerial0, content))
+				"Values" -> return Payload.Values(input.json.decodeFromJsonElement(ListSerializer(typeSerial0), content))
+			}
+		}
+		throw SerializationException("Unknown variant for Payload: $element")
+	}
+}
+
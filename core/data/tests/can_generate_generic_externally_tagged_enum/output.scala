@@ -0,0 +1,20 @@
+package com.agilebits
+
+package onepassword {
+
+sealed trait Payload[T] {
+	def serialName: String
+}
+object Payload {
+	case object Empty extends Payload[T] {
+		val serialName: String = "Empty"
+	}
+	case class Value[T](content: T) extends Payload[T] {
+		val serialName: String = "Value"
+	}
+	case class Values[T](content: Vector[T]) extends Payload[T] {
+		val serialName: String = "Values"
+	}
+}
+
+}
@@ -0,0 +1,54 @@
+import Foundation
+
+public enum Payload<T: Codable>: Codable {
+	case empty
+	case value(T)
+	case values([T])
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case empty = "Empty",
+			value = "Value",
+			values = "Values"
+	}
+
+	public init(from decoder: Decoder) throws {
+		if let container = try? decoder.singleValueContainer(), let type = try? container.decode(CodingKeys.self) {
+			switch type {
+			case .empty:
+				self = .empty
+				return
+			default:
+				break
+			}
+		}
+		if let container = try? decoder.container(keyedBy: CodingKeys.self), let type = container.allKeys.first {
+			switch type {
+			case .value:
+				let content = try container.decode(T.self, forKey: .value)
+				self = .value(content)
+				return
+			case .values:
+				let content = try container.decode([T].self, forKey: .values)
+				self = .values(content)
+				return
+			default:
+				break
+			}
+		}
+		throw DecodingError.typeMismatch(Payload.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Payload"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		switch self {
+		case .empty:
+			var container = encoder.singleValueContainer()
+			try container.encode(CodingKeys.empty)
+		case .value(let content):
+			var container = encoder.container(keyedBy: CodingKeys.self)
+			try container.encode(content, forKey: .value)
+		case .values(let content):
+			var container = encoder.container(keyedBy: CodingKeys.self)
+			try container.encode(content, forKey: .values)
+		}
+	}
+}
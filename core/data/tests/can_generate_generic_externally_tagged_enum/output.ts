@@ -0,0 +1,5 @@
+export type Payload<T> = 
+	| "Empty"
+	| { Value: T }
+	| { Values: T[] };
+
@@ -44,25 +44,21 @@ public enum CoreEnumUsingGenericStruct: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .variantA:
-				if let content = try? container.decode(CoreGenericStruct<String, Float>.self, forKey: .content) {
-					self = .variantA(content)
-					return
-				}
+				let content = try container.decode(CoreGenericStruct<String, Float>.self, forKey: .content)
+				self = .variantA(content)
+				return
 			case .variantB:
-				if let content = try? container.decode(CoreGenericStruct<String, Int32>.self, forKey: .content) {
-					self = .variantB(content)
-					return
-				}
+				let content = try container.decode(CoreGenericStruct<String, Int32>.self, forKey: .content)
+				self = .variantB(content)
+				return
 			case .variantC:
-				if let content = try? container.decode(CoreGenericStruct<String, Bool>.self, forKey: .content) {
-					self = .variantC(content)
-					return
-				}
+				let content = try container.decode(CoreGenericStruct<String, Bool>.self, forKey: .content)
+				self = .variantC(content)
+				return
 			case .variantD:
-				if let content = try? container.decode(CoreGenericStructUsingGenericStruct<CodableVoid>.self, forKey: .content) {
-					self = .variantD(content)
-					return
-				}
+				let content = try container.decode(CoreGenericStructUsingGenericStruct<CodableVoid>.self, forKey: .content)
+				self = .variantD(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(CoreEnumUsingGenericStruct.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for CoreEnumUsingGenericStruct"))
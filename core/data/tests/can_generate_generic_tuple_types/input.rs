@@ -0,0 +1,7 @@
+#[typeshare]
+pub struct Labeled<T>(String, T);
+
+#[typeshare]
+pub struct Timeline<T> {
+    pub events: Vec<(u32, T)>,
+}
@@ -0,0 +1,63 @@
+package proto
+
+import "encoding/json"
+import "fmt"
+
+type Labeled[T any] struct {
+	Field0 string
+	Field1 T
+}
+
+func (l *Labeled[T]) UnmarshalJSON(data []byte) error {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return err
+	}
+	if len(elements) != 2 {
+		return fmt.Errorf("expected 2 elements for Labeled, got %d", len(elements))
+	}
+	if err := json.Unmarshal(elements[0], &l.Field0); err != nil {
+		return err
+	}
+	if err := json.Unmarshal(elements[1], &l.Field1); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (l Labeled[T]) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]interface{}{l.Field0, l.Field1})
+}
+
+// Generated type representing a tuple in the `Timeline` Rust type
+type TimelineEventsTuple[T any] struct {
+	Field0 uint32
+	Field1 T
+}
+
+func (t *TimelineEventsTuple[T]) UnmarshalJSON(data []byte) error {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return err
+	}
+	if len(elements) != 2 {
+		return fmt.Errorf("expected 2 elements for TimelineEventsTuple, got %d", len(elements))
+	}
+	if err := json.Unmarshal(elements[0], &t.Field0); err != nil {
+		return err
+	}
+	if err := json.Unmarshal(elements[1], &t.Field1); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (t TimelineEventsTuple[T]) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]interface{}{t.Field0, t.Field1})
+}
+
+type Timeline[T any] struct {
+	Events []TimelineEventsTuple[T] `json:"events"`
+}
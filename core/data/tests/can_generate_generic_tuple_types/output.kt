@@ -0,0 +1,72 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable(with = LabeledSerializer::class)
+data class Labeled<T> (
+	val _0: String,
+	val _1: T
+)
+
+class LabeledSerializer<T>(private val typeSerial0: KSerializer<T>) : KSerializer<Labeled<T>> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Labeled")
+
+	override fun serialize(encoder: Encoder, value: Labeled<T>) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonArray {
+			add(output.json.encodeToJsonElement(serializer<String>(), value._0))
+			add(output.json.encodeToJsonElement(typeSerial0, value._1))
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Labeled<T> {
+		val input = decoder as JsonDecoder
+		val elements = input.decodeJsonElement().jsonArray
+		return Labeled(
+			input.json.decodeFromJsonElement(serializer<String>(), elements[0]),
+			input.json.decodeFromJsonElement(typeSerial0, elements[1])
+		)
+	}
+}
+
+/// Generated type representing a tuple in the `Timeline` Rust type
+@Serializable(with = TimelineEventsTupleSerializer::class)
+data class TimelineEventsTuple<T> (
+	val _0: UInt,
+	val _1: T
+)
+
+class TimelineEventsTupleSerializer<T>(private val typeSerial0: KSerializer<T>) : KSerializer<TimelineEventsTuple<T>> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("TimelineEventsTuple")
+
+	override fun serialize(encoder: Encoder, value: TimelineEventsTuple<T>) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonArray {
+			add(output.json.encodeToJsonElement(serializer<UInt>(), value._0))
+			add(output.json.encodeToJsonElement(typeSerial0, value._1))
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): TimelineEventsTuple<T> {
+		val input = decoder as JsonDecoder
+		val elements = input.decodeJsonElement().jsonArray
+		return TimelineEventsTuple(
+			input.json.decodeFromJsonElement(serializer<UInt>(), elements[0]),
+			input.json.decodeFromJsonElement(typeSerial0, elements[1])
+		)
+	}
+}
+
+@Serializable
+data class Timeline<T> (
+	val events: List<TimelineEventsTuple<T>>
+)
+
@@ -0,0 +1,14 @@
+package com.agilebits
+
+package object onepassword {
+
+type Labeled[T] = (String, T)
+
+}
+package onepassword {
+
+case class Timeline[T] (
+	events: Vector[(UInt, T)]
+)
+
+}
@@ -0,0 +1,54 @@
+import Foundation
+
+public struct Labeled<T: Codable>: Codable {
+	public let _0: String
+	public let _1: T
+
+	public init(_ _0: String, _ _1: T) {
+		self._0 = _0
+		self._1 = _1
+	}
+
+	public init(from decoder: Decoder) throws {
+		var container = try decoder.unkeyedContainer()
+		self._0 = try container.decode(String.self)
+		self._1 = try container.decode(T.self)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.unkeyedContainer()
+		try container.encode(_0)
+		try container.encode(_1)
+	}
+}
+
+/// Generated type representing a tuple in the `Timeline` Rust type
+public struct TimelineEventsTuple<T: Codable>: Codable {
+	public let _0: UInt32
+	public let _1: T
+
+	public init(_ _0: UInt32, _ _1: T) {
+		self._0 = _0
+		self._1 = _1
+	}
+
+	public init(from decoder: Decoder) throws {
+		var container = try decoder.unkeyedContainer()
+		self._0 = try container.decode(UInt32.self)
+		self._1 = try container.decode(T.self)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.unkeyedContainer()
+		try container.encode(_0)
+		try container.encode(_1)
+	}
+}
+
+public struct Timeline<T: Codable>: Codable {
+	public let events: [TimelineEventsTuple<T>]
+
+	public init(events: [TimelineEventsTuple<T>]) {
+		self.events = events
+	}
+}
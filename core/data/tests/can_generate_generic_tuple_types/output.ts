@@ -0,0 +1,6 @@
+export type Labeled<T> = [string, T];
+
+export interface Timeline<T> {
+	events: [number, T][];
+}
+
@@ -0,0 +1,6 @@
+#[typeshare]
+#[serde(untagged)]
+pub enum SingleOrMany<T> {
+    Single(T),
+    Many(Vec<T>),
+}
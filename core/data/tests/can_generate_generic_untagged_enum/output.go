@@ -0,0 +1,56 @@
+package proto
+
+import "encoding/json"
+import "fmt"
+
+type SingleOrManyTypes string
+const (
+	SingleOrManyTypeVariantSingle SingleOrManyTypes = "Single"
+	SingleOrManyTypeVariantMany SingleOrManyTypes = "Many"
+)
+type SingleOrMany[T any] struct{ 
+	Type SingleOrManyTypes `json:"type"`
+	content interface{}
+}
+
+func (s *SingleOrMany[T]) UnmarshalJSON(data []byte) error {
+	if res := new(T); json.Unmarshal(data, res) == nil {
+		s.Type = SingleOrManyTypeVariantSingle
+		s.content = res
+		return nil
+	}
+	if res := new([]T); json.Unmarshal(data, res) == nil {
+		s.Type = SingleOrManyTypeVariantMany
+		s.content = res
+		return nil
+	}
+
+	return fmt.Errorf("no variant of SingleOrMany matches %s", data)
+}
+
+func (s SingleOrMany[T]) MarshalJSON() ([]byte, error) {
+	return json.Marshal(s.content)
+}
+
+func (s SingleOrMany[T]) Single() T {
+	res, _ := s.content.(*T)
+	return *res
+}
+func (s SingleOrMany[T]) Many() []T {
+	res, _ := s.content.(*[]T)
+	return *res
+}
+
+func NewSingleOrManyTypeVariantSingle[T any](content T) SingleOrMany[T] {
+    return SingleOrMany[T]{
+        Type: SingleOrManyTypeVariantSingle,
+        content: &content,
+    }
+}
+func NewSingleOrManyTypeVariantMany[T any](content []T) SingleOrMany[T] {
+    return SingleOrMany[T]{
+        Type: SingleOrManyTypeVariantMany,
+        content: &content,
+    }
+}
+
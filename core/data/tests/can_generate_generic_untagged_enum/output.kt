@@ -0,0 +1,42 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable(with = SingleOrManySerializer::class)
+sealed class SingleOrMany<T> {
+	@Serializable
+	@SerialName("Single")
+	data class Single<T>(val content: T): SingleOrMany<T>()
+	@Serializable
+	@SerialName("Many")
+	data class Many<T>(val content: List<T>): SingleOrMany<T>()
+}
+
+class SingleOrManySerializer<T>(private val typeSerial0: KSerializer<T>) : KSerializer<SingleOrMany<T>> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("SingleOrMany")
+
+	override fun serialize(encoder: Encoder, value: SingleOrMany<T>) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is SingleOrMany.Single -> output.json.encodeToJsonElement(typeSerial0, value.content)
+			is SingleOrMany.Many -> output.json.encodeToJsonElement(ListSerializer(typeSerial0), value.content)
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): SingleOrMany<T> {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		runCatching { return SingleOrMany.Single(input.json.decodeFromJsonElement(typeSerial0, element)) }
+		runCatching { return SingleOrMany.Many(input.json.decodeFromJsonElement(ListSerializer(typeSerial0), element)) }
+		throw SerializationException("No variant of SingleOrMany matches $element")
+	}
+}
+
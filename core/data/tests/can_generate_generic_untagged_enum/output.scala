@@ -0,0 +1,17 @@
+package com.agilebits
+
+package onepassword {
+
+sealed trait SingleOrMany[T] {
+	def serialName: String
+}
+object SingleOrMany {
+	case class Single[T](content: T) extends SingleOrMany[T] {
+		val serialName: String = "Single"
+	}
+	case class Many[T](content: Vector[T]) extends SingleOrMany[T] {
+		val serialName: String = "Many"
+	}
+}
+
+}
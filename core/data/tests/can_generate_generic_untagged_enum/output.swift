@@ -0,0 +1,29 @@
+import Foundation
+
+public enum SingleOrMany<T: Codable>: Codable {
+	case single(T)
+	case many([T])
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.singleValueContainer()
+		if let content = try? container.decode(T.self) {
+			self = .single(content)
+			return
+		}
+		if let content = try? container.decode([T].self) {
+			self = .many(content)
+			return
+		}
+		throw DecodingError.typeMismatch(SingleOrMany.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for SingleOrMany"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.singleValueContainer()
+		switch self {
+		case .single(let content):
+			try container.encode(content)
+		case .many(let content):
+			try container.encode(content)
+		}
+	}
+}
@@ -0,0 +1,4 @@
+export type SingleOrMany<T> = 
+	| T
+	| T[];
+
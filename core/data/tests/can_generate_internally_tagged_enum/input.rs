@@ -0,0 +1,15 @@
+#[typeshare]
+pub struct Square {
+    pub side: f64,
+}
+
+/// A shape with the tag stored alongside its fields.
+#[typeshare]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Shape {
+    /// No shape at all
+    Empty,
+    Square(Square),
+    Circle { radius: f64 },
+    Custom(HashMap<String, f64>),
+}
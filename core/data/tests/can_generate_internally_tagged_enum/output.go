@@ -0,0 +1,121 @@
+package proto
+
+import "encoding/json"
+
+type Square struct {
+	Side float64 `json:"side"`
+}
+// Generated type representing the anonymous struct variant `Circle` of the `Shape` Rust enum
+type ShapeCircleInner struct {
+	Radius float64 `json:"radius"`
+}
+// A shape with the tag stored alongside its fields.
+type ShapeKinds string
+const (
+	// No shape at all
+	ShapeKindVariantEmpty ShapeKinds = "empty"
+	ShapeKindVariantSquare ShapeKinds = "square"
+	ShapeKindVariantCircle ShapeKinds = "circle"
+	ShapeKindVariantCustom ShapeKinds = "custom"
+)
+type Shape struct{ 
+	Kind ShapeKinds `json:"kind"`
+	content interface{}
+}
+
+func (s *Shape) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    ShapeKinds   `json:"kind"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	s.Kind = enum.Tag
+	switch s.Kind {
+	case ShapeKindVariantEmpty:
+		return nil
+	case ShapeKindVariantSquare:
+		var res Square
+		s.content = &res
+	case ShapeKindVariantCircle:
+		var res ShapeCircleInner
+		s.content = &res
+	case ShapeKindVariantCustom:
+		var res map[string]float64
+		s.content = &res
+
+	}
+	// The tag isn't part of the content, which may collect unknown keys
+	var fields map[string]json.RawMessage
+	if err := json.Unmarshal(data, &fields); err != nil {
+		return err
+	}
+	delete(fields, "kind")
+	content, err := json.Marshal(fields)
+	if err != nil {
+		return err
+	}
+	if err := json.Unmarshal(content, &s.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (s Shape) MarshalJSON() ([]byte, error) {
+	fields := map[string]json.RawMessage{}
+	if s.content != nil {
+		content, err := json.Marshal(s.content)
+		if err != nil {
+			return nil, err
+		}
+		if err := json.Unmarshal(content, &fields); err != nil {
+			return nil, err
+		}
+	}
+	tag, err := json.Marshal(s.Kind)
+	if err != nil {
+		return nil, err
+	}
+	fields["kind"] = tag
+	return json.Marshal(fields)
+}
+
+func (s Shape) Square() *Square {
+	res, _ := s.content.(*Square)
+	return res
+}
+func (s Shape) Circle() *ShapeCircleInner {
+	res, _ := s.content.(*ShapeCircleInner)
+	return res
+}
+func (s Shape) Custom() map[string]float64 {
+	res, _ := s.content.(*map[string]float64)
+	return *res
+}
+
+func NewShapeKindVariantEmpty() Shape {
+    return Shape{
+        Kind: ShapeKindVariantEmpty,
+    }
+}
+func NewShapeKindVariantSquare(content *Square) Shape {
+    return Shape{
+        Kind: ShapeKindVariantSquare,
+        content: content,
+    }
+}
+func NewShapeKindVariantCircle(content *ShapeCircleInner) Shape {
+    return Shape{
+        Kind: ShapeKindVariantCircle,
+        content: content,
+    }
+}
+func NewShapeKindVariantCustom(content map[string]float64) Shape {
+    return Shape{
+        Kind: ShapeKindVariantCustom,
+        content: &content,
+    }
+}
+
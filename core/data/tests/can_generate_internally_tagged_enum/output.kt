@@ -0,0 +1,89 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Square (
+	val side: Double
+)
+
+/// A shape with the tag stored alongside its fields.
+@Serializable(with = ShapeSerializer::class)
+sealed class Shape {
+	/// No shape at all
+	@Serializable
+	@SerialName("empty")
+	object Empty: Shape()
+	@Serializable
+	@SerialName("square")
+	data class Square(
+		val side: Double
+	): Shape()
+	@Serializable
+	@SerialName("circle")
+	data class Circle(
+		val radius: Double
+	): Shape()
+	@Serializable(with = ShapeCustomSerializer::class)
+	@SerialName("custom")
+	data class Custom(
+		val content: HashMap<String, Double>
+	): Shape()
+}
+
+object ShapeSerializer : KSerializer<Shape> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Shape")
+
+	override fun serialize(encoder: Encoder, value: Shape) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is Shape.Empty -> buildJsonObject { put("kind", "empty") }
+			is Shape.Square -> JsonObject(mapOf("kind" to JsonPrimitive("square")) + output.json.encodeToJsonElement(Shape.Square.serializer(), value).jsonObject)
+			is Shape.Circle -> JsonObject(mapOf("kind" to JsonPrimitive("circle")) + output.json.encodeToJsonElement(Shape.Circle.serializer(), value).jsonObject)
+			is Shape.Custom -> JsonObject(mapOf("kind" to JsonPrimitive("custom")) + output.json.encodeToJsonElement(Shape.Custom.serializer(), value).jsonObject)
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Shape {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		when ((element as? JsonObject)?.get("kind")?.jsonPrimitive?.contentOrNull) {
+			"empty" -> return Shape.Empty
+			"square" -> return input.json.decodeFromJsonElement(Shape.Square.serializer(), JsonObject(element.jsonObject - "kind"))
+			"circle" -> return input.json.decodeFromJsonElement(Shape.Circle.serializer(), JsonObject(element.jsonObject - "kind"))
+			"custom" -> return input.json.decodeFromJsonElement(Shape.Custom.serializer(), JsonObject(element.jsonObject - "kind"))
+		}
+		throw SerializationException("Unknown variant for Shape: $element")
+	}
+}
+
+object ShapeCustomSerializer : KSerializer<Shape.Custom> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Shape.Custom")
+
+	override fun serialize(encoder: Encoder, value: Shape.Custom) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonObject {
+			output.json.encodeToJsonElement(serializer<HashMap<String, Double>>(), value.content).jsonObject.forEach { (key, element) -> put(key, element) }
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Shape.Custom {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement().jsonObject
+		val rest = JsonObject(element - setOf<String>())
+		return Shape.Custom(
+			content = input.json.decodeFromJsonElement(serializer<HashMap<String, Double>>(), rest),
+		)
+	}
+}
+
@@ -0,0 +1,35 @@
+package com.agilebits
+
+package onepassword {
+
+case class Square (
+	side: Double
+)
+
+// A shape with the tag stored alongside its fields.
+sealed trait Shape {
+	def serialName: String
+}
+object Shape {
+	// No shape at all
+	case object Empty extends Shape {
+		val serialName: String = "empty"
+	}
+	case class Square(
+		side: Double
+	) extends Shape {
+		val serialName: String = "square"
+	}
+	case class Circle(
+		radius: Double
+	) extends Shape {
+		val serialName: String = "circle"
+	}
+	case class Custom(
+		content: Map[String, Double]
+	) extends Shape {
+		val serialName: String = "custom"
+	}
+}
+
+}
@@ -0,0 +1,97 @@
+import Foundation
+
+public struct Square: Codable {
+	public let side: Double
+
+	public init(side: Double) {
+		self.side = side
+	}
+}
+
+
+/// Generated type representing the anonymous struct variant `Circle` of the `Shape` Rust enum
+public struct ShapeCircleInner: Codable {
+	public let radius: Double
+
+	public init(radius: Double) {
+		self.radius = radius
+	}
+}
+/// A shape with the tag stored alongside its fields.
+public enum Shape: Codable {
+	/// No shape at all
+	case empty
+	case square(Square)
+	case circle(ShapeCircleInner)
+	case custom([String: Double])
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case empty,
+			square,
+			circle,
+			custom
+	}
+
+	/// Any key of the JSON object, for the map variants
+	private struct AnyKey: CodingKey {
+		var stringValue: String
+		var intValue: Int? { nil }
+
+		init(stringValue: String) {
+			self.stringValue = stringValue
+		}
+
+		init?(intValue: Int) {
+			return nil
+		}
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case kind
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .kind) {
+			switch type {
+			case .empty:
+				self = .empty
+				return
+			case .square:
+				let content = try Square(from: decoder)
+				self = .square(content)
+				return
+			case .circle:
+				let content = try ShapeCircleInner(from: decoder)
+				self = .circle(content)
+				return
+			case .custom:
+				let rest = try decoder.container(keyedBy: AnyKey.self)
+				let content = try Dictionary(uniqueKeysWithValues: rest.allKeys.filter { $0.stringValue != "kind" }.map { ($0.stringValue, try rest.decode(Double.self, forKey: $0)) })
+				self = .custom(content)
+				return
+			}
+		}
+		throw DecodingError.typeMismatch(Shape.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Shape"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .empty:
+			try container.encode(CodingKeys.empty, forKey: .kind)
+		case .square(let content):
+			try container.encode(CodingKeys.square, forKey: .kind)
+			try content.encode(to: encoder)
+		case .circle(let content):
+			try container.encode(CodingKeys.circle, forKey: .kind)
+			try content.encode(to: encoder)
+		case .custom(let content):
+			try container.encode(CodingKeys.custom, forKey: .kind)
+			var rest = encoder.container(keyedBy: AnyKey.self)
+			for (key, value) in content {
+				try rest.encode(value, forKey: AnyKey(stringValue: key))
+			}
+		}
+	}
+}
@@ -0,0 +1,14 @@
+export interface Square {
+	side: number;
+}
+
+/** A shape with the tag stored alongside its fields. */
+export type Shape = 
+	/** No shape at all */
+	| { kind: "empty" }
+	| { kind: "square" } & Square
+	| { kind: "circle" } & {
+	radius: number;
+}
+	| { kind: "custom" } & Record<string, number>;
+
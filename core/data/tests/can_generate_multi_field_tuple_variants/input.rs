@@ -0,0 +1,15 @@
+#[typeshare]
+#[serde(tag = "type", content = "content")]
+pub enum Event {
+    Started,
+    /// From and to positions
+    Moved(u32, u32),
+    Renamed(String, Option<String>),
+}
+
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub enum Command {
+    Stop,
+    Resize(u32, u32),
+}
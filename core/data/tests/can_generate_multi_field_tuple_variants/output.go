@@ -0,0 +1,234 @@
+package proto
+
+import "encoding/json"
+import "fmt"
+
+// Generated type representing a tuple in the `Command` Rust type
+type CommandResizeTuple struct {
+	Field0 uint32
+	Field1 uint32
+}
+
+func (c *CommandResizeTuple) UnmarshalJSON(data []byte) error {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return err
+	}
+	if len(elements) != 2 {
+		return fmt.Errorf("expected 2 elements for CommandResizeTuple, got %d", len(elements))
+	}
+	if err := json.Unmarshal(elements[0], &c.Field0); err != nil {
+		return err
+	}
+	if err := json.Unmarshal(elements[1], &c.Field1); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (c CommandResizeTuple) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]interface{}{c.Field0, c.Field1})
+}
+
+// Generated type representing a tuple in the `Event` Rust type
+type EventMovedTuple struct {
+	Field0 uint32
+	Field1 uint32
+}
+
+func (e *EventMovedTuple) UnmarshalJSON(data []byte) error {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return err
+	}
+	if len(elements) != 2 {
+		return fmt.Errorf("expected 2 elements for EventMovedTuple, got %d", len(elements))
+	}
+	if err := json.Unmarshal(elements[0], &e.Field0); err != nil {
+		return err
+	}
+	if err := json.Unmarshal(elements[1], &e.Field1); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (e EventMovedTuple) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]interface{}{e.Field0, e.Field1})
+}
+
+// Generated type representing a tuple in the `Event` Rust type
+type EventRenamedTuple struct {
+	Field0 string
+	Field1 *string
+}
+
+func (e *EventRenamedTuple) UnmarshalJSON(data []byte) error {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return err
+	}
+	if len(elements) != 2 {
+		return fmt.Errorf("expected 2 elements for EventRenamedTuple, got %d", len(elements))
+	}
+	if err := json.Unmarshal(elements[0], &e.Field0); err != nil {
+		return err
+	}
+	if err := json.Unmarshal(elements[1], &e.Field1); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (e EventRenamedTuple) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]interface{}{e.Field0, e.Field1})
+}
+
+type CommandTypes string
+const (
+	CommandTypeVariantStop CommandTypes = "stop"
+	CommandTypeVariantResize CommandTypes = "resize"
+)
+type Command struct{ 
+	Type CommandTypes `json:"type"`
+	content interface{}
+}
+
+func (c *Command) UnmarshalJSON(data []byte) error {
+	var tag CommandTypes
+	if err := json.Unmarshal(data, &tag); err == nil {
+		c.Type = tag
+		c.content = nil
+		return nil
+	}
+
+	var enum map[CommandTypes]json.RawMessage
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+	if len(enum) != 1 {
+		return fmt.Errorf("expected a single variant for Command, got %d", len(enum))
+	}
+
+	for tag, content := range enum {
+		c.Type = tag
+		switch c.Type {
+		case CommandTypeVariantStop:
+			return nil
+		case CommandTypeVariantResize:
+			var res CommandResizeTuple
+			c.content = &res
+
+		}
+		if err := json.Unmarshal(content, &c.content); err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+func (c Command) MarshalJSON() ([]byte, error) {
+	if c.content == nil {
+		return json.Marshal(c.Type)
+	}
+	return json.Marshal(map[CommandTypes]interface{}{c.Type: c.content})
+}
+
+func (c Command) Resize() *CommandResizeTuple {
+	res, _ := c.content.(*CommandResizeTuple)
+	return res
+}
+
+func NewCommandTypeVariantStop() Command {
+    return Command{
+        Type: CommandTypeVariantStop,
+    }
+}
+func NewCommandTypeVariantResize(content *CommandResizeTuple) Command {
+    return Command{
+        Type: CommandTypeVariantResize,
+        content: content,
+    }
+}
+
+type EventTypes string
+const (
+	EventTypeVariantStarted EventTypes = "Started"
+	// From and to positions
+	EventTypeVariantMoved EventTypes = "Moved"
+	EventTypeVariantRenamed EventTypes = "Renamed"
+)
+type Event struct{ 
+	Type EventTypes `json:"type"`
+	content interface{}
+}
+
+func (e *Event) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    EventTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	e.Type = enum.Tag
+	switch e.Type {
+	case EventTypeVariantStarted:
+		return nil
+	case EventTypeVariantMoved:
+		var res EventMovedTuple
+		e.content = &res
+	case EventTypeVariantRenamed:
+		var res EventRenamedTuple
+		e.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &e.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (e Event) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    EventTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = e.Type
+    enum.Content = e.content
+    return json.Marshal(enum)
+}
+
+func (e Event) Moved() *EventMovedTuple {
+	res, _ := e.content.(*EventMovedTuple)
+	return res
+}
+func (e Event) Renamed() *EventRenamedTuple {
+	res, _ := e.content.(*EventRenamedTuple)
+	return res
+}
+
+func NewEventTypeVariantStarted() Event {
+    return Event{
+        Type: EventTypeVariantStarted,
+    }
+}
+func NewEventTypeVariantMoved(content *EventMovedTuple) Event {
+    return Event{
+        Type: EventTypeVariantMoved,
+        content: content,
+    }
+}
+func NewEventTypeVariantRenamed(content *EventRenamedTuple) Event {
+    return Event{
+        Type: EventTypeVariantRenamed,
+        content: content,
+    }
+}
+
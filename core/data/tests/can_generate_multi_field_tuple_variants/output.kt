@@ -0,0 +1,149 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+/// Generated type representing a tuple in the `Command` Rust type
+@Serializable(with = CommandResizeTupleSerializer::class)
+data class CommandResizeTuple (
+	val _0: UInt,
+	val _1: UInt
+)
+
+object CommandResizeTupleSerializer : KSerializer<CommandResizeTuple> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("CommandResizeTuple")
+
+	override fun serialize(encoder: Encoder, value: CommandResizeTuple) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonArray {
+			add(output.json.encodeToJsonElement(serializer<UInt>(), value._0))
+			add(output.json.encodeToJsonElement(serializer<UInt>(), value._1))
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): CommandResizeTuple {
+		val input = decoder as JsonDecoder
+		val elements = input.decodeJsonElement().jsonArray
+		return CommandResizeTuple(
+			input.json.decodeFromJsonElement(serializer<UInt>(), elements[0]),
+			input.json.decodeFromJsonElement(serializer<UInt>(), elements[1])
+		)
+	}
+}
+
+/// Generated type representing a tuple in the `Event` Rust type
+@Serializable(with = EventMovedTupleSerializer::class)
+data class EventMovedTuple (
+	val _0: UInt,
+	val _1: UInt
+)
+
+object EventMovedTupleSerializer : KSerializer<EventMovedTuple> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("EventMovedTuple")
+
+	override fun serialize(encoder: Encoder, value: EventMovedTuple) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonArray {
+			add(output.json.encodeToJsonElement(serializer<UInt>(), value._0))
+			add(output.json.encodeToJsonElement(serializer<UInt>(), value._1))
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): EventMovedTuple {
+		val input = decoder as JsonDecoder
+		val elements = input.decodeJsonElement().jsonArray
+		return EventMovedTuple(
+			input.json.decodeFromJsonElement(serializer<UInt>(), elements[0]),
+			input.json.decodeFromJsonElement(serializer<UInt>(), elements[1])
+		)
+	}
+}
+
+/// Generated type representing a tuple in the `Event` Rust type
+@Serializable(with = EventRenamedTupleSerializer::class)
+data class EventRenamedTuple (
+	val _0: String,
+	val _1: String?
+)
+
+object EventRenamedTupleSerializer : KSerializer<EventRenamedTuple> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("EventRenamedTuple")
+
+	override fun serialize(encoder: Encoder, value: EventRenamedTuple) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonArray {
+			add(output.json.encodeToJsonElement(serializer<String>(), value._0))
+			add(output.json.encodeToJsonElement(serializer<String?>(), value._1))
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): EventRenamedTuple {
+		val input = decoder as JsonDecoder
+		val elements = input.decodeJsonElement().jsonArray
+		return EventRenamedTuple(
+			input.json.decodeFromJsonElement(serializer<String>(), elements[0]),
+			input.json.decodeFromJsonElement(serializer<String?>(), elements[1])
+		)
+	}
+}
+
+@Serializable(with = CommandSerializer::class)
+sealed class Command {
+	@Serializable
+	@SerialName("stop")
+	object Stop: Command()
+	@Serializable
+	@SerialName("resize")
+	data class Resize(val content: CommandResizeTuple): Command()
+}
+
+object CommandSerializer : KSerializer<Command> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Command")
+
+	override fun serialize(encoder: Encoder, value: Command) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is Command.Stop -> JsonPrimitive("stop")
+			is Command.Resize -> buildJsonObject { put("resize", output.json.encodeToJsonElement(serializer<CommandResizeTuple>(), value.content)) }
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Command {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		if (element is JsonPrimitive && element.isString) {
+			when (element.content) {
+				"stop" -> return Command.Stop
+			}
+		}
+		if (element is JsonObject && element.size == 1) {
+			val (key, content) = element.entries.first()
+			when (key) {
+				"resize" -> return Command.Resize(input.json.decodeFromJsonElement(serializer<CommandResizeTuple>(), content))
+			}
+		}
+		throw SerializationException("Unknown variant for Command: $element")
+	}
+}
+
+@Serializable
+sealed class Event {
+	@Serializable
+	@SerialName("Started")
+	object Started: Event()
+	/// From and to positions
+	@Serializable
+	@SerialName("Moved")
+	data class Moved(val content: EventMovedTuple): Event()
+	@Serializable
+	@SerialName("Renamed")
+	data class Renamed(val content: EventRenamedTuple): Event()
+}
+
@@ -0,0 +1,33 @@
+package com.agilebits
+
+package onepassword {
+
+sealed trait Command {
+	def serialName: String
+}
+object Command {
+	case object Stop extends Command {
+		val serialName: String = "stop"
+	}
+	case class Resize(content: (UInt, UInt)) extends Command {
+		val serialName: String = "resize"
+	}
+}
+
+sealed trait Event {
+	def serialName: String
+}
+object Event {
+	case object Started extends Event {
+		val serialName: String = "Started"
+	}
+	// From and to positions
+	case class Moved(content: (UInt, UInt)) extends Event {
+		val serialName: String = "Moved"
+	}
+	case class Renamed(content: (String, Option[String])) extends Event {
+		val serialName: String = "Renamed"
+	}
+}
+
+}
@@ -0,0 +1,165 @@
+import Foundation
+
+/// Generated type representing a tuple in the `Command` Rust type
+public struct CommandResizeTuple: Codable {
+	public let _0: UInt32
+	public let _1: UInt32
+
+	public init(_ _0: UInt32, _ _1: UInt32) {
+		self._0 = _0
+		self._1 = _1
+	}
+
+	public init(from decoder: Decoder) throws {
+		var container = try decoder.unkeyedContainer()
+		self._0 = try container.decode(UInt32.self)
+		self._1 = try container.decode(UInt32.self)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.unkeyedContainer()
+		try container.encode(_0)
+		try container.encode(_1)
+	}
+}
+
+/// Generated type representing a tuple in the `Event` Rust type
+public struct EventMovedTuple: Codable {
+	public let _0: UInt32
+	public let _1: UInt32
+
+	public init(_ _0: UInt32, _ _1: UInt32) {
+		self._0 = _0
+		self._1 = _1
+	}
+
+	public init(from decoder: Decoder) throws {
+		var container = try decoder.unkeyedContainer()
+		self._0 = try container.decode(UInt32.self)
+		self._1 = try container.decode(UInt32.self)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.unkeyedContainer()
+		try container.encode(_0)
+		try container.encode(_1)
+	}
+}
+
+/// Generated type representing a tuple in the `Event` Rust type
+public struct EventRenamedTuple: Codable {
+	public let _0: String
+	public let _1: String?
+
+	public init(_ _0: String, _ _1: String?) {
+		self._0 = _0
+		self._1 = _1
+	}
+
+	public init(from decoder: Decoder) throws {
+		var container = try decoder.unkeyedContainer()
+		self._0 = try container.decode(String.self)
+		self._1 = try container.decodeIfPresent(String.self)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.unkeyedContainer()
+		try container.encode(_0)
+		try container.encode(_1)
+	}
+}
+
+public enum Command: Codable {
+	case stop
+	case resize(CommandResizeTuple)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case stop,
+			resize
+	}
+
+	public init(from decoder: Decoder) throws {
+		if let container = try? decoder.singleValueContainer(), let type = try? container.decode(CodingKeys.self) {
+			switch type {
+			case .stop:
+				self = .stop
+				return
+			default:
+				break
+			}
+		}
+		if let container = try? decoder.container(keyedBy: CodingKeys.self), let type = container.allKeys.first {
+			switch type {
+			case .resize:
+				let content = try container.decode(CommandResizeTuple.self, forKey: .resize)
+				self = .resize(content)
+				return
+			default:
+				break
+			}
+		}
+		throw DecodingError.typeMismatch(Command.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Command"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		switch self {
+		case .stop:
+			var container = encoder.singleValueContainer()
+			try container.encode(CodingKeys.stop)
+		case .resize(let content):
+			var container = encoder.container(keyedBy: CodingKeys.self)
+			try container.encode(content, forKey: .resize)
+		}
+	}
+}
+
+public enum Event: Codable {
+	case started
+	/// From and to positions
+	case moved(EventMovedTuple)
+	case renamed(EventRenamedTuple)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case started = "Started",
+			moved = "Moved",
+			renamed = "Renamed"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case type, content
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
+			switch type {
+			case .started:
+				self = .started
+				return
+			case .moved:
+				let content = try container.decode(EventMovedTuple.self, forKey: .content)
+				self = .moved(content)
+				return
+			case .renamed:
+				let content = try container.decode(EventRenamedTuple.self, forKey: .content)
+				self = .renamed(content)
+				return
+			}
+		}
+		throw DecodingError.typeMismatch(Event.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Event"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .started:
+			try container.encode(CodingKeys.started, forKey: .type)
+		case .moved(let content):
+			try container.encode(CodingKeys.moved, forKey: .type)
+			try container.encode(content, forKey: .content)
+		case .renamed(let content):
+			try container.encode(CodingKeys.renamed, forKey: .type)
+			try container.encode(content, forKey: .content)
+		}
+	}
+}
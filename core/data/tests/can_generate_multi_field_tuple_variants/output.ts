@@ -0,0 +1,10 @@
+export type Command = 
+	| "stop"
+	| { resize: [number, number] };
+
+export type Event = 
+	| { type: "Started", content?: undefined }
+	/** From and to positions */
+	| { type: "Moved", content: [number, number] }
+	| { type: "Renamed", content: [string, string | undefined] };
+
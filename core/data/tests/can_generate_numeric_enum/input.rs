@@ -0,0 +1,27 @@
+/// How urgent a task is
+#[typeshare]
+#[derive(Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum Priority {
+    /// Can wait
+    Low = 1,
+    Medium,
+    High = 10,
+}
+
+/// A status code that can be negative
+#[typeshare]
+#[derive(Serialize_repr, Deserialize_repr)]
+#[repr(i32)]
+pub enum StatusCode {
+    Failed = -1,
+    Pending,
+    Done,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct Task {
+    pub priority: Priority,
+    pub status: StatusCode,
+}
@@ -0,0 +1,23 @@
+package proto
+
+import "encoding/json"
+
+// How urgent a task is
+type Priority int
+const (
+	// Can wait
+	PriorityLow Priority = 1
+	PriorityMedium Priority = 2
+	PriorityHigh Priority = 10
+)
+// A status code that can be negative
+type StatusCode int
+const (
+	StatusCodeFailed StatusCode = -1
+	StatusCodePending StatusCode = 0
+	StatusCodeDone StatusCode = 1
+)
+type Task struct {
+	Priority Priority `json:"priority"`
+	Status StatusCode `json:"status"`
+}
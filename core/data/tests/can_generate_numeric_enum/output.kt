@@ -0,0 +1,63 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+/// How urgent a task is
+@Serializable(with = PrioritySerializer::class)
+enum class Priority(val value: Int) {
+	/// Can wait
+	Low(1),
+	Medium(2),
+	High(10),
+}
+
+object PrioritySerializer : KSerializer<Priority> {
+	override val descriptor: SerialDescriptor = PrimitiveSerialDescriptor("Priority", PrimitiveKind.INT)
+
+	override fun serialize(encoder: Encoder, value: Priority) {
+		encoder.encodeInt(value.value)
+	}
+
+	override fun deserialize(decoder: Decoder): Priority {
+		val value = decoder.decodeInt()
+		return Priority.values().firstOrNull { it.value == value }
+			?: throw SerializationException("No variant of Priority has the value $value")
+	}
+}
+
+/// A status code that can be negative
+@Serializable(with = StatusCodeSerializer::class)
+enum class StatusCode(val value: Int) {
+	Failed(-1),
+	Pending(0),
+	Done(1),
+}
+
+object StatusCodeSerializer : KSerializer<StatusCode> {
+	override val descriptor: SerialDescriptor = PrimitiveSerialDescriptor("StatusCode", PrimitiveKind.INT)
+
+	override fun serialize(encoder: Encoder, value: StatusCode) {
+		encoder.encodeInt(value.value)
+	}
+
+	override fun deserialize(decoder: Decoder): StatusCode {
+		val value = decoder.decodeInt()
+		return StatusCode.values().firstOrNull { it.value == value }
+			?: throw SerializationException("No variant of StatusCode has the value $value")
+	}
+}
+
+@Serializable
+data class Task (
+	val priority: Priority,
+	val status: StatusCode
+)
+
@@ -0,0 +1,43 @@
+package com.agilebits
+
+package onepassword {
+
+case class Task (
+	priority: Priority,
+	status: StatusCode
+)
+
+// How urgent a task is
+sealed trait Priority {
+	def value: Int
+}
+object Priority {
+	// Can wait
+	case object Low extends Priority {
+		val value: Int = 1
+	}
+	case object Medium extends Priority {
+		val value: Int = 2
+	}
+	case object High extends Priority {
+		val value: Int = 10
+	}
+}
+
+// A status code that can be negative
+sealed trait StatusCode {
+	def value: Int
+}
+object StatusCode {
+	case object Failed extends StatusCode {
+		val value: Int = -1
+	}
+	case object Pending extends StatusCode {
+		val value: Int = 0
+	}
+	case object Done extends StatusCode {
+		val value: Int = 1
+	}
+}
+
+}
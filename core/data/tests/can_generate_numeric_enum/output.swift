@@ -0,0 +1,26 @@
+import Foundation
+
+/// How urgent a task is
+public enum Priority: UInt8, Codable {
+	/// Can wait
+	case low = 1
+	case medium = 2
+	case high = 10
+}
+
+/// A status code that can be negative
+public enum StatusCode: Int32, Codable {
+	case failed = -1
+	case pending = 0
+	case done = 1
+}
+
+public struct Task: Codable {
+	public let priority: Priority
+	public let status: StatusCode
+
+	public init(priority: Priority, status: StatusCode) {
+		self.priority = priority
+		self.status = status
+	}
+}
@@ -0,0 +1,20 @@
+/** How urgent a task is */
+export enum Priority {
+	/** Can wait */
+	Low = 1,
+	Medium = 2,
+	High = 10,
+}
+
+/** A status code that can be negative */
+export enum StatusCode {
+	Failed = -1,
+	Pending = 0,
+	Done = 1,
+}
+
+export interface Task {
+	priority: Priority;
+	status: StatusCode;
+}
+
@@ -0,0 +1,7 @@
+package proto
+
+import "encoding/json"
+
+type SomeStruct struct {
+	FieldA uint32 `json:"field_a"`
+}
@@ -0,0 +1,23 @@
+/// A message with its kind as a tag
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub struct TextMessage {
+    pub text: String,
+    pub sent_at: Option<String>,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename = "Image")]
+pub struct ImageMessage {
+    pub url: String,
+}
+
+/// A generic struct with a tag
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub struct Envelope<T> {
+    pub payload: T,
+}
@@ -0,0 +1,55 @@
+package proto
+
+import "encoding/json"
+
+// A generic struct with a tag
+type Envelope[T any] struct {
+	Payload T `json:"payload"`
+}
+
+// The value of the `kind` tag of Envelope
+const EnvelopeKind = "Envelope"
+
+// The fields of Envelope, without its JSON encoding
+type envelopeFields[T any] Envelope[T]
+
+func (e Envelope[T]) MarshalJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		Kind string `json:"kind"`
+		envelopeFields[T]
+	}{EnvelopeKind, envelopeFields[T](e)})
+}
+type Image struct {
+	Url string `json:"url"`
+}
+
+// The value of the `type` tag of Image
+const ImageType = "Image"
+
+// The fields of Image, without its JSON encoding
+type imageFields Image
+
+func (i Image) MarshalJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		Type string `json:"type"`
+		imageFields
+	}{ImageType, imageFields(i)})
+}
+// A message with its kind as a tag
+type TextMessage struct {
+	Text string `json:"text"`
+	SentAt *string `json:"sentAt,omitempty"`
+}
+
+// The value of the `kind` tag of TextMessage
+const TextMessageKind = "TextMessage"
+
+// The fields of TextMessage, without its JSON encoding
+type textMessageFields TextMessage
+
+func (t TextMessage) MarshalJSON() ([]byte, error) {
+	return json.Marshal(struct {
+		Kind string `json:"kind"`
+		textMessageFields
+	}{TextMessageKind, textMessageFields(t)})
+}
@@ -0,0 +1,43 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.EncodeDefault
+
+/// A generic struct with a tag
+@Serializable
+@SerialName("Envelope")
+data class Envelope<T> (
+	val payload: T
+) {
+	@OptIn(ExperimentalSerializationApi::class)
+	@EncodeDefault
+	@SerialName("kind")
+	val kind: String = "Envelope"
+}
+
+@Serializable
+@SerialName("Image")
+data class Image (
+	val url: String
+) {
+	@OptIn(ExperimentalSerializationApi::class)
+	@EncodeDefault
+	@SerialName("type")
+	val type: String = "Image"
+}
+
+/// A message with its kind as a tag
+@Serializable
+@SerialName("TextMessage")
+data class TextMessage (
+	val text: String,
+	val sentAt: String? = null
+) {
+	@OptIn(ExperimentalSerializationApi::class)
+	@EncodeDefault
+	@SerialName("kind")
+	val kind: String = "TextMessage"
+}
+
@@ -0,0 +1,20 @@
+package com.agilebits
+
+package onepassword {
+
+// A generic struct with a tag
+case class Envelope[T] (
+	payload: T
+)
+
+case class Image (
+	url: String
+)
+
+// A message with its kind as a tag
+case class TextMessage (
+	text: String,
+	sentAt: Option[String] = None
+)
+
+}
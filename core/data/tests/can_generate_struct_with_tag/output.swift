@@ -0,0 +1,92 @@
+import Foundation
+
+/// A generic struct with a tag
+public struct Envelope<T: Codable>: Codable {
+	public let payload: T
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case kind,
+			payload
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		let tag = try container.decode(String.self, forKey: .kind)
+		guard tag == "Envelope" else {
+			throw DecodingError.dataCorruptedError(forKey: .kind, in: container, debugDescription: "Wrong kind tag for Envelope: \(tag)")
+		}
+		self.payload = try container.decode(T.self, forKey: .payload)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: CodingKeys.self)
+		try container.encode("Envelope", forKey: .kind)
+		try container.encode(self.payload, forKey: .payload)
+	}
+
+	public init(payload: T) {
+		self.payload = payload
+	}
+}
+
+public struct Image: Codable {
+	public let url: String
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case type,
+			url
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		let tag = try container.decode(String.self, forKey: .type)
+		guard tag == "Image" else {
+			throw DecodingError.dataCorruptedError(forKey: .type, in: container, debugDescription: "Wrong type tag for Image: \(tag)")
+		}
+		self.url = try container.decode(String.self, forKey: .url)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: CodingKeys.self)
+		try container.encode("Image", forKey: .type)
+		try container.encode(self.url, forKey: .url)
+	}
+
+	public init(url: String) {
+		self.url = url
+	}
+}
+
+/// A message with its kind as a tag
+public struct TextMessage: Codable {
+	public let text: String
+	public let sentAt: String?
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case kind,
+			text,
+			sentAt
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		let tag = try container.decode(String.self, forKey: .kind)
+		guard tag == "TextMessage" else {
+			throw DecodingError.dataCorruptedError(forKey: .kind, in: container, debugDescription: "Wrong kind tag for TextMessage: \(tag)")
+		}
+		self.text = try container.decode(String.self, forKey: .text)
+		self.sentAt = try container.decodeIfPresent(String.self, forKey: .sentAt)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: CodingKeys.self)
+		try container.encode("TextMessage", forKey: .kind)
+		try container.encode(self.text, forKey: .text)
+		try container.encodeIfPresent(self.sentAt, forKey: .sentAt)
+	}
+
+	public init(text: String, sentAt: String?) {
+		self.text = text
+		self.sentAt = sentAt
+	}
+}
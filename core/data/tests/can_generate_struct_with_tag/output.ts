@@ -0,0 +1,18 @@
+/** A generic struct with a tag */
+export interface Envelope<T> {
+	kind: "Envelope";
+	payload: T;
+}
+
+export interface Image {
+	type: "Image";
+	url: string;
+}
+
+/** A message with its kind as a tag */
+export interface TextMessage {
+	kind: "TextMessage";
+	text: string;
+	sentAt?: string;
+}
+
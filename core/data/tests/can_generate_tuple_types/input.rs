@@ -0,0 +1,17 @@
+/// A point on a plane.
+#[typeshare]
+pub struct Point(f32, f32);
+
+#[typeshare]
+pub struct Segment {
+    pub ends: (Point, Point),
+    /// Labels along the segment, with their offset
+    pub labels: Vec<(String, u32)>,
+}
+
+#[typeshare]
+#[serde(tag = "type", content = "content")]
+pub enum Selection {
+    Everything,
+    Range((u32, Option<u32>)),
+}
@@ -0,0 +1,184 @@
+package proto
+
+import "encoding/json"
+import "fmt"
+
+// A point on a plane.
+type Point struct {
+	Field0 float32
+	Field1 float32
+}
+
+func (p *Point) UnmarshalJSON(data []byte) error {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return err
+	}
+	if len(elements) != 2 {
+		return fmt.Errorf("expected 2 elements for Point, got %d", len(elements))
+	}
+	if err := json.Unmarshal(elements[0], &p.Field0); err != nil {
+		return err
+	}
+	if err := json.Unmarshal(elements[1], &p.Field1); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (p Point) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]interface{}{p.Field0, p.Field1})
+}
+
+// Generated type representing a tuple in the `Segment` Rust type
+type SegmentEndsTuple struct {
+	Field0 Point
+	Field1 Point
+}
+
+func (s *SegmentEndsTuple) UnmarshalJSON(data []byte) error {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return err
+	}
+	if len(elements) != 2 {
+		return fmt.Errorf("expected 2 elements for SegmentEndsTuple, got %d", len(elements))
+	}
+	if err := json.Unmarshal(elements[0], &s.Field0); err != nil {
+		return err
+	}
+	if err := json.Unmarshal(elements[1], &s.Field1); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (s SegmentEndsTuple) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]interface{}{s.Field0, s.Field1})
+}
+
+// Generated type representing a tuple in the `Segment` Rust type
+type SegmentLabelsTuple struct {
+	Field0 string
+	Field1 uint32
+}
+
+func (s *SegmentLabelsTuple) UnmarshalJSON(data []byte) error {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return err
+	}
+	if len(elements) != 2 {
+		return fmt.Errorf("expected 2 elements for SegmentLabelsTuple, got %d", len(elements))
+	}
+	if err := json.Unmarshal(elements[0], &s.Field0); err != nil {
+		return err
+	}
+	if err := json.Unmarshal(elements[1], &s.Field1); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (s SegmentLabelsTuple) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]interface{}{s.Field0, s.Field1})
+}
+
+// Generated type representing a tuple in the `Selection` Rust type
+type SelectionRangeTuple struct {
+	Field0 uint32
+	Field1 *uint32
+}
+
+func (s *SelectionRangeTuple) UnmarshalJSON(data []byte) error {
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {
+		return err
+	}
+	if len(elements) != 2 {
+		return fmt.Errorf("expected 2 elements for SelectionRangeTuple, got %d", len(elements))
+	}
+	if err := json.Unmarshal(elements[0], &s.Field0); err != nil {
+		return err
+	}
+	if err := json.Unmarshal(elements[1], &s.Field1); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (s SelectionRangeTuple) MarshalJSON() ([]byte, error) {
+	return json.Marshal([]interface{}{s.Field0, s.Field1})
+}
+
+type Segment struct {
+	Ends SegmentEndsTuple `json:"ends"`
+	// Labels along the segment, with their offset
+	Labels []SegmentLabelsTuple `json:"labels"`
+}
+type SelectionTypes string
+const (
+	SelectionTypeVariantEverything SelectionTypes = "Everything"
+	SelectionTypeVariantRange SelectionTypes = "Range"
+)
+type Selection struct{ 
+	Type SelectionTypes `json:"type"`
+	content interface{}
+}
+
+func (s *Selection) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    SelectionTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	s.Type = enum.Tag
+	switch s.Type {
+	case SelectionTypeVariantEverything:
+		return nil
+	case SelectionTypeVariantRange:
+		var res SelectionRangeTuple
+		s.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &s.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (s Selection) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    SelectionTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = s.Type
+    enum.Content = s.content
+    return json.Marshal(enum)
+}
+
+func (s Selection) Range() *SelectionRangeTuple {
+	res, _ := s.content.(*SelectionRangeTuple)
+	return res
+}
+
+func NewSelectionTypeVariantEverything() Selection {
+    return Selection{
+        Type: SelectionTypeVariantEverything,
+    }
+}
+func NewSelectionTypeVariantRange(content *SelectionRangeTuple) Selection {
+    return Selection{
+        Type: SelectionTypeVariantRange,
+        content: content,
+    }
+}
+
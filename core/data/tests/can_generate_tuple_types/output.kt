@@ -0,0 +1,141 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+/// A point on a plane.
+@Serializable(with = PointSerializer::class)
+data class Point (
+	val _0: Float,
+	val _1: Float
+)
+
+object PointSerializer : KSerializer<Point> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Point")
+
+	override fun serialize(encoder: Encoder, value: Point) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonArray {
+			add(output.json.encodeToJsonElement(serializer<Float>(), value._0))
+			add(output.json.encodeToJsonElement(serializer<Float>(), value._1))
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Point {
+		val input = decoder as JsonDecoder
+		val elements = input.decodeJsonElement().jsonArray
+		return Point(
+			input.json.decodeFromJsonElement(serializer<Float>(), elements[0]),
+			input.json.decodeFromJsonElement(serializer<Float>(), elements[1])
+		)
+	}
+}
+
+/// Generated type representing a tuple in the `Segment` Rust type
+@Serializable(with = SegmentEndsTupleSerializer::class)
+data class SegmentEndsTuple (
+	val _0: Point,
+	val _1: Point
+)
+
+object SegmentEndsTupleSerializer : KSerializer<SegmentEndsTuple> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("SegmentEndsTuple")
+
+	override fun serialize(encoder: Encoder, value: SegmentEndsTuple) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonArray {
+			add(output.json.encodeToJsonElement(serializer<Point>(), value._0))
+			add(output.json.encodeToJsonElement(serializer<Point>(), value._1))
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): SegmentEndsTuple {
+		val input = decoder as JsonDecoder
+		val elements = input.decodeJsonElement().jsonArray
+		return SegmentEndsTuple(
+			input.json.decodeFromJsonElement(serializer<Point>(), elements[0]),
+			input.json.decodeFromJsonElement(serializer<Point>(), elements[1])
+		)
+	}
+}
+
+/// Generated type representing a tuple in the `Segment` Rust type
+@Serializable(with = SegmentLabelsTupleSerializer::class)
+data class SegmentLabelsTuple (
+	val _0: String,
+	val _1: UInt
+)
+
+object SegmentLabelsTupleSerializer : KSerializer<SegmentLabelsTuple> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("SegmentLabelsTuple")
+
+	override fun serialize(encoder: Encoder, value: SegmentLabelsTuple) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonArray {
+			add(output.json.encodeToJsonElement(serializer<String>(), value._0))
+			add(output.json.encodeToJsonElement(serializer<UInt>(), value._1))
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): SegmentLabelsTuple {
+		val input = decoder as JsonDecoder
+		val elements = input.decodeJsonElement().jsonArray
+		return SegmentLabelsTuple(
+			input.json.decodeFromJsonElement(serializer<String>(), elements[0]),
+			input.json.decodeFromJsonElement(serializer<UInt>(), elements[1])
+		)
+	}
+}
+
+/// Generated type representing a tuple in the `Selection` Rust type
+@Serializable(with = SelectionRangeTupleSerializer::class)
+data class SelectionRangeTuple (
+	val _0: UInt,
+	val _1: UInt?
+)
+
+object SelectionRangeTupleSerializer : KSerializer<SelectionRangeTuple> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("SelectionRangeTuple")
+
+	override fun serialize(encoder: Encoder, value: SelectionRangeTuple) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(buildJsonArray {
+			add(output.json.encodeToJsonElement(serializer<UInt>(), value._0))
+			add(output.json.encodeToJsonElement(serializer<UInt?>(), value._1))
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): SelectionRangeTuple {
+		val input = decoder as JsonDecoder
+		val elements = input.decodeJsonElement().jsonArray
+		return SelectionRangeTuple(
+			input.json.decodeFromJsonElement(serializer<UInt>(), elements[0]),
+			input.json.decodeFromJsonElement(serializer<UInt?>(), elements[1])
+		)
+	}
+}
+
+@Serializable
+data class Segment (
+	val ends: SegmentEndsTuple,
+	/// Labels along the segment, with their offset
+	val labels: List<SegmentLabelsTuple>
+)
+
+@Serializable
+sealed class Selection {
+	@Serializable
+	@SerialName("Everything")
+	object Everything: Selection()
+	@Serializable
+	@SerialName("Range")
+	data class Range(val content: SelectionRangeTuple): Selection()
+}
+
@@ -0,0 +1,29 @@
+package com.agilebits
+
+package object onepassword {
+
+// A point on a plane.
+type Point = (Float, Float)
+
+}
+package onepassword {
+
+case class Segment (
+	ends: (Point, Point),
+	// Labels along the segment, with their offset
+	labels: Vector[(String, UInt)]
+)
+
+sealed trait Selection {
+	def serialName: String
+}
+object Selection {
+	case object Everything extends Selection {
+		val serialName: String = "Everything"
+	}
+	case class Range(content: (UInt, Option[UInt])) extends Selection {
+		val serialName: String = "Range"
+	}
+}
+
+}
@@ -0,0 +1,145 @@
+import Foundation
+
+/// A point on a plane.
+public struct Point: Codable {
+	public let _0: Float
+	public let _1: Float
+
+	public init(_ _0: Float, _ _1: Float) {
+		self._0 = _0
+		self._1 = _1
+	}
+
+	public init(from decoder: Decoder) throws {
+		var container = try decoder.unkeyedContainer()
+		self._0 = try container.decode(Float.self)
+		self._1 = try container.decode(Float.self)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.unkeyedContainer()
+		try container.encode(_0)
+		try container.encode(_1)
+	}
+}
+
+/// Generated type representing a tuple in the `Segment` Rust type
+public struct SegmentEndsTuple: Codable {
+	public let _0: Point
+	public let _1: Point
+
+	public init(_ _0: Point, _ _1: Point) {
+		self._0 = _0
+		self._1 = _1
+	}
+
+	public init(from decoder: Decoder) throws {
+		var container = try decoder.unkeyedContainer()
+		self._0 = try container.decode(Point.self)
+		self._1 = try container.decode(Point.self)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.unkeyedContainer()
+		try container.encode(_0)
+		try container.encode(_1)
+	}
+}
+
+/// Generated type representing a tuple in the `Segment` Rust type
+public struct SegmentLabelsTuple: Codable {
+	public let _0: String
+	public let _1: UInt32
+
+	public init(_ _0: String, _ _1: UInt32) {
+		self._0 = _0
+		self._1 = _1
+	}
+
+	public init(from decoder: Decoder) throws {
+		var container = try decoder.unkeyedContainer()
+		self._0 = try container.decode(String.self)
+		self._1 = try container.decode(UInt32.self)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.unkeyedContainer()
+		try container.encode(_0)
+		try container.encode(_1)
+	}
+}
+
+/// Generated type representing a tuple in the `Selection` Rust type
+public struct SelectionRangeTuple: Codable {
+	public let _0: UInt32
+	public let _1: UInt32?
+
+	public init(_ _0: UInt32, _ _1: UInt32?) {
+		self._0 = _0
+		self._1 = _1
+	}
+
+	public init(from decoder: Decoder) throws {
+		var container = try decoder.unkeyedContainer()
+		self._0 = try container.decode(UInt32.self)
+		self._1 = try container.decodeIfPresent(UInt32.self)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.unkeyedContainer()
+		try container.encode(_0)
+		try container.encode(_1)
+	}
+}
+
+public struct Segment: Codable {
+	public let ends: SegmentEndsTuple
+	/// Labels along the segment, with their offset
+	public let labels: [SegmentLabelsTuple]
+
+	public init(ends: SegmentEndsTuple, labels: [SegmentLabelsTuple]) {
+		self.ends = ends
+		self.labels = labels
+	}
+}
+
+public enum Selection: Codable {
+	case everything
+	case range(SelectionRangeTuple)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case everything = "Everything",
+			range = "Range"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case type, content
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
+			switch type {
+			case .everything:
+				self = .everything
+				return
+			case .range:
+				let content = try container.decode(SelectionRangeTuple.self, forKey: .content)
+				self = .range(content)
+				return
+			}
+		}
+		throw DecodingError.typeMismatch(Selection.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Selection"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .everything:
+			try container.encode(CodingKeys.everything, forKey: .type)
+		case .range(let content):
+			try container.encode(CodingKeys.range, forKey: .type)
+			try container.encode(content, forKey: .content)
+		}
+	}
+}
@@ -0,0 +1,13 @@
+/** A point on a plane. */
+export type Point = [number, number];
+
+export interface Segment {
+	ends: [Point, Point];
+	/** Labels along the segment, with their offset */
+	labels: [string, number][];
+}
+
+export type Selection = 
+	| { type: "Everything", content?: undefined }
+	| { type: "Range", content: [number, number | undefined] };
+
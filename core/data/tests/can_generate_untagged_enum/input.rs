@@ -0,0 +1,24 @@
+#[typeshare]
+pub struct Location {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// A value that may be written as a string or a number.
+#[typeshare]
+#[serde(untagged)]
+pub enum StringOrNumber {
+    Text(String),
+    Number(f64),
+}
+
+/// Where an item can be found, if anywhere.
+#[typeshare]
+#[serde(untagged)]
+pub enum Whereabouts {
+    /// Not known
+    Unknown,
+    Address(String),
+    Coordinates(Location),
+    Room { building: String, floor: u32 },
+}
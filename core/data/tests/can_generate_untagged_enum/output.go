@@ -0,0 +1,167 @@
+package proto
+
+import "bytes"
+import "encoding/json"
+import "fmt"
+
+type Location struct {
+	Latitude float64 `json:"latitude"`
+	Longitude float64 `json:"longitude"`
+}
+// A value that may be written as a string or a number.
+type StringOrNumberTypes string
+const (
+	StringOrNumberTypeVariantText StringOrNumberTypes = "Text"
+	StringOrNumberTypeVariantNumber StringOrNumberTypes = "Number"
+)
+type StringOrNumber struct{ 
+	Type StringOrNumberTypes `json:"type"`
+	content interface{}
+}
+
+func (s *StringOrNumber) UnmarshalJSON(data []byte) error {
+	if res := new(string); json.Unmarshal(data, res) == nil {
+		s.Type = StringOrNumberTypeVariantText
+		s.content = res
+		return nil
+	}
+	if res := new(float64); json.Unmarshal(data, res) == nil {
+		s.Type = StringOrNumberTypeVariantNumber
+		s.content = res
+		return nil
+	}
+
+	return fmt.Errorf("no variant of StringOrNumber matches %s", data)
+}
+
+func (s StringOrNumber) MarshalJSON() ([]byte, error) {
+	return json.Marshal(s.content)
+}
+
+func (s StringOrNumber) Text() string {
+	res, _ := s.content.(*string)
+	return *res
+}
+func (s StringOrNumber) Number() float64 {
+	res, _ := s.content.(*float64)
+	return *res
+}
+
+func NewStringOrNumberTypeVariantText(content string) StringOrNumber {
+    return StringOrNumber{
+        Type: StringOrNumberTypeVariantText,
+        content: &content,
+    }
+}
+func NewStringOrNumberTypeVariantNumber(content float64) StringOrNumber {
+    return StringOrNumber{
+        Type: StringOrNumberTypeVariantNumber,
+        content: &content,
+    }
+}
+
+// Generated type representing the anonymous struct variant `Room` of the `Whereabouts` Rust enum
+type WhereaboutsRoomInner struct {
+	Building string `json:"building"`
+	Floor uint32 `json:"floor"`
+}
+// Where an item can be found, if anywhere.
+type WhereaboutsTypes string
+const (
+	// Not known
+	WhereaboutsTypeVariantUnknown WhereaboutsTypes = "Unknown"
+	WhereaboutsTypeVariantAddress WhereaboutsTypes = "Address"
+	WhereaboutsTypeVariantCoordinates WhereaboutsTypes = "Coordinates"
+	WhereaboutsTypeVariantRoom WhereaboutsTypes = "Room"
+)
+type Whereabouts struct{ 
+	Type WhereaboutsTypes `json:"type"`
+	content interface{}
+}
+
+func (w *Whereabouts) UnmarshalJSON(data []byte) error {
+	if string(data) == "null" {
+		w.Type = WhereaboutsTypeVariantUnknown
+		w.content = nil
+		return nil
+	}
+	if res := new(string); json.Unmarshal(data, res) == nil {
+		w.Type = WhereaboutsTypeVariantAddress
+		w.content = res
+		return nil
+	}
+	if res := new(Location); decodeUntaggedObject(data, res, true, "latitude", "longitude") {
+		w.Type = WhereaboutsTypeVariantCoordinates
+		w.content = res
+		return nil
+	}
+	if res := new(WhereaboutsRoomInner); decodeUntaggedObject(data, res, true, "building", "floor") {
+		w.Type = WhereaboutsTypeVariantRoom
+		w.content = res
+		return nil
+	}
+
+	return fmt.Errorf("no variant of Whereabouts matches %s", data)
+}
+
+func (w Whereabouts) MarshalJSON() ([]byte, error) {
+	return json.Marshal(w.content)
+}
+
+func (w Whereabouts) Address() string {
+	res, _ := w.content.(*string)
+	return *res
+}
+func (w Whereabouts) Coordinates() *Location {
+	res, _ := w.content.(*Location)
+	return res
+}
+func (w Whereabouts) Room() *WhereaboutsRoomInner {
+	res, _ := w.content.(*WhereaboutsRoomInner)
+	return res
+}
+
+func NewWhereaboutsTypeVariantUnknown() Whereabouts {
+    return Whereabouts{
+        Type: WhereaboutsTypeVariantUnknown,
+    }
+}
+func NewWhereaboutsTypeVariantAddress(content string) Whereabouts {
+    return Whereabouts{
+        Type: WhereaboutsTypeVariantAddress,
+        content: &content,
+    }
+}
+func NewWhereaboutsTypeVariantCoordinates(content *Location) Whereabouts {
+    return Whereabouts{
+        Type: WhereaboutsTypeVariantCoordinates,
+        content: content,
+    }
+}
+func NewWhereaboutsTypeVariantRoom(content *WhereaboutsRoomInner) Whereabouts {
+    return Whereabouts{
+        Type: WhereaboutsTypeVariantRoom,
+        content: content,
+    }
+}
+
+// decodeUntaggedObject decodes data into v if it is an object with all the required keys. When
+// disallowUnknownFields is set, the object must not have keys that aren't fields of v either.
+// json.Unmarshal alone would decode any object into any struct, so the first object variant of
+// an untagged enum would always match.
+func decodeUntaggedObject(data []byte, v interface{}, disallowUnknownFields bool, required ...string) bool {
+	var fields map[string]json.RawMessage
+	if err := json.Unmarshal(data, &fields); err != nil || fields == nil {
+		return false
+	}
+	for _, key := range required {
+		if _, ok := fields[key]; !ok {
+			return false
+		}
+	}
+	decoder := json.NewDecoder(bytes.NewReader(data))
+	if disallowUnknownFields {
+		decoder.DisallowUnknownFields()
+	}
+	return decoder.Decode(v) == nil
+}
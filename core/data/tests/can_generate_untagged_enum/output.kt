@@ -0,0 +1,98 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Location (
+	val latitude: Double,
+	val longitude: Double
+)
+
+/// A value that may be written as a string or a number.
+@Serializable(with = StringOrNumberSerializer::class)
+sealed class StringOrNumber {
+	@Serializable
+	@SerialName("Text")
+	data class Text(val content: String): StringOrNumber()
+	@Serializable
+	@SerialName("Number")
+	data class Number(val content: Double): StringOrNumber()
+}
+
+object StringOrNumberSerializer : KSerializer<StringOrNumber> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("StringOrNumber")
+
+	override fun serialize(encoder: Encoder, value: StringOrNumber) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is StringOrNumber.Text -> output.json.encodeToJsonElement(serializer<String>(), value.content)
+			is StringOrNumber.Number -> output.json.encodeToJsonElement(serializer<Double>(), value.content)
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): StringOrNumber {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		runCatching { return StringOrNumber.Text(input.json.decodeFromJsonElement(serializer<String>(), element)) }
+		runCatching { return StringOrNumber.Number(input.json.decodeFromJsonElement(serializer<Double>(), element)) }
+		throw SerializationException("No variant of StringOrNumber matches $element")
+	}
+}
+
+/// Generated type representing the anonymous struct variant `Room` of the `Whereabouts` Rust enum
+@Serializable
+data class WhereaboutsRoomInner (
+	val building: String,
+	val floor: UInt
+)
+
+/// Where an item can be found, if anywhere.
+@Serializable(with = WhereaboutsSerializer::class)
+sealed class Whereabouts {
+	/// Not known
+	@Serializable
+	@SerialName("Unknown")
+	object Unknown: Whereabouts()
+	@Serializable
+	@SerialName("Address")
+	data class Address(val content: String): Whereabouts()
+	@Serializable
+	@SerialName("Coordinates")
+	data class Coordinates(val content: Location): Whereabouts()
+	@Serializable
+	@SerialName("Room")
+	data class Room(val content: WhereaboutsRoomInner): Whereabouts()
+}
+
+object WhereaboutsSerializer : KSerializer<Whereabouts> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Whereabouts")
+
+	override fun serialize(encoder: Encoder, value: Whereabouts) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is Whereabouts.Unknown -> JsonNull
+			is Whereabouts.Address -> output.json.encodeToJsonElement(serializer<String>(), value.content)
+			is Whereabouts.Coordinates -> output.json.encodeToJsonElement(serializer<Location>(), value.content)
+			is Whereabouts.Room -> output.json.encodeToJsonElement(WhereaboutsRoomInner.serializer(), value.content)
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Whereabouts {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		if (element is JsonNull) return Whereabouts.Unknown
+		runCatching { return Whereabouts.Address(input.json.decodeFromJsonElement(serializer<String>(), element)) }
+		runCatching { return Whereabouts.Coordinates(input.json.decodeFromJsonElement(serializer<Location>(), element)) }
+		runCatching { return Whereabouts.Room(input.json.decodeFromJsonElement(WhereaboutsRoomInner.serializer(), element)) }
+		throw SerializationException("No variant of Whereabouts matches $element")
+	}
+}
+
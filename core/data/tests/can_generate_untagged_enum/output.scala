@@ -0,0 +1,57 @@
+package com.agilebits
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Long
+
+}
+package onepassword {
+
+case class Location (
+	latitude: Double,
+	longitude: Double
+)
+
+// A value that may be written as a string or a number.
+sealed trait StringOrNumber {
+	def serialName: String
+}
+object StringOrNumber {
+	case class Text(content: String) extends StringOrNumber {
+		val serialName: String = "Text"
+	}
+	case class Number(content: Double) extends StringOrNumber {
+		val serialName: String = "Number"
+	}
+}
+
+// Generated type representing the anonymous struct variant `Room` of the `Whereabouts` Rust enum
+case class WhereaboutsRoomInner (
+	building: String,
+	floor: UInt
+)
+
+// Where an item can be found, if anywhere.
+sealed trait Whereabouts {
+	def serialName: String
+}
+object Whereabouts {
+	// Not known
+	case object Unknown extends Whereabouts {
+		val serialName: String = "Unknown"
+	}
+	case class Address(content: String) extends Whereabouts {
+		val serialName: String = "Address"
+	}
+	case class Coordinates(content: Location) extends Whereabouts {
+		val serialName: String = "Coordinates"
+	}
+	case class Room(content: WhereaboutsRoomInner) extends Whereabouts {
+		val serialName: String = "Room"
+	}
+}
+
+}
@@ -0,0 +1,95 @@
+import Foundation
+
+public struct Location: Codable {
+	public let latitude: Double
+	public let longitude: Double
+
+	public init(latitude: Double, longitude: Double) {
+		self.latitude = latitude
+		self.longitude = longitude
+	}
+}
+
+/// A value that may be written as a string or a number.
+public enum StringOrNumber: Codable {
+	case text(String)
+	case number(Double)
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.singleValueContainer()
+		if let content = try? container.decode(String.self) {
+			self = .text(content)
+			return
+		}
+		if let content = try? container.decode(Double.self) {
+			self = .number(content)
+			return
+		}
+		throw DecodingError.typeMismatch(StringOrNumber.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for StringOrNumber"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.singleValueContainer()
+		switch self {
+		case .text(let content):
+			try container.encode(content)
+		case .number(let content):
+			try container.encode(content)
+		}
+	}
+}
+
+
+/// Generated type representing the anonymous struct variant `Room` of the `Whereabouts` Rust enum
+public struct WhereaboutsRoomInner: Codable {
+	public let building: String
+	public let floor: UInt32
+
+	public init(building: String, floor: UInt32) {
+		self.building = building
+		self.floor = floor
+	}
+}
+/// Where an item can be found, if anywhere.
+public enum Whereabouts: Codable {
+	/// Not known
+	case unknown
+	case address(String)
+	case coordinates(Location)
+	case room(WhereaboutsRoomInner)
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.singleValueContainer()
+		if container.decodeNil() {
+			self = .unknown
+			return
+		}
+		if let content = try? container.decode(String.self) {
+			self = .address(content)
+			return
+		}
+		if let content = try? container.decode(Location.self) {
+			self = .coordinates(content)
+			return
+		}
+		if let content = try? container.decode(WhereaboutsRoomInner.self) {
+			self = .room(content)
+			return
+		}
+		throw DecodingError.typeMismatch(Whereabouts.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Whereabouts"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.singleValueContainer()
+		switch self {
+		case .unknown:
+			try container.encodeNil()
+		case .address(let content):
+			try container.encode(content)
+		case .coordinates(let content):
+			try container.encode(content)
+		case .room(let content):
+			try container.encode(content)
+		}
+	}
+}
@@ -0,0 +1,21 @@
+export interface Location {
+	latitude: number;
+	longitude: number;
+}
+
+/** A value that may be written as a string or a number. */
+export type StringOrNumber = 
+	| string
+	| number;
+
+/** Where an item can be found, if anywhere. */
+export type Whereabouts = 
+	/** Not known */
+	| null
+	| string
+	| Location
+	| {
+	building: string;
+	floor: number;
+};
+
@@ -0,0 +1,14 @@
+#[typeshare]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A shape, told apart by the fields of its object.
+#[typeshare]
+#[serde(untagged)]
+pub enum Shape {
+    Point(Point),
+    Circle { x: f64, y: f64, radius: f64 },
+    Label { text: String, color: Option<String> },
+}
@@ -0,0 +1,109 @@
+package proto
+
+import "bytes"
+import "encoding/json"
+import "fmt"
+
+type Point struct {
+	X float64 `json:"x"`
+	Y float64 `json:"y"`
+}
+// Generated type representing the anonymous struct variant `Circle` of the `Shape` Rust enum
+type ShapeCircleInner struct {
+	X float64 `json:"x"`
+	Y float64 `json:"y"`
+	Radius float64 `json:"radius"`
+}
+// Generated type representing the anonymous struct variant `Label` of the `Shape` Rust enum
+type ShapeLabelInner struct {
+	Text string `json:"text"`
+	Color *string `json:"color,omitempty"`
+}
+// A shape, told apart by the fields of its object.
+type ShapeTypes string
+const (
+	ShapeTypeVariantPoint ShapeTypes = "Point"
+	ShapeTypeVariantCircle ShapeTypes = "Circle"
+	ShapeTypeVariantLabel ShapeTypes = "Label"
+)
+type Shape struct{ 
+	Type ShapeTypes `json:"type"`
+	content interface{}
+}
+
+func (s *Shape) UnmarshalJSON(data []byte) error {
+	if res := new(Point); decodeUntaggedObject(data, res, true, "x", "y") {
+		s.Type = ShapeTypeVariantPoint
+		s.content = res
+		return nil
+	}
+	if res := new(ShapeCircleInner); decodeUntaggedObject(data, res, true, "x", "y", "radius") {
+		s.Type = ShapeTypeVariantCircle
+		s.content = res
+		return nil
+	}
+	if res := new(ShapeLabelInner); decodeUntaggedObject(data, res, true, "text") {
+		s.Type = ShapeTypeVariantLabel
+		s.content = res
+		return nil
+	}
+
+	return fmt.Errorf("no variant of Shape matches %s", data)
+}
+
+func (s Shape) MarshalJSON() ([]byte, error) {
+	return json.Marshal(s.content)
+}
+
+func (s Shape) Point() *Point {
+	res, _ := s.content.(*Point)
+	return res
+}
+func (s Shape) Circle() *ShapeCircleInner {
+	res, _ := s.content.(*ShapeCircleInner)
+	return res
+}
+func (s Shape) Label() *ShapeLabelInner {
+	res, _ := s.content.(*ShapeLabelInner)
+	return res
+}
+
+func NewShapeTypeVariantPoint(content *Point) Shape {
+    return Shape{
+        Type: ShapeTypeVariantPoint,
+        content: content,
+    }
+}
+func NewShapeTypeVariantCircle(content *ShapeCircleInner) Shape {
+    return Shape{
+        Type: ShapeTypeVariantCircle,
+        content: content,
+    }
+}
+func NewShapeTypeVariantLabel(content *ShapeLabelInner) Shape {
+    return Shape{
+        Type: ShapeTypeVariantLabel,
+        content: content,
+    }
+}
+
+// decodeUntaggedObject decodes data into v if it is an object with all the required keys. When
+// disallowUnknownFields is set, the object must not have keys that aren't fields of v either.
+// json.Unmarshal alone would decode any object into any struct, so the first object variant of
+// an untagged enum would always match.
+func decodeUntaggedObject(data []byte, v interface{}, disallowUnknownFields bool, required ...string) bool {
+	var fields map[string]json.RawMessage
+	if err := json.Unmarshal(data, &fields); err != nil || fields == nil {
+		return false
+	}
+	for _, key := range required {
+		if _, ok := fields[key]; !ok {
+			return false
+		}
+	}
+	decoder := json.NewDecoder(bytes.NewReader(data))
+	if disallowUnknownFields {
+		decoder.DisallowUnknownFields()
+	}
+	return decoder.Decode(v) == nil
+}
@@ -0,0 +1,69 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+@Serializable
+data class Point (
+	val x: Double,
+	val y: Double
+)
+
+/// Generated type representing the anonymous struct variant `Circle` of the `Shape` Rust enum
+@Serializable
+data class ShapeCircleInner (
+	val x: Double,
+	val y: Double,
+	val radius: Double
+)
+
+/// Generated type representing the anonymous struct variant `Label` of the `Shape` Rust enum
+@Serializable
+data class ShapeLabelInner (
+	val text: String,
+	val color: String? = null
+)
+
+/// A shape, told apart by the fields of its object.
+@Serializable(with = ShapeSerializer::class)
+sealed class Shape {
+	@Serializable
+	@SerialName("Point")
+	data class Point(val content: Point): Shape()
+	@Serializable
+	@SerialName("Circle")
+	data class Circle(val content: ShapeCircleInner): Shape()
+	@Serializable
+	@SerialName("Label")
+	data class Label(val content: ShapeLabelInner): Shape()
+}
+
+object ShapeSerializer : KSerializer<Shape> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Shape")
+
+	override fun serialize(encoder: Encoder, value: Shape) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is Shape.Point -> output.json.encodeToJsonElement(serializer<Point>(), value.content)
+			is Shape.Circle -> output.json.encodeToJsonElement(ShapeCircleInner.serializer(), value.content)
+			is Shape.Label -> output.json.encodeToJsonElement(ShapeLabelInner.serializer(), value.content)
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Shape {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		runCatching { return Shape.Point(input.json.decodeFromJsonElement(serializer<Point>(), element)) }
+		runCatching { return Shape.Circle(input.json.decodeFromJsonElement(ShapeCircleInner.serializer(), element)) }
+		runCatching { return Shape.Label(input.json.decodeFromJsonElement(ShapeLabelInner.serializer(), element)) }
+		throw SerializationException("No variant of Shape matches $element")
+	}
+}
+
@@ -0,0 +1,39 @@
+package com.agilebits
+
+package onepassword {
+
+case class Point (
+	x: Double,
+	y: Double
+)
+
+// Generated type representing the anonymous struct variant `Circle` of the `Shape` Rust enum
+case class ShapeCircleInner (
+	x: Double,
+	y: Double,
+	radius: Double
+)
+
+// Generated type representing the anonymous struct variant `Label` of the `Shape` Rust enum
+case class ShapeLabelInner (
+	text: String,
+	color: Option[String] = None
+)
+
+// A shape, told apart by the fields of its object.
+sealed trait Shape {
+	def serialName: String
+}
+object Shape {
+	case class Point(content: Point) extends Shape {
+		val serialName: String = "Point"
+	}
+	case class Circle(content: ShapeCircleInner) extends Shape {
+		val serialName: String = "Circle"
+	}
+	case class Label(content: ShapeLabelInner) extends Shape {
+		val serialName: String = "Label"
+	}
+}
+
+}
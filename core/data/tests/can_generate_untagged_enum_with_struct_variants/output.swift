@@ -0,0 +1,71 @@
+import Foundation
+
+public struct Point: Codable {
+	public let x: Double
+	public let y: Double
+
+	public init(x: Double, y: Double) {
+		self.x = x
+		self.y = y
+	}
+}
+
+
+/// Generated type representing the anonymous struct variant `Circle` of the `Shape` Rust enum
+public struct ShapeCircleInner: Codable {
+	public let x: Double
+	public let y: Double
+	public let radius: Double
+
+	public init(x: Double, y: Double, radius: Double) {
+		self.x = x
+		self.y = y
+		self.radius = radius
+	}
+}
+
+/// Generated type representing the anonymous struct variant `Label` of the `Shape` Rust enum
+public struct ShapeLabelInner: Codable {
+	public let text: String
+	public let color: String?
+
+	public init(text: String, color: String?) {
+		self.text = text
+		self.color = color
+	}
+}
+/// A shape, told apart by the fields of its object.
+public enum Shape: Codable {
+	case point(Point)
+	case circle(ShapeCircleInner)
+	case label(ShapeLabelInner)
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.singleValueContainer()
+		if let content = try? container.decode(Point.self) {
+			self = .point(content)
+			return
+		}
+		if let content = try? container.decode(ShapeCircleInner.self) {
+			self = .circle(content)
+			return
+		}
+		if let content = try? container.decode(ShapeLabelInner.self) {
+			self = .label(content)
+			return
+		}
+		throw DecodingError.typeMismatch(Shape.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Shape"))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.singleValueContainer()
+		switch self {
+		case .point(let content):
+			try container.encode(content)
+		case .circle(let content):
+			try container.encode(content)
+		case .label(let content):
+			try container.encode(content)
+		}
+	}
+}
@@ -0,0 +1,18 @@
+export interface Point {
+	x: number;
+	y: number;
+}
+
+/** A shape, told apart by the fields of its object. */
+export type Shape = 
+	| Point
+	| {
+	x: number;
+	y: number;
+	radius: number;
+}
+	| {
+	text: string;
+	color?: string;
+};
+
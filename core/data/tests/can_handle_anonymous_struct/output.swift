@@ -44,15 +44,13 @@ public enum AutofilledBy: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .us:
-				if let content = try? container.decode(AutofilledByUsInner.self, forKey: .content) {
-					self = .us(content)
-					return
-				}
+				let content = try container.decode(AutofilledByUsInner.self, forKey: .content)
+				self = .us(content)
+				return
 			case .somethingElse:
-				if let content = try? container.decode(AutofilledBySomethingElseInner.self, forKey: .content) {
-					self = .somethingElse(content)
-					return
-				}
+				let content = try container.decode(AutofilledBySomethingElseInner.self, forKey: .content)
+				self = .somethingElse(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(AutofilledBy.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for AutofilledBy"))
@@ -121,28 +119,24 @@ public enum EnumWithManyVariants: Codable {
 				self = .unitVariant
 				return
 			case .tupleVariantString:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .tupleVariantString(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .tupleVariantString(content)
+				return
 			case .anonVariant:
-				if let content = try? container.decode(EnumWithManyVariantsAnonVariantInner.self, forKey: .content) {
-					self = .anonVariant(content)
-					return
-				}
+				let content = try container.decode(EnumWithManyVariantsAnonVariantInner.self, forKey: .content)
+				self = .anonVariant(content)
+				return
 			case .tupleVariantInt:
-				if let content = try? container.decode(Int32.self, forKey: .content) {
-					self = .tupleVariantInt(content)
-					return
-				}
+				let content = try container.decode(Int32.self, forKey: .content)
+				self = .tupleVariantInt(content)
+				return
 			case .anotherUnitVariant:
 				self = .anotherUnitVariant
 				return
 			case .anotherAnonVariant:
-				if let content = try? container.decode(EnumWithManyVariantsAnotherAnonVariantInner.self, forKey: .content) {
-					self = .anotherAnonVariant(content)
-					return
-				}
+				let content = try container.decode(EnumWithManyVariantsAnotherAnonVariantInner.self, forKey: .content)
+				self = .anotherAnonVariant(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(EnumWithManyVariants.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for EnumWithManyVariants"))
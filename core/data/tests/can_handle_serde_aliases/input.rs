@@ -0,0 +1,11 @@
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all(serialize = "camelCase", deserialize = "snake_case"))]
+pub struct Profile {
+    /// Was called `name` before
+    #[serde(alias = "name")]
+    pub display_name: String,
+    #[serde(rename(serialize = "avatar", deserialize = "picture"), alias = "image")]
+    pub avatar_url: Option<String>,
+    pub id: String,
+}
@@ -0,0 +1,10 @@
+package proto
+
+import "encoding/json"
+
+type Profile struct {
+	// Was called `name` before
+	DisplayName string `json:"displayName"`
+	AvatarUrl *string `json:"avatar,omitempty"`
+	Id string `json:"id"`
+}
@@ -0,0 +1,19 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.json.JsonNames
+
+@Serializable
+data class Profile (
+	/// Was called `name` before
+	@OptIn(ExperimentalSerializationApi::class)
+	@JsonNames("display_name", "name")
+	val displayName: String,
+	@OptIn(ExperimentalSerializationApi::class)
+	@JsonNames("picture", "image")
+	val avatar: String? = null,
+	val id: String
+)
+
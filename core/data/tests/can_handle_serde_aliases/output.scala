@@ -0,0 +1,12 @@
+package com.agilebits
+
+package onepassword {
+
+case class Profile (
+	// Was called `name` before
+	displayName: String,
+	avatar: Option[String] = None,
+	id: String
+)
+
+}
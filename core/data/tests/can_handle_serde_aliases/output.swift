@@ -0,0 +1,38 @@
+import Foundation
+
+public struct Profile: Codable {
+	/// Was called `name` before
+	public let displayName: String
+	public let avatar: String?
+	public let id: String
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case displayName,
+			displayNameAlias1 = "display_name",
+			displayNameAlias2 = "name",
+			avatar,
+			avatarAlias1 = "picture",
+			avatarAlias2 = "image",
+			id
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		self.displayName = try container.decode(String.self, forKey: [CodingKeys.displayName, .displayNameAlias1, .displayNameAlias2].first(where: container.contains) ?? .displayName)
+		self.avatar = try container.decodeIfPresent(String.self, forKey: [CodingKeys.avatar, .avatarAlias1, .avatarAlias2].first(where: container.contains) ?? .avatar)
+		self.id = try container.decode(String.self, forKey: .id)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: CodingKeys.self)
+		try container.encode(self.displayName, forKey: .displayName)
+		try container.encodeIfPresent(self.avatar, forKey: .avatar)
+		try container.encode(self.id, forKey: .id)
+	}
+
+	public init(displayName: String, avatar: String?, id: String) {
+		self.displayName = displayName
+		self.avatar = avatar
+		self.id = id
+	}
+}
@@ -0,0 +1,11 @@
+export interface Profile {
+	/**
+	 * Was called `name` before
+	 * Deserialized from `display_name` or `name`
+	 */
+	displayName: string;
+	/** Deserialized from `picture` or `image` */
+	avatar?: string;
+	id: string;
+}
+
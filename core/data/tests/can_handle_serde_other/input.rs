@@ -0,0 +1,41 @@
+/// A simple enum that tolerates new variants
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub enum Platform {
+    Ios,
+    Android,
+    #[serde(other)]
+    Unknown,
+}
+
+/// An algebraic enum that tolerates new variants
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "content")]
+pub enum Event {
+    Opened(String),
+    Closed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// An externally tagged enum that tolerates new variants
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub enum Command {
+    Start,
+    Run(u32),
+    #[serde(other)]
+    Unknown,
+}
+
+/// An internally tagged enum that tolerates new variants
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Shape {
+    Circle { radius: f64 },
+    Empty,
+    #[serde(other)]
+    Unknown,
+}
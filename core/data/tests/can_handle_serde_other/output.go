@@ -0,0 +1,253 @@
+package proto
+
+import "encoding/json"
+import "fmt"
+
+// An externally tagged enum that tolerates new variants
+type CommandTypes string
+const (
+	CommandTypeVariantStart CommandTypes = "Start"
+	CommandTypeVariantRun CommandTypes = "Run"
+	CommandTypeVariantUnknown CommandTypes = "Unknown"
+)
+type Command struct{ 
+	Type CommandTypes `json:"type"`
+	content interface{}
+}
+
+func (c *Command) UnmarshalJSON(data []byte) error {
+	var tag CommandTypes
+	if err := json.Unmarshal(data, &tag); err == nil {
+		c.Type = tag
+		c.content = nil
+		return nil
+	}
+
+	var enum map[CommandTypes]json.RawMessage
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+	if len(enum) != 1 {
+		return fmt.Errorf("expected a single variant for Command, got %d", len(enum))
+	}
+
+	for tag, content := range enum {
+		c.Type = tag
+		switch c.Type {
+		case CommandTypeVariantStart:
+			return nil
+		case CommandTypeVariantRun:
+			var res uint32
+			c.content = &res
+		case CommandTypeVariantUnknown:
+			return nil
+
+		}
+		if err := json.Unmarshal(content, &c.content); err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+func (c Command) MarshalJSON() ([]byte, error) {
+	if c.content == nil {
+		return json.Marshal(c.Type)
+	}
+	return json.Marshal(map[CommandTypes]interface{}{c.Type: c.content})
+}
+
+func (c Command) Run() uint32 {
+	res, _ := c.content.(*uint32)
+	return *res
+}
+
+func NewCommandTypeVariantStart() Command {
+    return Command{
+        Type: CommandTypeVariantStart,
+    }
+}
+func NewCommandTypeVariantRun(content uint32) Command {
+    return Command{
+        Type: CommandTypeVariantRun,
+        content: &content,
+    }
+}
+func NewCommandTypeVariantUnknown() Command {
+    return Command{
+        Type: CommandTypeVariantUnknown,
+    }
+}
+
+// An algebraic enum that tolerates new variants
+type EventTypes string
+const (
+	EventTypeVariantOpened EventTypes = "Opened"
+	EventTypeVariantClosed EventTypes = "Closed"
+	EventTypeVariantUnknown EventTypes = "Unknown"
+)
+type Event struct{ 
+	Type EventTypes `json:"type"`
+	content interface{}
+}
+
+func (e *Event) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    EventTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	e.Type = enum.Tag
+	switch e.Type {
+	case EventTypeVariantOpened:
+		var res string
+		e.content = &res
+	case EventTypeVariantClosed:
+		return nil
+	case EventTypeVariantUnknown:
+		return nil
+
+	}
+	if err := json.Unmarshal(enum.Content, &e.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (e Event) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    EventTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = e.Type
+    enum.Content = e.content
+    return json.Marshal(enum)
+}
+
+func (e Event) Opened() string {
+	res, _ := e.content.(*string)
+	return *res
+}
+
+func NewEventTypeVariantOpened(content string) Event {
+    return Event{
+        Type: EventTypeVariantOpened,
+        content: &content,
+    }
+}
+func NewEventTypeVariantClosed() Event {
+    return Event{
+        Type: EventTypeVariantClosed,
+    }
+}
+func NewEventTypeVariantUnknown() Event {
+    return Event{
+        Type: EventTypeVariantUnknown,
+    }
+}
+
+// A simple enum that tolerates new variants
+type Platform string
+const (
+	PlatformIos Platform = "Ios"
+	PlatformAndroid Platform = "Android"
+	PlatformUnknown Platform = "Unknown"
+)
+// Generated type representing the anonymous struct variant `Circle` of the `Shape` Rust enum
+type ShapeCircleInner struct {
+	Radius float64 `json:"radius"`
+}
+// An internally tagged enum that tolerates new variants
+type ShapeKinds string
+const (
+	ShapeKindVariantCircle ShapeKinds = "Circle"
+	ShapeKindVariantEmpty ShapeKinds = "Empty"
+	ShapeKindVariantUnknown ShapeKinds = "Unknown"
+)
+type Shape struct{ 
+	Kind ShapeKinds `json:"kind"`
+	content interface{}
+}
+
+func (s *Shape) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    ShapeKinds   `json:"kind"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	s.Kind = enum.Tag
+	switch s.Kind {
+	case ShapeKindVariantCircle:
+		var res ShapeCircleInner
+		s.content = &res
+	case ShapeKindVariantEmpty:
+		return nil
+	case ShapeKindVariantUnknown:
+		return nil
+
+	}
+	// The tag isn't part of the content, which may collect unknown keys
+	var fields map[string]json.RawMessage
+	if err := json.Unmarshal(data, &fields); err != nil {
+		return err
+	}
+	delete(fields, "kind")
+	content, err := json.Marshal(fields)
+	if err != nil {
+		return err
+	}
+	if err := json.Unmarshal(content, &s.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (s Shape) MarshalJSON() ([]byte, error) {
+	fields := map[string]json.RawMessage{}
+	if s.content != nil {
+		content, err := json.Marshal(s.content)
+		if err != nil {
+			return nil, err
+		}
+		if err := json.Unmarshal(content, &fields); err != nil {
+			return nil, err
+		}
+	}
+	tag, err := json.Marshal(s.Kind)
+	if err != nil {
+		return nil, err
+	}
+	fields["kind"] = tag
+	return json.Marshal(fields)
+}
+
+func (s Shape) Circle() *ShapeCircleInner {
+	res, _ := s.content.(*ShapeCircleInner)
+	return res
+}
+
+func NewShapeKindVariantCircle(content *ShapeCircleInner) Shape {
+    return Shape{
+        Kind: ShapeKindVariantCircle,
+        content: content,
+    }
+}
+func NewShapeKindVariantEmpty() Shape {
+    return Shape{
+        Kind: ShapeKindVariantEmpty,
+    }
+}
+func NewShapeKindVariantUnknown() Shape {
+    return Shape{
+        Kind: ShapeKindVariantUnknown,
+    }
+}
+
@@ -0,0 +1,153 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.KSerializer
+import kotlinx.serialization.SerializationException
+import kotlinx.serialization.builtins.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+import kotlinx.serialization.serializer
+
+/// An externally tagged enum that tolerates new variants
+@Serializable(with = CommandSerializer::class)
+sealed class Command {
+	@Serializable
+	@SerialName("Start")
+	object Start: Command()
+	@Serializable
+	@SerialName("Run")
+	data class Run(val content: UInt): Command()
+	data class Unknown(val json: JsonElement): Command()
+}
+
+object CommandSerializer : KSerializer<Command> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Command")
+
+	override fun serialize(encoder: Encoder, value: Command) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is Command.Start -> JsonPrimitive("Start")
+			is Command.Run -> buildJsonObject { put("Run", output.json.encodeToJsonElement(serializer<UInt>(), value.content)) }
+			is Command.Unknown -> value.json
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Command {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		if (element is JsonPrimitive && element.isString) {
+			when (element.content) {
+				"Start" -> return Command.Start
+			}
+		}
+		if (element is JsonObject && element.size == 1) {
+			val (key, content) = element.entries.first()
+			when (key) {
+				"Run" -> return Command.Run(input.json.decodeFromJsonElement(serializer<UInt>(), content))
+			}
+		}
+		return Command.Unknown(element)
+	}
+}
+
+/// An algebraic enum that tolerates new variants
+@Serializable(with = EventSerializer::class)
+sealed class Event {
+	@Serializable
+	@SerialName("Opened")
+	data class Opened(val content: String): Event()
+	@Serializable
+	@SerialName("Closed")
+	object Closed: Event()
+	data class Unknown(val json: JsonElement): Event()
+}
+
+object EventSerializer : KSerializer<Event> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Event")
+
+	override fun serialize(encoder: Encoder, value: Event) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is Event.Opened -> buildJsonObject { put("type", "Opened"); put("content", output.json.encodeToJsonElement(serializer<String>(), value.content)) }
+			is Event.Closed -> buildJsonObject { put("type", "Closed") }
+			is Event.Unknown -> value.json
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Event {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		when ((element as? JsonObject)?.get("type")?.jsonPrimitive?.contentOrNull) {
+			"Opened" -> return Event.Opened(input.json.decodeFromJsonElement(serializer<String>(), element.jsonObject.getValue("content")))
+			"Closed" -> return Event.Closed
+		}
+		return Event.Unknown(element)
+	}
+}
+
+/// A simple enum that tolerates new variants
+@Serializable(with = PlatformSerializer::class)
+enum class Platform(val string: String) {
+	@SerialName("Ios")
+	Ios("Ios"),
+	@SerialName("Android")
+	Android("Android"),
+	@SerialName("Unknown")
+	Unknown("Unknown"),
+}
+
+object PlatformSerializer : KSerializer<Platform> {
+	override val descriptor: SerialDescriptor = PrimitiveSerialDescriptor("Platform", PrimitiveKind.STRING)
+
+	override fun serialize(encoder: Encoder, value: Platform) {
+		encoder.encodeString(value.string)
+	}
+
+	override fun deserialize(decoder: Decoder): Platform {
+		val value = decoder.decodeString()
+		return Platform.values().firstOrNull { it.string == value }
+			?: Platform.Unknown
+	}
+}
+
+/// An internally tagged enum that tolerates new variants
+@Serializable(with = ShapeSerializer::class)
+sealed class Shape {
+	@Serializable
+	@SerialName("Circle")
+	data class Circle(
+		val radius: Double
+	): Shape()
+	@Serializable
+	@SerialName("Empty")
+	object Empty: Shape()
+	data class Unknown(val json: JsonElement): Shape()
+}
+
+object ShapeSerializer : KSerializer<Shape> {
+	override val descriptor: SerialDescriptor = buildClassSerialDescriptor("Shape")
+
+	override fun serialize(encoder: Encoder, value: Shape) {
+		val output = encoder as JsonEncoder
+		output.encodeJsonElement(when (value) {
+			is Shape.Circle -> JsonObject(mapOf("kind" to JsonPrimitive("Circle")) + output.json.encodeToJsonElement(Shape.Circle.serializer(), value).jsonObject)
+			is Shape.Empty -> buildJsonObject { put("kind", "Empty") }
+			is Shape.Unknown -> value.json
+		})
+	}
+
+	override fun deserialize(decoder: Decoder): Shape {
+		val input = decoder as JsonDecoder
+		val element = input.decodeJsonElement()
+		when ((element as? JsonObject)?.get("kind")?.jsonPrimitive?.contentOrNull) {
+			"Circle" -> return input.json.decodeFromJsonElement(Shape.Circle.serializer(), JsonObject(element.jsonObject - "kind"))
+			"Empty" -> return Shape.Empty
+		}
+		return Shape.Unknown(element)
+	}
+}
+
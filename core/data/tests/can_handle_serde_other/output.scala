@@ -0,0 +1,79 @@
+package com.agilebits
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Long
+
+}
+package onepassword {
+
+// An externally tagged enum that tolerates new variants
+sealed trait Command {
+	def serialName: String
+}
+object Command {
+	case object Start extends Command {
+		val serialName: String = "Start"
+	}
+	case class Run(content: UInt) extends Command {
+		val serialName: String = "Run"
+	}
+	case object Unknown extends Command {
+		val serialName: String = "Unknown"
+	}
+}
+
+// An algebraic enum that tolerates new variants
+sealed trait Event {
+	def serialName: String
+}
+object Event {
+	case class Opened(content: String) extends Event {
+		val serialName: String = "Opened"
+	}
+	case object Closed extends Event {
+		val serialName: String = "Closed"
+	}
+	case object Unknown extends Event {
+		val serialName: String = "Unknown"
+	}
+}
+
+// A simple enum that tolerates new variants
+sealed trait Platform {
+	def serialName: String
+}
+object Platform {
+	case object Ios extends Platform {
+		val serialName: String = "Ios"
+	}
+	case object Android extends Platform {
+		val serialName: String = "Android"
+	}
+	case object Unknown extends Platform {
+		val serialName: String = "Unknown"
+	}
+}
+
+// An internally tagged enum that tolerates new variants
+sealed trait Shape {
+	def serialName: String
+}
+object Shape {
+	case class Circle(
+		radius: Double
+	) extends Shape {
+		val serialName: String = "Circle"
+	}
+	case object Empty extends Shape {
+		val serialName: String = "Empty"
+	}
+	case object Unknown extends Shape {
+		val serialName: String = "Unknown"
+	}
+}
+
+}
@@ -0,0 +1,236 @@
+import Foundation
+
+/// An externally tagged enum that tolerates new variants
+public enum Command: Codable {
+	case start
+	case run(UInt32)
+	case unknown(CodableJSON)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case start = "Start",
+			run = "Run"
+	}
+
+	public init(from decoder: Decoder) throws {
+		if let container = try? decoder.singleValueContainer(), let type = try? container.decode(CodingKeys.self) {
+			switch type {
+			case .start:
+				self = .start
+				return
+			default:
+				break
+			}
+		}
+		if let container = try? decoder.container(keyedBy: CodingKeys.self), let type = container.allKeys.first {
+			switch type {
+			case .run:
+				let content = try container.decode(UInt32.self, forKey: .run)
+				self = .run(content)
+				return
+			default:
+				break
+			}
+		}
+		self = .unknown(try CodableJSON(from: decoder))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		switch self {
+		case .start:
+			var container = encoder.singleValueContainer()
+			try container.encode(CodingKeys.start)
+		case .run(let content):
+			var container = encoder.container(keyedBy: CodingKeys.self)
+			try container.encode(content, forKey: .run)
+		case .unknown(let json):
+			try json.encode(to: encoder)
+		}
+	}
+}
+
+/// An algebraic enum that tolerates new variants
+public enum Event: Codable {
+	case opened(String)
+	case closed
+	case unknown(CodableJSON)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case opened = "Opened",
+			closed = "Closed"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case type, content
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
+			switch type {
+			case .opened:
+				let content = try container.decode(String.self, forKey: .content)
+				self = .opened(content)
+				return
+			case .closed:
+				self = .closed
+				return
+			}
+		}
+		self = .unknown(try CodableJSON(from: decoder))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .opened(let content):
+			try container.encode(CodingKeys.opened, forKey: .type)
+			try container.encode(content, forKey: .content)
+		case .closed:
+			try container.encode(CodingKeys.closed, forKey: .type)
+		case .unknown(let json):
+			try json.encode(to: encoder)
+		}
+	}
+}
+
+/// A simple enum that tolerates new variants
+public enum Platform: String, Codable {
+	case ios = "Ios"
+	case android = "Android"
+	case unknown = "Unknown"
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.singleValueContainer()
+		let rawValue = try container.decode(String.self)
+		self = Platform(rawValue: rawValue) ?? .unknown
+	}
+}
+
+
+/// Generated type representing the anonymous struct variant `Circle` of the `Shape` Rust enum
+public struct ShapeCircleInner: Codable {
+	public let radius: Double
+
+	public init(radius: Double) {
+		self.radius = radius
+	}
+}
+/// An internally tagged enum that tolerates new variants
+public enum Shape: Codable {
+	case circle(ShapeCircleInner)
+	case empty
+	case unknown(CodableJSON)
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case circle = "Circle",
+			empty = "Empty"
+	}
+
+	private enum ContainerCodingKeys: String, CodingKey {
+		case kind
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: ContainerCodingKeys.self)
+		if let type = try? container.decode(CodingKeys.self, forKey: .kind) {
+			switch type {
+			case .circle:
+				let content = try ShapeCircleInner(from: decoder)
+				self = .circle(content)
+				return
+			case .empty:
+				self = .empty
+				return
+			}
+		}
+		self = .unknown(try CodableJSON(from: decoder))
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: ContainerCodingKeys.self)
+		switch self {
+		case .circle(let content):
+			try container.encode(CodingKeys.circle, forKey: .kind)
+			try content.encode(to: encoder)
+		case .empty:
+			try container.encode(CodingKeys.empty, forKey: .kind)
+		case .unknown(let json):
+			try json.encode(to: encoder)
+		}
+	}
+}
+
+/// Holds the raw JSON of a value that doesn't match any known variant
+public enum CodableJSON: Codable {
+	case null
+	case bool(Bool)
+	case number(Double)
+	case string(String)
+	case array([CodableJSON])
+	case object([String: CodableJSON])
+
+	private struct Key: CodingKey {
+		var stringValue: String
+		var intValue: Int? { nil }
+
+		init(stringValue: String) {
+			self.stringValue = stringValue
+		}
+
+		init?(intValue: Int) {
+			return nil
+		}
+	}
+
+	public init(from decoder: Decoder) throws {
+		if let container = try? decoder.container(keyedBy: Key.self) {
+			var object = [String: CodableJSON]()
+			for key in container.allKeys {
+				object[key.stringValue] = try container.decode(CodableJSON.self, forKey: key)
+			}
+			self = .object(object)
+		} else if var container = try? decoder.unkeyedContainer() {
+			var array = [CodableJSON]()
+			while !container.isAtEnd {
+				array.append(try container.decode(CodableJSON.self))
+			}
+			self = .array(array)
+		} else {
+			let container = try decoder.singleValueContainer()
+			if container.decodeNil() {
+				self = .null
+			} else if let value = try? container.decode(Bool.self) {
+				self = .bool(value)
+			} else if let value = try? container.decode(Double.self) {
+				self = .number(value)
+			} else {
+				self = .string(try container.decode(String.self))
+			}
+		}
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		switch self {
+		case .object(let object):
+			var container = encoder.container(keyedBy: Key.self)
+			for (key, value) in object {
+				try container.encode(value, forKey: Key(stringValue: key))
+			}
+		case .array(let array):
+			var container = encoder.unkeyedContainer()
+			try container.encode(contentsOf: array)
+		case .null:
+			var container = encoder.singleValueContainer()
+			try container.encodeNil()
+		case .bool(let value):
+			var container = encoder.singleValueContainer()
+			try container.encode(value)
+		case .number(let value):
+			var container = encoder.singleValueContainer()
+			try container.encode(value)
+		case .string(let value):
+			var container = encoder.singleValueContainer()
+			try container.encode(value)
+		}
+	}
+}
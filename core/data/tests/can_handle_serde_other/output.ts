@@ -0,0 +1,26 @@
+/** An externally tagged enum that tolerates new variants */
+export type Command = 
+	| "Start"
+	| { Run: number }
+	| string;
+
+/** An algebraic enum that tolerates new variants */
+export type Event = 
+	| { type: "Opened", content: string }
+	| { type: "Closed", content?: undefined }
+	| { type: string, content?: unknown };
+
+/** A simple enum that tolerates new variants */
+export type Platform = 
+	| "Ios"
+	| "Android"
+	| string;
+
+/** An internally tagged enum that tolerates new variants */
+export type Shape = 
+	| { kind: "Circle" } & {
+	radius: number;
+}
+	| { kind: "Empty" }
+	| { kind: string };
+
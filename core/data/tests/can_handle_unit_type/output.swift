@@ -26,10 +26,9 @@ public enum EnumHasVoidType: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .hasAUnit:
-				if let content = try? container.decode(CodableVoid.self, forKey: .content) {
-					self = .hasAUnit(content)
-					return
-				}
+				let content = try container.decode(CodableVoid.self, forKey: .content)
+				self = .hasAUnit(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(EnumHasVoidType.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for EnumHasVoidType"))
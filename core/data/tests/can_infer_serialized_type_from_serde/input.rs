@@ -0,0 +1,43 @@
+/// Serialized as a string with `Display` and `FromStr`
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Color {
+    Red,
+    Green,
+    Custom(u8, u8, u8),
+}
+
+/// Serialized as a list of tags
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(from = "Vec<String>", into = "Vec<String>")]
+pub struct Tags {
+    tags: HashSet<String>,
+}
+
+/// Only deserialized from a string, so it is still serialized as a struct
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Version {
+    major: u32,
+    minor: u32,
+}
+
+/// Serialized as its only field
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UserId {
+    id: String,
+}
+
+#[typeshare]
+#[derive(Serialize, Deserialize)]
+pub struct User {
+    id: UserId,
+    tags: Tags,
+    favorite_color: Color,
+    version: Version,
+}
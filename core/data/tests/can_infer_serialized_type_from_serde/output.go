@@ -0,0 +1,24 @@
+package proto
+
+import "encoding/json"
+
+// Serialized as a string with `Display` and `FromStr`
+type Color string
+
+// Serialized as a list of tags
+type Tags []string
+
+// Serialized as its only field
+type UserId string
+
+// Only deserialized from a string, so it is still serialized as a struct
+type Version struct {
+	Major uint32 `json:"major"`
+	Minor uint32 `json:"minor"`
+}
+type User struct {
+	Id UserId `json:"id"`
+	Tags Tags `json:"tags"`
+	FavoriteColor Color `json:"favorite_color"`
+	Version Version `json:"version"`
+}
@@ -0,0 +1,29 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+
+/// Serialized as a string with `Display` and `FromStr`
+typealias Color = String
+
+/// Serialized as a list of tags
+typealias Tags = List<String>
+
+/// Serialized as its only field
+typealias UserId = String
+
+/// Only deserialized from a string, so it is still serialized as a struct
+@Serializable
+data class Version (
+	val major: UInt,
+	val minor: UInt
+)
+
+@Serializable
+data class User (
+	val id: UserId,
+	val tags: Tags,
+	val favorite_color: Color,
+	val version: Version
+)
+
@@ -0,0 +1,35 @@
+package com.agilebits
+
+package object onepassword {
+
+type UByte = Byte
+type UShort = Short
+type UInt = Int
+type ULong = Long
+
+// Serialized as a string with `Display` and `FromStr`
+type Color = String
+
+// Serialized as a list of tags
+type Tags = Vector[String]
+
+// Serialized as its only field
+type UserId = String
+
+}
+package onepassword {
+
+case class User (
+	id: UserId,
+	tags: Tags,
+	favorite_color: Color,
+	version: Version
+)
+
+// Only deserialized from a string, so it is still serialized as a struct
+case class Version (
+	major: UInt,
+	minor: UInt
+)
+
+}
@@ -0,0 +1,35 @@
+import Foundation
+
+/// Serialized as a string with `Display` and `FromStr`
+public typealias Color = String
+
+/// Serialized as a list of tags
+public typealias Tags = [String]
+
+/// Serialized as its only field
+public typealias UserId = String
+
+/// Only deserialized from a string, so it is still serialized as a struct
+public struct Version: Codable {
+	public let major: UInt32
+	public let minor: UInt32
+
+	public init(major: UInt32, minor: UInt32) {
+		self.major = major
+		self.minor = minor
+	}
+}
+
+public struct User: Codable {
+	public let id: UserId
+	public let tags: Tags
+	public let favorite_color: Color
+	public let version: Version
+
+	public init(id: UserId, tags: Tags, favorite_color: Color, version: Version) {
+		self.id = id
+		self.tags = tags
+		self.favorite_color = favorite_color
+		self.version = version
+	}
+}
@@ -0,0 +1,22 @@
+/** Serialized as a string with `Display` and `FromStr` */
+export type Color = string;
+
+/** Serialized as a list of tags */
+export type Tags = string[];
+
+/** Serialized as its only field */
+export type UserId = string;
+
+/** Only deserialized from a string, so it is still serialized as a struct */
+export interface Version {
+	major: number;
+	minor: number;
+}
+
+export interface User {
+	id: UserId;
+	tags: Tags;
+	favorite_color: Color;
+	version: Version;
+}
+
@@ -40,15 +40,13 @@ public enum OverrideEnum: Codable {
 				self = .unitVariant
 				return
 			case .tupleVariant:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .tupleVariant(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .tupleVariant(content)
+				return
 			case .anonymousStructVariant:
-				if let content = try? container.decode(OverrideEnumAnonymousStructVariantInner.self, forKey: .content) {
-					self = .anonymousStructVariant(content)
-					return
-				}
+				let content = try container.decode(OverrideEnumAnonymousStructVariantInner.self, forKey: .content)
+				self = .anonymousStructVariant(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(OverrideEnum.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for OverrideEnum"))
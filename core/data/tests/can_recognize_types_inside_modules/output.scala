@@ -5,7 +5,7 @@ package object onepassword {
 type UByte = Byte
 type UShort = Short
 type UInt = Int
-type ULong = Int
+type ULong = Long
 
 }
 package onepassword {
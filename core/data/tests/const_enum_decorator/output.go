@@ -0,0 +1,24 @@
+package proto
+
+import "encoding/json"
+
+type BestHockeyTeams string
+const (
+	BestHockeyTeamsPittsburghPenguins BestHockeyTeams = "PittsburghPenguins"
+)
+type BestHockeyTeams1 string
+const (
+	BestHockeyTeams1PittsburghPenguins BestHockeyTeams1 = "PittsburghPenguins"
+)
+type BestHockeyTeams2 string
+const (
+	BestHockeyTeams2PittsburghPenguins BestHockeyTeams2 = "PittsburghPenguins"
+)
+type BestHockeyTeams3 string
+const (
+	BestHockeyTeams3PittsburghPenguins BestHockeyTeams3 = "PittsburghPenguins"
+)
+type BestHockeyTeams4 string
+const (
+	BestHockeyTeams4PittsburghPenguins BestHockeyTeams4 = "PittsburghPenguins"
+)
@@ -90,18 +90,16 @@ public enum TestEnum: Codable {
 				self = .variant5
 				return
 			case .variant7:
-				if let content = try? container.decode(TestEnumVariant7Inner.self, forKey: .content) {
-					self = .variant7(content)
-					return
-				}
+				let content = try container.decode(TestEnumVariant7Inner.self, forKey: .content)
+				self = .variant7(content)
+				return
 			case .variant8:
 				self = .variant8
 				return
 			case .variant9:
-				if let content = try? container.decode(TestEnumVariant9Inner.self, forKey: .content) {
-					self = .variant9(content)
-					return
-				}
+				let content = try container.decode(TestEnumVariant9Inner.self, forKey: .content)
+				self = .variant9(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(TestEnum.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for TestEnum"))
@@ -0,0 +1,67 @@
+package proto
+
+import "encoding/json"
+
+type catch struct {
+	Default string `json:"default"`
+	Case string `json:"case"`
+}
+type switchTypes string
+const (
+	switchTypeVariantdefault switchTypes = "default"
+)
+type switch_ struct{ 
+	Type switchTypes `json:"type"`
+	content interface{}
+}
+
+func (s *switch_) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    switchTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	s.Type = enum.Tag
+	switch s.Type {
+	case switchTypeVariantdefault:
+		var res catch
+		s.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &s.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (s switch_) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    switchTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = s.Type
+    enum.Content = s.content
+    return json.Marshal(enum)
+}
+
+func (s switch_) default_() *catch {
+	res, _ := s.content.(*catch)
+	return res
+}
+
+func NewswitchTypeVariantdefault(content *catch) switch_ {
+    return switch_{
+        Type: switchTypeVariantdefault,
+        content: content,
+    }
+}
+
+type throws string
+const (
+	throwscase throws = "case"
+	throwsdefault throws = "default"
+)
@@ -26,10 +26,9 @@ public enum `switch`: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .default:
-				if let content = try? container.decode(`catch`.self, forKey: .content) {
-					self = .default(content)
-					return
-				}
+				let content = try container.decode(`catch`.self, forKey: .content)
+				self = .default(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(`switch`.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for `switch`"))
@@ -0,0 +1,24 @@
+package proto
+
+import "encoding/json"
+
+type ButtonState struct {
+}
+type ButtonStyle struct {
+}
+type Button[T any, V any, I any] struct {
+	// Label of the button
+	Label I `json:"label"`
+	// Accessibility label if it needed to be different than label
+	AccessibilityLabel *string `json:"accessibility_label,omitempty"`
+	// Optional tooltips that provide extra explanation for a button
+	Tooltip *string `json:"tooltip,omitempty"`
+	// Button action if there one
+	Action *T `json:"action,omitempty"`
+	// Icon if there is one
+	Icon *V `json:"icon,omitempty"`
+	// Button state
+	State ButtonState `json:"state"`
+	// Button Mode
+	Style ButtonStyle `json:"style"`
+}
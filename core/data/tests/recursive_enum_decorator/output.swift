@@ -38,20 +38,17 @@ public indirect enum MoreOptions: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .news:
-				if let content = try? container.decode(Bool.self, forKey: .content) {
-					self = .news(content)
-					return
-				}
+				let content = try container.decode(Bool.self, forKey: .content)
+				self = .news(content)
+				return
 			case .exactly:
-				if let content = try? container.decode(MoreOptionsExactlyInner.self, forKey: .content) {
-					self = .exactly(content)
-					return
-				}
+				let content = try container.decode(MoreOptionsExactlyInner.self, forKey: .content)
+				self = .exactly(content)
+				return
 			case .built:
-				if let content = try? container.decode(MoreOptionsBuiltInner.self, forKey: .content) {
-					self = .built(content)
-					return
-				}
+				let content = try container.decode(MoreOptionsBuiltInner.self, forKey: .content)
+				self = .built(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(MoreOptions.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for MoreOptions"))
@@ -93,20 +90,17 @@ public indirect enum Options: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .red:
-				if let content = try? container.decode(Bool.self, forKey: .content) {
-					self = .red(content)
-					return
-				}
+				let content = try container.decode(Bool.self, forKey: .content)
+				self = .red(content)
+				return
 			case .banana:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .banana(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .banana(content)
+				return
 			case .vermont:
-				if let content = try? container.decode(Options.self, forKey: .content) {
-					self = .vermont(content)
-					return
-				}
+				let content = try container.decode(Options.self, forKey: .content)
+				self = .vermont(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(Options.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for Options"))
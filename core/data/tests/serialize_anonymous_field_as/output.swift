@@ -19,15 +19,13 @@ public enum SomeEnum: Codable {
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .context:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .context(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .context(content)
+				return
 			case .other:
-				if let content = try? container.decode(Int32.self, forKey: .content) {
-					self = .other(content)
-					return
-				}
+				let content = try container.decode(Int32.self, forKey: .content)
+				self = .other(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(SomeEnum.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for SomeEnum"))
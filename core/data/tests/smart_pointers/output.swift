@@ -93,10 +93,9 @@ public enum BoxyColors: Codable {
 				self = .blue
 				return
 			case .green:
-				if let content = try? container.decode(String.self, forKey: .content) {
-					self = .green(content)
-					return
-				}
+				let content = try container.decode(String.self, forKey: .content)
+				self = .green(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(BoxyColors.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for BoxyColors"))
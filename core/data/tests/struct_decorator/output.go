@@ -0,0 +1,26 @@
+package proto
+
+import "encoding/json"
+
+type BestHockeyTeams5 string
+
+type BestHockeyTeams struct {
+	PittsburghPenguins uint32 `json:"PittsburghPenguins"`
+	Lies string `json:"Lies"`
+}
+type BestHockeyTeams1 struct {
+	PittsburghPenguins uint32 `json:"PittsburghPenguins"`
+	Lies string `json:"Lies"`
+}
+type BestHockeyTeams2 struct {
+	PittsburghPenguins uint32 `json:"PittsburghPenguins"`
+	Lies string `json:"Lies"`
+}
+type BestHockeyTeams3 struct {
+	PittsburghPenguins uint32 `json:"PittsburghPenguins"`
+	Lies string `json:"Lies"`
+}
+type BestHockeyTeams4 struct {
+	PittsburghPenguins uint32 `json:"PittsburghPenguins"`
+	Lies string `json:"Lies"`
+}
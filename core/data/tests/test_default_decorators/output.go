@@ -0,0 +1,6 @@
+package proto
+
+import "encoding/json"
+
+type EmptyType struct {
+}
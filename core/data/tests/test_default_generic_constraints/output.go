@@ -0,0 +1,67 @@
+package proto
+
+import "encoding/json"
+
+type GenericType[K any, V any] struct {
+	Key K `json:"key"`
+	Value V `json:"value"`
+}
+// Generated type representing the anonymous struct variant `Variant` of the `GenericEnum` Rust enum
+type GenericEnumVariantInner[K any, V any] struct {
+	Key K `json:"key"`
+	Value V `json:"value"`
+}
+type GenericEnumTypes string
+const (
+	GenericEnumTypeVariantVariant GenericEnumTypes = "Variant"
+)
+type GenericEnum[K any, V any] struct{ 
+	Type GenericEnumTypes `json:"type"`
+	content interface{}
+}
+
+func (g *GenericEnum[K, V]) UnmarshalJSON(data []byte) error {
+	var enum struct {
+		Tag    GenericEnumTypes   `json:"type"`
+		Content json.RawMessage `json:"content"`
+	}
+	if err := json.Unmarshal(data, &enum); err != nil {
+		return err
+	}
+
+	g.Type = enum.Tag
+	switch g.Type {
+	case GenericEnumTypeVariantVariant:
+		var res GenericEnumVariantInner[K, V]
+		g.content = &res
+
+	}
+	if err := json.Unmarshal(enum.Content, &g.content); err != nil {
+		return err
+	}
+
+	return nil
+}
+
+func (g GenericEnum[K, V]) MarshalJSON() ([]byte, error) {
+    var enum struct {
+		Tag    GenericEnumTypes   `json:"type"`
+		Content interface{} `json:"content,omitempty"`
+    }
+    enum.Tag = g.Type
+    enum.Content = g.content
+    return json.Marshal(enum)
+}
+
+func (g GenericEnum[K, V]) Variant() *GenericEnumVariantInner[K, V] {
+	res, _ := g.content.(*GenericEnumVariantInner[K, V])
+	return res
+}
+
+func NewGenericEnumTypeVariantVariant[K any, V any](content *GenericEnumVariantInner[K, V]) GenericEnum[K, V] {
+    return GenericEnum[K, V]{
+        Type: GenericEnumTypeVariantVariant,
+        content: content,
+    }
+}
+
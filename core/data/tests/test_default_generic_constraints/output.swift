@@ -37,10 +37,9 @@ public enum GenericEnum<K: Codable & Identifiable & Sendable, V: Codable & Ident
 		if let type = try? container.decode(CodingKeys.self, forKey: .type) {
 			switch type {
 			case .variant:
-				if let content = try? container.decode(GenericEnumVariantInner<K, V>.self, forKey: .content) {
-					self = .variant(content)
-					return
-				}
+				let content = try container.decode(GenericEnumVariantInner<K, V>.self, forKey: .content)
+				self = .variant(content)
+				return
 			}
 		}
 		throw DecodingError.typeMismatch(GenericEnum.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for GenericEnum"))
@@ -0,0 +1,17 @@
+#[typeshare]
+#[serde(rename_all = "camelCase")]
+pub struct Document {
+    #[serde(alias = "name")]
+    pub title: String,
+    /// Missing from the JSON when there are no tags
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Missing from the JSON instead of being null
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Serialized as null when there is no summary
+    pub summary: Option<String>,
+    /// Serialized as null when there is no editor, and may be missing
+    #[serde(default)]
+    pub editor: Option<String>,
+}
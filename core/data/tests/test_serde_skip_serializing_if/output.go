@@ -0,0 +1,15 @@
+package proto
+
+import "encoding/json"
+
+type Document struct {
+	Title string `json:"title"`
+	// Missing from the JSON when there are no tags
+	Tags *[]string `json:"tags,omitempty"`
+	// Missing from the JSON instead of being null
+	Author *string `json:"author,omitempty"`
+	// Serialized as null when there is no summary
+	Summary *string `json:"summary,omitempty"`
+	// Serialized as null when there is no editor, and may be missing
+	Editor *string `json:"editor,omitempty"`
+}
@@ -0,0 +1,22 @@
+package com.agilebits.onepassword
+
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.json.JsonNames
+
+@Serializable
+data class Document (
+	@OptIn(ExperimentalSerializationApi::class)
+	@JsonNames("name")
+	val title: String,
+	/// Missing from the JSON when there are no tags
+	val tags: List<String>? = null,
+	/// Missing from the JSON instead of being null
+	val author: String? = null,
+	/// Serialized as null when there is no summary
+	val summary: String? = null,
+	/// Serialized as null when there is no editor, and may be missing
+	val editor: String? = null
+)
+
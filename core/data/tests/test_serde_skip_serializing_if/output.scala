@@ -0,0 +1,17 @@
+package com.agilebits
+
+package onepassword {
+
+case class Document (
+	title: String,
+	// Missing from the JSON when there are no tags
+	tags: Vector[String] = _,
+	// Missing from the JSON instead of being null
+	author: Option[String] = None,
+	// Serialized as null when there is no summary
+	summary: Option[String] = None,
+	// Serialized as null when there is no editor, and may be missing
+	editor: Option[String] = None
+)
+
+}
@@ -0,0 +1,48 @@
+import Foundation
+
+public struct Document: Codable {
+	public let title: String
+	/// Missing from the JSON when there are no tags
+	public let tags: [String]?
+	/// Missing from the JSON instead of being null
+	public let author: String?
+	/// Serialized as null when there is no summary
+	public let summary: String?
+	/// Serialized as null when there is no editor, and may be missing
+	public let editor: String?
+
+	enum CodingKeys: String, CodingKey, Codable {
+		case title,
+			titleAlias1 = "name",
+			tags,
+			author,
+			summary,
+			editor
+	}
+
+	public init(from decoder: Decoder) throws {
+		let container = try decoder.container(keyedBy: CodingKeys.self)
+		self.title = try container.decode(String.self, forKey: [CodingKeys.title, .titleAlias1].first(where: container.contains) ?? .title)
+		self.tags = try container.decodeIfPresent([String].self, forKey: .tags)
+		self.author = try container.decodeIfPresent(String.self, forKey: .author)
+		self.summary = try container.decodeIfPresent(String.self, forKey: .summary)
+		self.editor = try container.decodeIfPresent(String.self, forKey: .editor)
+	}
+
+	public func encode(to encoder: Encoder) throws {
+		var container = encoder.container(keyedBy: CodingKeys.self)
+		try container.encode(self.title, forKey: .title)
+		try container.encodeIfPresent(self.tags, forKey: .tags)
+		try container.encodeIfPresent(self.author, forKey: .author)
+		try container.encodeIfPresent(self.summary, forKey: .summary)
+		try container.encodeIfPresent(self.editor, forKey: .editor)
+	}
+
+	public init(title: String, tags: [String]? = nil, author: String? = nil, summary: String?, editor: String?) {
+		self.title = title
+		self.tags = tags
+		self.author = author
+		self.summary = summary
+		self.editor = editor
+	}
+}
@@ -0,0 +1,13 @@
+export interface Document {
+	/** Deserialized from `title` or `name` */
+	title: string;
+	/** Missing from the JSON when there are no tags */
+	tags?: string[];
+	/** Missing from the JSON instead of being null */
+	author?: string;
+	/** Serialized as null when there is no summary */
+	summary?: string;
+	/** Serialized as null when there is no editor, and may be missing */
+	editor?: string;
+}
+
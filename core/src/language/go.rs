@@ -1,27 +1,125 @@
 use std::io::Write;
 
-use crate::language::SupportedLanguage;
+use crate::language::{CrateName, SupportedLanguage};
 use crate::parser::ParsedData;
 use crate::rename::RenameExt;
-use crate::rust_types::{RustItem, RustTypeFormatError, SpecialRustType};
+use crate::rust_types::{
+    Deprecation, RustConst, RustConstExpr, RustItem, RustType, RustTypeFormatError, SpecialRustType,
+};
 use crate::{
     language::Language,
     rust_types::{RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
     topsort::topsort,
 };
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+use super::{
+    anonymous_struct_generic_types, check_untagged_variants, escape_string_literal, is_int64,
+    keys_outside_flattened_maps, name_tuple_types, struct_keys, used_imports, CrateTypes,
+    Int64Strategy, ScopedCrateTypes,
+};
+
+/// A release of Go, such as 1.18.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GoVersion {
+    /// The major version, 1 for every release so far.
+    pub major: u32,
+    /// The minor version, such as 18 for Go 1.18.
+    pub minor: u32,
+}
+
+impl GoVersion {
+    /// The first release with type parameters, which generic Rust types are
+    /// generated as.
+    pub const GENERICS: Self = Self {
+        major: 1,
+        minor: 18,
+    };
+}
+
+impl Default for GoVersion {
+    fn default() -> Self {
+        Self::GENERICS
+    }
+}
 
-use super::CrateTypes;
+impl Display for GoVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// A Go version that couldn't be parsed.
+#[derive(Debug, Error)]
+#[error("Invalid Go version `{0}`, expected a version like `1.18`")]
+pub struct InvalidGoVersion(String);
+
+impl FromStr for GoVersion {
+    type Err = InvalidGoVersion;
+
+    /// Parse a version like `1.18`. A patch version, like in `1.18.3`, is ignored.
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidGoVersion(version.to_owned());
+        let parts = version
+            .trim_start_matches("go")
+            .split('.')
+            .map(str::parse)
+            .collect::<Result<Vec<u32>, _>>()
+            .map_err(|_| invalid())?;
+        match parts[..] {
+            [major, minor] | [major, minor, _] => Ok(Self { major, minor }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+// Keywords taken from https://go.dev/ref/spec#Keywords
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
 
 /// All information needed to generate Go type-code
 #[derive(Default)]
 pub struct Go {
     /// Name of the Go package.
     pub package: String,
+    /// Import path of the Go module holding the generated packages, when
+    /// generating multiple files. Each crate is a package in this module,
+    /// such as `github.com/example/types/core_types`.
+    pub module_root: String,
     /// Conversions from Rust type names to Go type names.
     pub type_mappings: HashMap<String, String>,
     /// Abbreviations that should be fully uppercased to comply with Go's formatting rules.
     pub uppercase_acronyms: Vec<String>,
+    /// How 64 bit integers are represented.
+    pub int64_strategy: Int64Strategy,
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
@@ -43,27 +141,61 @@ pub struct Go {
     ///
     /// This, however, is rarely applicable in practice, and having this feature does not justify exposing an unintuitive user interface.
     pub no_pointer_slice: bool,
+    /// The oldest Go release the generated code must compile with. Generic
+    /// types are rejected before Go 1.18.
+    pub min_version: GoVersion,
+    /// The package of each type from another crate used by the file being
+    /// generated, so references to it are qualified like `core_types.Id`.
+    /// This is filled in by `generate_types` when generating multiple files.
+    pub imported_types: HashMap<String, String>,
+    /// The keys of the JSON object of each struct, including those of the
+    /// structs flattened into it. This is filled in by `generate_types`, and
+    /// keeps the keys of fields out of flattened maps.
+    pub struct_keys: HashMap<String, Vec<String>>,
 }
 
 impl Language for Go {
     fn generate_types(
         &mut self,
         w: &mut dyn Write,
-        _imports: &CrateTypes,
+        imports: &CrateTypes,
         data: ParsedData,
     ) -> std::io::Result<()> {
+        let mut data = data;
+        if self.names_tuple_types() {
+            name_tuple_types(&mut data);
+        }
+
         self.begin_file(w, &data)?;
+        let decodes_untagged_objects = decodes_untagged_objects(&data);
+
+        self.struct_keys = struct_keys(&data);
+
+        if data.multi_file {
+            let imports = used_imports(&data, imports);
+            self.imported_types = imports
+                .iter()
+                .flat_map(|(crate_name, types)| {
+                    types
+                        .iter()
+                        .map(move |ty| (ty.to_string(), crate_name.to_string()))
+                })
+                .collect();
+            self.write_imports(w, &data.crate_name, imports)?;
+        }
 
         let ParsedData {
             structs,
             enums,
             aliases,
+            consts,
             ..
         } = data;
 
-        let mut items = aliases
+        let mut items = consts
             .into_iter()
-            .map(RustItem::Alias)
+            .map(RustItem::Const)
+            .chain(aliases.into_iter().map(RustItem::Alias))
             .chain(structs.into_iter().map(RustItem::Struct))
             .chain(enums.into_iter().map(RustItem::Enum))
             .collect::<Vec<_>>();
@@ -76,6 +208,12 @@ impl Language for Go {
             .iter()
             .flat_map(|item| match item {
                 RustItem::Struct(s) => Some(s.id.original.as_str()),
+                // Tuples are written as structs
+                RustItem::Alias(a)
+                    if matches!(a.r#type, RustType::Special(SpecialRustType::Tuple(_))) =>
+                {
+                    Some(a.id.original.as_str())
+                }
                 _ => None,
             })
             .collect::<HashSet<_>>();
@@ -91,14 +229,27 @@ impl Language for Go {
             }
         }
 
+        let structs = items
+            .iter()
+            .filter_map(|item| match item {
+                RustItem::Struct(s) => Some((s.id.original.as_str(), s)),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+
         for thing in &items {
             match thing {
-                RustItem::Enum(e) => self.write_enum(w, e, &types_mapping_to_struct)?,
+                RustItem::Enum(e) => self.write_enum(w, e, &types_mapping_to_struct, &structs)?,
                 RustItem::Struct(s) => self.write_struct(w, s)?,
                 RustItem::Alias(a) => self.write_type_alias(w, a)?,
+                RustItem::Const(c) => self.write_const(w, c)?,
             }
         }
 
+        if decodes_untagged_objects {
+            writeln!(w, "{UNTAGGED_OBJECT_DECODER}")?;
+        }
+
         self.end_file(w)
     }
 
@@ -106,13 +257,42 @@ impl Language for Go {
         &self.type_mappings
     }
 
+    fn format_simple_type(
+        &mut self,
+        base: &String,
+        _generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        Ok(if let Some(mapped) = self.type_map().get(base) {
+            mapped.into()
+        } else if let Some(package) = self.imported_types.get(base) {
+            format!("{package}.{}", go_keyword_aware_rename(base))
+        } else {
+            go_keyword_aware_rename(base)
+        })
+    }
+
+    fn format_generic_parameters(&mut self, parameters: Vec<String>) -> String {
+        format!("[{}]", parameters.join(", "))
+    }
+
     fn format_special_type(
         &mut self,
         special_ty: &SpecialRustType,
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         Ok(match special_ty {
-            SpecialRustType::Vec(rtype) => format!("[]{}", self.format_type(rtype, generic_types)?),
+            SpecialRustType::I64
+            | SpecialRustType::U64
+            | SpecialRustType::ISize
+            | SpecialRustType::USize
+                if self.int64_strategy != Int64Strategy::Number =>
+            {
+                self.int64_strategy
+                    .format_non_native(special_ty, "string", "Go")?
+            }
+            SpecialRustType::Vec(rtype) | SpecialRustType::Set(rtype) => {
+                format!("[]{}", self.format_type(rtype, generic_types)?)
+            }
             SpecialRustType::Array(rtype, len) => {
                 format!("[{}]{}", len, self.format_type(rtype, generic_types)?)
             }
@@ -130,11 +310,14 @@ impl Language for Go {
                     self.format_type(rtype, generic_types)?
                 )
             }
-            SpecialRustType::HashMap(rtype1, rtype2) => format!(
+            SpecialRustType::HashMap(rtype1, rtype2)
+            | SpecialRustType::OrderedMap(rtype1, rtype2) => format!(
                 "map[{}]{}",
                 self.format_type(rtype1, generic_types)?,
                 self.format_type(rtype2, generic_types)?
             ),
+            // Tuples are replaced by generated types in `name_tuple_types`
+            SpecialRustType::Tuple(_) => return Err(RustTypeFormatError::UnnamedTuple),
             SpecialRustType::Unit => "struct{}".into(),
             SpecialRustType::String => "string".into(),
             SpecialRustType::Char => "rune".into(),
@@ -154,7 +337,7 @@ impl Language for Go {
         })
     }
 
-    fn begin_file(&mut self, w: &mut dyn Write, _parsed_data: &ParsedData) -> std::io::Result<()> {
+    fn begin_file(&mut self, w: &mut dyn Write, parsed_data: &ParsedData) -> std::io::Result<()> {
         if !self.no_version_header {
             // This comment is specifically formatted to satisfy gosec's template for a generated file,
             // so the generated Go file can be ignored with `gosec -exclude-generated`.
@@ -164,57 +347,319 @@ impl Language for Go {
                 env!("CARGO_PKG_VERSION")
             )?;
         }
-        writeln!(w, "package {}", self.package)?;
+        // Each crate is its own package when generating multiple files
+        if parsed_data.multi_file {
+            writeln!(w, "package {}", parsed_data.crate_name)?;
+        } else {
+            writeln!(w, "package {}", self.package)?;
+        }
         writeln!(w)?;
+        if decodes_untagged_objects(parsed_data) {
+            writeln!(w, "import \"bytes\"")?;
+        }
         writeln!(w, "import \"encoding/json\"")?;
+        if parsed_data
+            .enums
+            .iter()
+            .any(|e| matches!(e, RustEnum::ExternallyTagged(_) | RustEnum::Untagged(_)))
+            || parsed_data
+                .aliases
+                .iter()
+                .any(|a| matches!(a.r#type, RustType::Special(SpecialRustType::Tuple(_))))
+        {
+            writeln!(w, "import \"fmt\"")?;
+        }
         writeln!(w)?;
         Ok(())
     }
 
     fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> std::io::Result<()> {
-        write_comments(w, 0, &ty.comments)?;
+        write_item_comments(w, 0, &ty.comments, &ty.deprecated)?;
+
+        if let RustType::Special(SpecialRustType::Tuple(elements)) = &ty.r#type {
+            return self.write_tuple_struct(w, ty, elements);
+        }
 
         writeln!(
             w,
-            "type {} {}\n",
-            self.acronyms_to_uppercase(&ty.id.original),
-            self.format_type(&ty.r#type, &[])
+            "type {}{} {}\n",
+            go_keyword_aware_rename(&self.acronyms_to_uppercase(&ty.id.original)),
+            self.format_type_parameters(&ty.generic_types, [&ty.r#type])?,
+            self.format_type(&ty.r#type, &ty.generic_types)
                 .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?
         )?;
 
         Ok(())
     }
 
+    fn write_const(&mut self, w: &mut dyn Write, c: &RustConst) -> std::io::Result<()> {
+        write_comments(w, 0, &c.comments)?;
+
+        let value = match &c.expr {
+            RustConstExpr::Int(value)
+                if self.int64_strategy == Int64Strategy::String && is_int64(&c.r#type) =>
+            {
+                format!("\"{value}\"")
+            }
+            RustConstExpr::Int(value) => value.to_string(),
+            RustConstExpr::Float(value) => value.clone(),
+            RustConstExpr::Bool(value) => value.to_string(),
+            RustConstExpr::String(value) => format!("\"{}\"", escape_string_literal(value)),
+        };
+
+        writeln!(
+            w,
+            "const {} {} = {}\n",
+            self.acronyms_to_uppercase(&c.id.original.to_pascal_case()),
+            self.format_type(&c.r#type, &[])
+                .map_err(std::io::Error::other)?,
+            value
+        )
+    }
+
     fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> std::io::Result<()> {
-        write_comments(w, 0, &rs.comments)?;
+        write_item_comments(w, 0, &rs.comments, &rs.deprecated)?;
         writeln!(
             w,
-            "type {} struct {{",
-            self.acronyms_to_uppercase(&rs.id.renamed)
+            "type {}{} struct {{",
+            go_keyword_aware_rename(&self.acronyms_to_uppercase(&rs.id.renamed)),
+            self.format_type_parameters(&rs.generic_types, rs.fields.iter().map(|f| &f.ty))?
         )?;
 
         rs.fields
             .iter()
             .try_for_each(|f| self.write_field(w, f, rs.generic_types.as_slice()))?;
 
-        writeln!(w, "}}")
+        writeln!(w, "}}")?;
+
+        if rs.tag_key.is_some() || rs.fields.iter().any(|f| f.flattened && f.ty.is_map()) {
+            self.write_struct_encoding(w, rs)?;
+        }
+
+        Ok(())
     }
 
     fn write_imports(
         &mut self,
-        _writer: &mut dyn Write,
-        _imports: super::ScopedCrateTypes<'_>,
+        w: &mut dyn Write,
+        _crate_name: &CrateName,
+        imports: ScopedCrateTypes<'_>,
     ) -> std::io::Result<()> {
-        unimplemented!()
+        if imports.is_empty() {
+            return Ok(());
+        }
+        for crate_name in imports.keys() {
+            writeln!(w, "import \"{}/{crate_name}\"", self.module_root)?;
+        }
+        writeln!(w)
+    }
+
+    fn names_tuple_types(&self) -> bool {
+        true
     }
 }
 
 impl Go {
+    /// Write the JSON encoding of a struct that `encoding/json` can't handle
+    /// by itself. The constant value of a `#[serde(tag)]` is added to the
+    /// fields of the struct, and flattened maps hold the keys that don't
+    /// belong to another field.
+    fn write_struct_encoding(&mut self, w: &mut dyn Write, rs: &RustStruct) -> std::io::Result<()> {
+        let struct_name = self.acronyms_to_uppercase(&rs.id.renamed);
+        let struct_type = format!(
+            "{}{}",
+            go_keyword_aware_rename(&struct_name),
+            format_type_arguments(&rs.generic_types)
+        );
+        let short_name = rs.id.renamed[..1].to_lowercase();
+        // Go before 1.20 doesn't allow declaring a type inside a method of a
+        // generic type, so the type without the methods is declared next to it
+        let fields_name = format!(
+            "{}{}Fields",
+            struct_name[..1].to_lowercase(),
+            &struct_name[1..]
+        );
+        let fields_type = format!("{fields_name}{}", format_type_arguments(&rs.generic_types));
+        let tag = rs
+            .tag_key
+            .as_ref()
+            .map(|tag_key| (tag_key, self.format_field_name(tag_key.to_string(), true)));
+
+        if let Some((tag_key, tag_field)) = &tag {
+            writeln!(
+                w,
+                r#"
+// The value of the `{tag_key}` tag of {struct_name}
+const {struct_name}{tag_field} = {:?}"#,
+                rs.id.renamed
+            )?;
+        }
+        writeln!(
+            w,
+            r#"
+// The fields of {struct_name}, without its JSON encoding
+type {fields_name}{} {struct_type}"#,
+            self.format_type_parameters(&rs.generic_types, rs.fields.iter().map(|f| &f.ty))?
+        )?;
+
+        let flattened_maps = rs
+            .fields
+            .iter()
+            .filter(|f| f.flattened && f.ty.is_map())
+            .map(|f| self.format_field_name(f.id.original.to_string(), true))
+            .collect::<Vec<_>>();
+        if flattened_maps.is_empty() {
+            let (tag_key, tag_field) = tag.expect("structs without flattened maps have a tag");
+            return writeln!(
+                w,
+                r#"
+func ({short_name} {struct_type}) MarshalJSON() ([]byte, error) {{
+	return json.Marshal(struct {{
+		{tag_field} string `json:"{tag_key}"`
+		{fields_type}
+	}}{{{struct_name}{tag_field}, {fields_type}({short_name})}})
+}}"#
+            );
+        }
+
+        let mut keys = keys_outside_flattened_maps(&rs.fields, &self.struct_keys);
+        keys.extend(rs.tag_key.iter().cloned());
+
+        writeln!(
+            w,
+            r#"
+func ({short_name} *{struct_type}) UnmarshalJSON(data []byte) error {{
+	if err := json.Unmarshal(data, (*{fields_type})({short_name})); err != nil {{
+		return err
+	}}
+	var rest map[string]json.RawMessage
+	if err := json.Unmarshal(data, &rest); err != nil {{
+		return err
+	}}
+	// The keys of the other fields don't belong in the flattened maps
+	for _, key := range []string{{{keys}}} {{
+		delete(rest, key)
+	}}
+	restData, err := json.Marshal(rest)
+	if err != nil {{
+		return err
+	}}
+{decode_maps}	return nil
+}}
+
+func ({short_name} {struct_type}) MarshalJSON() ([]byte, error) {{
+	object := map[string]json.RawMessage{{}}
+	for _, entries := range []interface{{}}{{{encode_maps}}} {{
+		data, err := json.Marshal(entries)
+		if err != nil {{
+			return nil, err
+		}}
+		if err := json.Unmarshal(data, &object); err != nil {{
+			return nil, err
+		}}
+	}}
+	data, err := json.Marshal({fields_type}({short_name}))
+	if err != nil {{
+		return nil, err
+	}}
+	if err := json.Unmarshal(data, &object); err != nil {{
+		return nil, err
+	}}
+{encode_tag}	return json.Marshal(object)
+}}"#,
+            keys = keys
+                .iter()
+                .map(|key| format!("{key:?}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            decode_maps = flattened_maps
+                .iter()
+                .map(|field| format!(
+                    "\tif err := json.Unmarshal(restData, &{short_name}.{field}); err != nil {{\n\t\treturn err\n\t}}\n"
+                ))
+                .collect::<String>(),
+            encode_maps = flattened_maps
+                .iter()
+                .map(|field| format!("{short_name}.{field}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            encode_tag = match &tag {
+                Some((tag_key, tag_field)) => format!(
+                    "\tobject[{tag_key:?}], _ = json.Marshal({struct_name}{tag_field})\n"
+                ),
+                None => String::new(),
+            },
+        )
+    }
+
+    /// Write a struct for a tuple, like a tuple struct with several fields.
+    /// Tuples are serialized as arrays, so the struct gets its own JSON
+    /// encoding.
+    fn write_tuple_struct(
+        &mut self,
+        w: &mut dyn Write,
+        ty: &RustTypeAlias,
+        elements: &[RustType],
+    ) -> std::io::Result<()> {
+        let struct_name = go_keyword_aware_rename(&self.acronyms_to_uppercase(&ty.id.original));
+        let struct_type = format!("{struct_name}{}", format_type_arguments(&ty.generic_types));
+        let short_name = ty.id.original[..1].to_lowercase();
+
+        writeln!(
+            w,
+            "type {struct_name}{} struct {{",
+            self.format_type_parameters(&ty.generic_types, elements)?
+        )?;
+        for (i, element) in elements.iter().enumerate() {
+            let element_type = self
+                .format_type(element, ty.generic_types.as_slice())
+                .map_err(std::io::Error::other)?;
+            writeln!(
+                w,
+                "\tField{i} {}",
+                self.acronyms_to_uppercase(&element_type)
+            )?;
+        }
+        writeln!(w, "}}")?;
+
+        writeln!(
+            w,
+            r#"
+func ({short_name} *{struct_type}) UnmarshalJSON(data []byte) error {{
+	var elements []json.RawMessage
+	if err := json.Unmarshal(data, &elements); err != nil {{
+		return err
+	}}
+	if len(elements) != {len} {{
+		return fmt.Errorf("expected {len} elements for {struct_name}, got %d", len(elements))
+	}}
+{decode_elements}
+	return nil
+}}
+
+func ({short_name} {struct_type}) MarshalJSON() ([]byte, error) {{
+	return json.Marshal([]interface{{}}{{{encode_elements}}})
+}}
+"#,
+            len = elements.len(),
+            decode_elements = (0..elements.len())
+                .map(|i| format!(
+                    "\tif err := json.Unmarshal(elements[{i}], &{short_name}.Field{i}); err != nil {{\n\t\treturn err\n\t}}\n"
+                ))
+                .collect::<String>(),
+            encode_elements = (0..elements.len())
+                .map(|i| format!("{short_name}.Field{i}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
+
     fn write_enum(
         &mut self,
         w: &mut dyn Write,
         e: &RustEnum,
         custom_structs: &HashSet<&str>,
+        structs: &HashMap<&str, &RustStruct>,
     ) -> std::io::Result<()> {
         // Make a suitable name for an anonymous struct enum variant
         let uppercase_acronyms = self.uppercase_acronyms.clone();
@@ -228,28 +673,29 @@ impl Go {
         // Generate named types for any anonymous struct variants of this enum
         self.write_types_for_anonymous_structs(w, e, &make_anonymous_struct_name)?;
 
-        write_comments(w, 0, &e.shared().comments)?;
+        write_item_comments(w, 0, &e.shared().comments, &e.shared().deprecated)?;
 
         match e {
             RustEnum::Unit(shared) => {
-                writeln!(
-                    w,
-                    "type {} string",
-                    self.acronyms_to_uppercase(&shared.id.original)
-                )?;
+                let enum_name = self.acronyms_to_uppercase(&shared.id.original);
+                let enum_type = go_keyword_aware_rename(&enum_name);
+                writeln!(w, "type {enum_type} string")?;
 
                 write!(w, "const (")?;
 
                 shared.variants.iter().try_for_each(|v| match v {
                     RustEnumVariant::Unit(variant_shared) => {
                         writeln!(w)?;
-                        write_comments(w, 1, &variant_shared.comments)?;
+                        write_item_comments(
+                            w,
+                            1,
+                            &variant_shared.comments,
+                            &variant_shared.deprecated,
+                        )?;
                         write!(
                             w,
-                            "\t{}{} {} = {:?}",
-                            self.acronyms_to_uppercase(&shared.id.original),
+                            "\t{enum_name}{} {enum_type} = {:?}",
                             self.acronyms_to_uppercase(&variant_shared.id.original),
-                            self.acronyms_to_uppercase(&shared.id.original),
                             &variant_shared.id.renamed
                         )
                     }
@@ -258,13 +704,75 @@ impl Go {
 
                 writeln!(w, "\n)")
             }
-            RustEnum::Algebraic {
-                tag_key,
-                content_key,
-                shared,
-                ..
-            } => {
+            RustEnum::Numeric { repr, shared } => {
+                // Discriminants are always serialized as numbers, whatever the 64 bit integer strategy
+                let repr_type = match repr {
+                    SpecialRustType::I64 => "int64".into(),
+                    SpecialRustType::U64 => "uint64".into(),
+                    SpecialRustType::ISize | SpecialRustType::USize => "int".into(),
+                    _ => self
+                        .format_special_type(repr, &[])
+                        .map_err(std::io::Error::other)?,
+                };
+                let enum_name = self.acronyms_to_uppercase(&shared.id.original);
+                let enum_type = go_keyword_aware_rename(&enum_name);
+                writeln!(w, "type {enum_type} {repr_type}")?;
+
+                write!(w, "const (")?;
+
+                shared.variants.iter().try_for_each(|v| {
+                    writeln!(w)?;
+                    write_item_comments(w, 1, &v.shared().comments, &v.shared().deprecated)?;
+                    write!(
+                        w,
+                        "\t{enum_name}{} {enum_type} = {}",
+                        self.acronyms_to_uppercase(&v.shared().id.original),
+                        v.shared().discriminant.unwrap_or_default()
+                    )
+                })?;
+
+                writeln!(w, "\n)")
+            }
+            RustEnum::Algebraic { shared, .. }
+            | RustEnum::ExternallyTagged(shared)
+            | RustEnum::InternallyTagged { shared, .. }
+            | RustEnum::Untagged(shared) => {
+                // Externally tagged, internally tagged and untagged enums are
+                // held in the same shape as adjacently tagged ones, only their
+                // JSON encoding differs.
+                let (tag_key, content_key) = match e {
+                    RustEnum::Algebraic {
+                        tag_key,
+                        content_key,
+                        ..
+                    } => (tag_key.as_str(), content_key.as_str()),
+                    RustEnum::InternallyTagged { tag_key, .. } => (tag_key.as_str(), "content"),
+                    _ => ("type", "content"),
+                };
+                let externally_tagged = matches!(e, RustEnum::ExternallyTagged(_));
+                let internally_tagged = matches!(e, RustEnum::InternallyTagged { .. });
+                let untagged = matches!(e, RustEnum::Untagged(_));
+                if untagged {
+                    check_untagged_variants(e).map_err(std::io::Error::other)?;
+                }
                 let struct_name = self.acronyms_to_uppercase(&shared.id.original);
+                // The content of generic variants is still held as an
+                // `interface{}`, but accessors and constructors are typed.
+                let type_parameters = self.format_type_parameters(
+                    &shared.generic_types,
+                    shared.variants.iter().flat_map(|v| match v {
+                        RustEnumVariant::Tuple { ty, .. } => vec![ty],
+                        RustEnumVariant::AnonymousStruct { fields, .. } => {
+                            fields.iter().map(|f| &f.ty).collect()
+                        }
+                        RustEnumVariant::Unit(_) => Vec::new(),
+                    }),
+                )?;
+                let enum_type = format!(
+                    "{}{}",
+                    go_keyword_aware_rename(&struct_name),
+                    format_type_arguments(&shared.generic_types)
+                );
                 let content_field = content_key.to_string().to_camel_case();
                 let tag_field = self.format_field_name(tag_key.to_string(), true);
                 let struct_short_name = shared.id.original[..1].to_lowercase();
@@ -278,18 +786,22 @@ impl Go {
                 writeln!(w, "const (")?;
 
                 let mut decoding_cases = Vec::new();
+                let mut decoding_attempts = Vec::new();
                 let mut variant_accessors = Vec::new();
                 let mut variant_constructors = Vec::new();
 
                 for v in &shared.variants {
                     let variant_name = self.acronyms_to_uppercase(&v.shared().id.original);
                     let variant_type = match v {
-                        RustEnumVariant::Tuple { ty, .. } => {
-                            Some(self.format_type(ty, &[]).unwrap())
-                        }
-                        RustEnumVariant::AnonymousStruct { .. } => {
-                            Some(make_anonymous_struct_name(&variant_name))
-                        }
+                        RustEnumVariant::Tuple { ty, .. } => Some(
+                            self.format_type(ty, &shared.generic_types)
+                                .map_err(std::io::Error::other)?,
+                        ),
+                        RustEnumVariant::AnonymousStruct { fields, .. } => Some(format!(
+                            "{}{}",
+                            make_anonymous_struct_name(&variant_name),
+                            format_type_arguments(&anonymous_struct_generic_types(e, fields))
+                        )),
                         RustEnumVariant::Unit(_) => None,
                     };
                     let variant_type_const = format!(
@@ -304,16 +816,45 @@ impl Go {
                     ));
 
                     if let Some(variant_type) = variant_type {
-                        let (variant_pointer, variant_deref, variant_ref) =
-                            match (v, custom_structs.contains(&variant_type.as_str())) {
-                                (RustEnumVariant::AnonymousStruct { .. }, ..) | (.., true) => {
-                                    ("*", "", "")
-                                }
-                                _ => ("", "*", "&"),
-                            };
+                        // Generic structs are looked up without their type arguments
+                        let (variant_pointer, variant_deref, variant_ref) = match v {
+                            RustEnumVariant::AnonymousStruct { .. } => ("*", "", ""),
+                            RustEnumVariant::Tuple { ty, .. }
+                                if custom_structs.contains(ty.id()) =>
+                            {
+                                ("*", "", "")
+                            }
+                            _ => ("", "*", "&"),
+                        };
 
                         let formatted_variant_type = self.acronyms_to_uppercase(&variant_type);
 
+                        // Any object decodes into any struct with `json.Unmarshal`,
+                        // so object variants check the keys of the object first.
+                        let decode = match untagged_object_fields(v, structs) {
+                            Some((fields, tag_key)) => {
+                                let required = required_keys(fields, structs)
+                                    .into_iter()
+                                    .chain(tag_key)
+                                    .map(|key| format!(", {key:?}"))
+                                    .collect::<String>();
+                                format!(
+                                    "decodeUntaggedObject(data, res, {}{required})",
+                                    tag_key.is_none()
+                                )
+                            }
+                            None => "json.Unmarshal(data, res) == nil".to_owned(),
+                        };
+                        decoding_attempts.push(format!(
+                            r#"	if res := new({formatted_variant_type}); {decode} {{
+		{short_name}.{tag_field} = {variant_type_const}
+		{short_name}.{content_field} = res
+		return nil
+	}}
+"#,
+                            short_name = struct_short_name,
+                        ));
+
                         decoding_cases.push(format!(
                             "\t\tvar res {formatted_variant_type}
 \t\t{short_name}.{content_field} = &res
@@ -329,22 +870,21 @@ impl Go {
 }}
 "#,
                             short_name = struct_short_name,
-                            full_name = struct_name,
-                            variant_name = variant_name,
+                            full_name = enum_type,
+                            variant_name = go_keyword_aware_rename(&variant_name),
                             variant_pointer = variant_pointer,
                             variant_deref = variant_deref,
                             formatted_variant_type = formatted_variant_type,
                             content_field = content_field,
                         ));
                         variant_constructors.push(format!(
-                            r#"func New{variant_type_const}(content {variant_pointer}{formatted_variant_type}) {struct_name} {{
-    return {struct_name}{{
+                            r#"func New{variant_type_const}{type_parameters}(content {variant_pointer}{formatted_variant_type}) {enum_type} {{
+    return {enum_type}{{
         {tag_field}: {variant_type_const},
         {content_field}: {variant_ref}content,
     }}
 }}
 "#,
-                            struct_name = struct_name,
                             tag_field = tag_field,
                             variant_type_const = variant_type_const,
                             variant_pointer = variant_pointer,
@@ -354,33 +894,45 @@ impl Go {
                         ));
                     } else {
                         decoding_cases.push("\t\treturn nil\n".to_string());
+                        decoding_attempts.push(format!(
+                            r#"	if string(data) == "null" {{
+		{short_name}.{tag_field} = {variant_type_const}
+		{short_name}.{content_field} = nil
+		return nil
+	}}
+"#,
+                            short_name = struct_short_name,
+                        ));
 
                         variant_constructors.push(format!(
-                            r#"func New{variant_type_const}() {struct_name} {{
-    return {struct_name}{{
+                            r#"func New{variant_type_const}{type_parameters}() {enum_type} {{
+    return {enum_type}{{
         {tag_field}: {variant_type_const},
     }}
 }}
 "#,
-                            struct_name = struct_name,
                             tag_field = tag_field,
                             variant_type_const = variant_type_const,
                         ));
                     }
 
-                    write_comments(w, 1, &v.shared().comments)?;
+                    write_item_comments(w, 1, &v.shared().comments, &v.shared().deprecated)?;
                     writeln!(
                         w,
                         "\t{} {} = {:?}",
                         variant_type_const,
                         variant_key_type,
-                        &v.shared().id.original
+                        &v.shared().id.renamed
                     )?;
                 }
 
                 writeln!(w, ")")?;
 
-                writeln!(w, "type {} struct{{ ", struct_name)?;
+                writeln!(
+                    w,
+                    "type {}{type_parameters} struct{{ ",
+                    go_keyword_aware_rename(&struct_name)
+                )?;
                 writeln!(
                     w,
                     "\t{} {} `json:{:?}`",
@@ -391,10 +943,156 @@ impl Go {
                 writeln!(w, "\t{} interface{{}}", content_field)?;
                 writeln!(w, "}}")?;
 
+                if externally_tagged {
+                    return writeln!(
+                        w,
+                        r#"
+func ({short_name} *{enum_type}) UnmarshalJSON(data []byte) error {{
+	var tag {variant_key_type}
+	if err := json.Unmarshal(data, &tag); err == nil {{
+		{short_name}.{tag_field} = tag
+		{short_name}.{content_field} = nil
+		return nil
+	}}
+
+	var enum map[{variant_key_type}]json.RawMessage
+	if err := json.Unmarshal(data, &enum); err != nil {{
+		return err
+	}}
+	if len(enum) != 1 {{
+		return fmt.Errorf("expected a single variant for {full_name}, got %d", len(enum))
+	}}
+
+	for tag, content := range enum {{
+		{short_name}.{tag_field} = tag
+		switch {short_name}.{tag_field} {{
+{decode_cases}
+		}}
+		if err := json.Unmarshal(content, &{short_name}.{content_field}); err != nil {{
+			return err
+		}}
+	}}
+
+	return nil
+}}
+
+func ({short_name} {enum_type}) MarshalJSON() ([]byte, error) {{
+	if {short_name}.{content_field} == nil {{
+		return json.Marshal({short_name}.{tag_field})
+	}}
+	return json.Marshal(map[{variant_key_type}]interface{{}}{{{short_name}.{tag_field}: {short_name}.{content_field}}})
+}}
+
+{variant_accessors}
+{variant_constructors}"#,
+                        short_name = struct_short_name,
+                        full_name = struct_name,
+                        tag_field = tag_field,
+                        content_field = content_field,
+                        decode_cases = decoding_cases
+                            .join("")
+                            .lines()
+                            .map(|line| format!("\t{line}\n"))
+                            .collect::<String>(),
+                        variant_accessors = variant_accessors.join(""),
+                        variant_constructors = variant_constructors.join(""),
+                        variant_key_type = variant_key_type,
+                    );
+                }
+
+                if untagged {
+                    return writeln!(
+                        w,
+                        r#"
+func ({short_name} *{enum_type}) UnmarshalJSON(data []byte) error {{
+{decoding_attempts}
+	return fmt.Errorf("no variant of {full_name} matches %s", data)
+}}
+
+func ({short_name} {enum_type}) MarshalJSON() ([]byte, error) {{
+	return json.Marshal({short_name}.{content_field})
+}}
+
+{variant_accessors}
+{variant_constructors}"#,
+                        short_name = struct_short_name,
+                        full_name = struct_name,
+                        content_field = content_field,
+                        decoding_attempts = decoding_attempts.join(""),
+                        variant_accessors = variant_accessors.join(""),
+                        variant_constructors = variant_constructors.join(""),
+                    );
+                }
+
+                if internally_tagged {
+                    return writeln!(
+                        w,
+                        r#"
+func ({short_name} *{enum_type}) UnmarshalJSON(data []byte) error {{
+	var enum struct {{
+		Tag    {variant_key_type}   `json:"{tag_key}"`
+	}}
+	if err := json.Unmarshal(data, &enum); err != nil {{
+		return err
+	}}
+
+	{short_name}.{tag_field} = enum.Tag
+	switch {short_name}.{tag_field} {{
+{decode_cases}
+	}}
+	// The tag isn't part of the content, which may collect unknown keys
+	var fields map[string]json.RawMessage
+	if err := json.Unmarshal(data, &fields); err != nil {{
+		return err
+	}}
+	delete(fields, "{tag_key}")
+	content, err := json.Marshal(fields)
+	if err != nil {{
+		return err
+	}}
+	if err := json.Unmarshal(content, &{short_name}.{content_field}); err != nil {{
+		return err
+	}}
+
+	return nil
+}}
+
+func ({short_name} {enum_type}) MarshalJSON() ([]byte, error) {{
+	fields := map[string]json.RawMessage{{}}
+	if {short_name}.{content_field} != nil {{
+		content, err := json.Marshal({short_name}.{content_field})
+		if err != nil {{
+			return nil, err
+		}}
+		if err := json.Unmarshal(content, &fields); err != nil {{
+			return nil, err
+		}}
+	}}
+	tag, err := json.Marshal({short_name}.{tag_field})
+	if err != nil {{
+		return nil, err
+	}}
+	fields["{tag_key}"] = tag
+	return json.Marshal(fields)
+}}
+
+{variant_accessors}
+{variant_constructors}"#,
+                        short_name = struct_short_name,
+                        tag_field = tag_field,
+                        content_field = content_field,
+                        decode_cases = decoding_cases.join(""),
+                        variant_accessors = variant_accessors.join(""),
+                        variant_constructors = variant_constructors.join(""),
+                        tag_key = tag_key,
+                        variant_key_type = variant_key_type,
+                    );
+                }
+
                 writeln!(
                     w,
                     r#"
-func ({short_name} *{full_name}) UnmarshalJSON(data []byte) error {{
+func ({short_name} *{enum_type}) UnmarshalJSON(data []byte) error {{
 	var enum struct {{
 		Tag    {variant_key_type}   `json:"{tag_key}"`
 		Content json.RawMessage `json:"{content_key}"`
@@ -414,7 +1112,7 @@ func ({short_name} *{full_name}) UnmarshalJSON(data []byte) error {{
 	return nil
 }}
 
-func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
+func ({short_name} {enum_type}) MarshalJSON() ([]byte, error) {{
     var enum struct {{
 		Tag    {variant_key_type}   `json:"{tag_key}"`
 		Content interface{{}} `json:"{content_key},omitempty"`
@@ -427,7 +1125,6 @@ func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
 {variant_accessors}
 {variant_constructors}"#,
                     short_name = struct_short_name,
-                    full_name = struct_name,
                     tag_field = tag_field,
                     content_field = content_field,
                     decode_cases = decoding_cases.join(""),
@@ -455,7 +1152,7 @@ func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
             }
         }
 
-        write_comments(w, 1, &field.comments)?;
+        write_item_comments(w, 1, &field.comments, &field.deprecated)?;
 
         let type_name = match field.type_override(SupportedLanguage::Go) {
             Some(type_override) => type_override.to_owned(),
@@ -465,14 +1162,46 @@ func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
         };
 
         let go_type = self.acronyms_to_uppercase(&type_name);
-        let is_optional = field.ty.is_optional() || field.has_default;
+
+        // Flattened maps are filled in by the JSON encoding of the struct
+        if field.flattened && field.ty.is_map() {
+            return writeln!(
+                w,
+                "\t{} {go_type} `json:\"-\"`",
+                self.format_field_name(field.id.original.to_string(), true)
+            );
+        }
+
+        // Flattened fields become embedded structs, which `encoding/json`
+        // serializes inline with the fields of the enclosing struct.
+        if field.flattened {
+            let ty = match &field.ty {
+                RustType::Special(SpecialRustType::Option(ty)) => ty.as_ref(),
+                ty => ty,
+            };
+            if !matches!(ty, RustType::Simple { .. } | RustType::Generic { .. }) {
+                return Err(std::io::Error::other(format!(
+                    "Flattened field `{}` must be a struct to be embedded in Go",
+                    field.id.original
+                )));
+            }
+
+            let pointer = if field.may_be_absent() && !field.ty.is_optional() {
+                "*"
+            } else {
+                ""
+            };
+            return writeln!(w, "\t{pointer}{go_type}");
+        }
+
+        let is_optional = field.ty.is_optional() || field.may_be_absent();
         let formatted_renamed_id = format!("{:?}", &field.id.renamed);
         let renamed_id = &formatted_renamed_id[1..formatted_renamed_id.len() - 1];
         writeln!(
             w,
             "\t{} {}{} `json:\"{}{}\"`",
             self.format_field_name(field.id.original.to_string(), true),
-            (field.has_default && !field.ty.is_optional())
+            (field.may_be_absent() && !field.ty.is_optional())
                 .then_some("*")
                 .unwrap_or_default(),
             go_type,
@@ -483,6 +1212,38 @@ func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
         Ok(())
     }
 
+    /// Format the type parameters of a generic type, like `[K comparable, V any]`,
+    /// or nothing if it isn't generic. Parameters used as map keys in `types`
+    /// must be `comparable`.
+    fn format_type_parameters<'a>(
+        &self,
+        generic_types: &[String],
+        types: impl IntoIterator<Item = &'a RustType>,
+    ) -> std::io::Result<String> {
+        let Some(first) = generic_types.first() else {
+            return Ok(String::new());
+        };
+        if self.min_version < GoVersion::GENERICS {
+            return Err(std::io::Error::other(
+                RustTypeFormatError::GenericsForbiddenInGo(first.clone()),
+            ));
+        }
+
+        let types = types.into_iter().collect::<Vec<_>>();
+        let parameters = generic_types
+            .iter()
+            .map(|generic| {
+                let constraint = if types.iter().any(|ty| is_map_key(ty, generic)) {
+                    "comparable"
+                } else {
+                    "any"
+                };
+                format!("{generic} {constraint}")
+            })
+            .collect::<Vec<_>>();
+        Ok(format!("[{}]", parameters.join(", ")))
+    }
+
     // Convert any of the configured acronyms to uppercase to follow Go's formatting standard.
     // If self.uppercase_acronyms contains ID (or id), Id will get replaced by ID.
     fn acronyms_to_uppercase(&self, name: &str) -> String {
@@ -499,6 +1260,120 @@ func ({short_name} {full_name}) MarshalJSON() ([]byte, error) {{
     }
 }
 
+/// Decodes an object variant of an untagged enum, for files with untagged
+/// enums that have object variants.
+const UNTAGGED_OBJECT_DECODER: &str = r#"// decodeUntaggedObject decodes data into v if it is an object with all the required keys. When
+// disallowUnknownFields is set, the object must not have keys that aren't fields of v either.
+// json.Unmarshal alone would decode any object into any struct, so the first object variant of
+// an untagged enum would always match.
+func decodeUntaggedObject(data []byte, v interface{}, disallowUnknownFields bool, required ...string) bool {
+	var fields map[string]json.RawMessage
+	if err := json.Unmarshal(data, &fields); err != nil || fields == nil {
+		return false
+	}
+	for _, key := range required {
+		if _, ok := fields[key]; !ok {
+			return false
+		}
+	}
+	decoder := json.NewDecoder(bytes.NewReader(data))
+	if disallowUnknownFields {
+		decoder.DisallowUnknownFields()
+	}
+	return decoder.Decode(v) == nil
+}"#;
+
+/// Whether any untagged enum in `data` has an object variant, which needs the
+/// decoder in [`UNTAGGED_OBJECT_DECODER`].
+fn decodes_untagged_objects(data: &ParsedData) -> bool {
+    let structs = data
+        .structs
+        .iter()
+        .map(|s| (s.id.original.as_str(), s))
+        .collect::<HashMap<_, _>>();
+    data.enums.iter().any(|e| {
+        matches!(e, RustEnum::Untagged(_))
+            && e.shared()
+                .variants
+                .iter()
+                .any(|v| untagged_object_fields(v, &structs).is_some())
+    })
+}
+
+/// The fields of the object a variant of an untagged enum holds, and the key
+/// of its `#[serde(tag)]` if it has one. This is `None` unless the variant holds
+/// an anonymous struct or a struct defined in the same file.
+fn untagged_object_fields<'a>(
+    v: &'a RustEnumVariant,
+    structs: &HashMap<&str, &'a RustStruct>,
+) -> Option<(&'a [RustField], Option<&'a str>)> {
+    match v {
+        RustEnumVariant::AnonymousStruct { fields, .. } => Some((fields, None)),
+        RustEnumVariant::Tuple { ty, .. } => structs
+            .get(ty.id())
+            .map(|s| (s.fields.as_slice(), s.tag_key.as_deref())),
+        RustEnumVariant::Unit(_) => None,
+    }
+}
+
+/// The keys an object must have to decode into a struct with `fields`, which
+/// are the keys of the fields that are always serialized, including those of
+/// the structs flattened into it.
+fn required_keys<'a>(
+    fields: &'a [RustField],
+    structs: &HashMap<&str, &'a RustStruct>,
+) -> Vec<&'a str> {
+    fields
+        .iter()
+        .filter(|f| !f.ty.is_optional() && !f.may_be_absent())
+        .flat_map(|f| {
+            if f.flattened {
+                structs
+                    .get(f.ty.id())
+                    .map(|s| required_keys(&s.fields, structs))
+                    .unwrap_or_default()
+            } else {
+                vec![f.id.renamed.as_str()]
+            }
+        })
+        .collect()
+}
+
+/// Format the type arguments to use a generic type declared with
+/// `generic_types` inside its own declaration, like `[K, V]`.
+fn format_type_arguments(generic_types: &[String]) -> String {
+    if generic_types.is_empty() {
+        String::new()
+    } else {
+        format!("[{}]", generic_types.join(", "))
+    }
+}
+
+/// Whether the generic parameter `generic` is used as the key of a map in `ty`.
+fn is_map_key(ty: &RustType, generic: &str) -> bool {
+    match ty {
+        RustType::Special(
+            SpecialRustType::HashMap(key, value) | SpecialRustType::OrderedMap(key, value),
+        ) => {
+            matches!(key.as_ref(), RustType::Simple { id } if id == generic)
+                || is_map_key(key, generic)
+                || is_map_key(value, generic)
+        }
+        _ => ty.parameters().any(|ty| is_map_key(ty, generic)),
+    }
+}
+
+/// Add an underscore to names that are Go keywords, so they can be used as
+/// identifiers. Exported names start with an uppercase letter, so only
+/// unexported names can clash with keywords.
+fn go_keyword_aware_rename(name: &str) -> String {
+    if GO_KEYWORDS.contains(&name) {
+        format!("{name}_")
+    } else {
+        name.to_owned()
+    }
+}
+
 fn write_comment(w: &mut dyn Write, indent: usize, comment: &str) -> std::io::Result<()> {
     writeln!(w, "{}// {}", "\t".repeat(indent), comment)?;
     Ok(())
@@ -510,6 +1385,28 @@ fn write_comments(w: &mut dyn Write, indent: usize, comments: &[String]) -> std:
         .try_for_each(|comment| write_comment(w, indent, comment))
 }
 
+/// Write the comments of an item, followed by a `Deprecated:` paragraph if it
+/// is deprecated, which Go tooling recognizes.
+fn write_item_comments(
+    w: &mut dyn Write,
+    indent: usize,
+    comments: &[String],
+    deprecated: &Option<Deprecation>,
+) -> std::io::Result<()> {
+    write_comments(w, indent, comments)?;
+    if let Some(deprecated) = deprecated {
+        let indent = "\t".repeat(indent);
+        if !comments.is_empty() {
+            writeln!(w, "{indent}//")?;
+        }
+        match deprecated.message() {
+            Some(message) => writeln!(w, "{indent}// Deprecated: {message}")?,
+            None => writeln!(w, "{indent}// Deprecated:")?,
+        }
+    }
+    Ok(())
+}
+
 fn convert_acronyms_to_uppercase(uppercase_acronyms: Vec<String>, name: &str) -> String {
     let mut res = name.to_string();
     for a in &uppercase_acronyms {
@@ -532,6 +1429,7 @@ fn convert_acronyms_to_uppercase(uppercase_acronyms: Vec<String>, name: &str) ->
     res
 }
 
+#[cfg(test)]
 mod test {
     #[test]
     fn no_pointer_slice() {
@@ -554,4 +1452,140 @@ mod test {
         let go_slice = super::Language::format_special_type(&mut go, &optional_slice, &[]).unwrap();
         assert_eq!(go_slice, "[]int");
     }
+
+    #[test]
+    fn multi_file_uses_crate_packages_and_imports() {
+        use std::collections::{HashMap, HashSet};
+
+        let source = r#"
+use core_types::Id;
+
+#[typeshare]
+pub struct User {
+    pub id: Id,
+}
+"#;
+        let data = crate::parser::parse(
+            source,
+            "user_types".into(),
+            "user_types.go".into(),
+            "user_types/src/lib.rs".into(),
+            &[],
+            true,
+            &[],
+        )
+        .unwrap()
+        .unwrap();
+        let all_types = HashMap::from([("core_types".into(), HashSet::from(["Id".into()]))]);
+
+        let mut go = super::Go {
+            module_root: "github.com/example/types".into(),
+            no_version_header: true,
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        super::Language::generate_types(&mut go, &mut out, &all_types, data).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "package user_types
+
+import \"encoding/json\"
+
+import \"github.com/example/types/core_types\"
+
+type User struct {
+\tId core_types.Id `json:\"id\"`
+}
+"
+        );
+    }
+
+    fn generate(go: &mut super::Go, source: &str) -> std::io::Result<String> {
+        let data = crate::parser::parse(
+            source,
+            "default_name".into(),
+            "file_name".into(),
+            "file_path".into(),
+            &[],
+            false,
+            &[],
+        )
+        .unwrap()
+        .unwrap();
+        let mut out = Vec::new();
+        super::Language::generate_types(go, &mut out, &Default::default(), data)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn parses_go_versions() {
+        use super::GoVersion;
+
+        assert_eq!(
+            "1.18".parse::<GoVersion>().unwrap(),
+            GoVersion {
+                major: 1,
+                minor: 18
+            }
+        );
+        assert_eq!(
+            "1.21.4".parse::<GoVersion>().unwrap(),
+            GoVersion {
+                major: 1,
+                minor: 21
+            }
+        );
+        assert!("1".parse::<GoVersion>().is_err());
+        assert!("1.x".parse::<GoVersion>().is_err());
+        assert!("1.18.0.1".parse::<GoVersion>().is_err());
+    }
+
+    #[test]
+    fn generics_require_go_1_18() {
+        let source = r#"
+#[typeshare]
+pub struct Page<T> {
+    pub items: Vec<T>,
+}
+"#;
+        let mut go = super::Go {
+            min_version: "1.17".parse().unwrap(),
+            ..Default::default()
+        };
+        let error = generate(&mut go, source).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Generic parameter `T` is forbidden in Go"
+        );
+
+        go.min_version = "1.18".parse().unwrap();
+        assert!(generate(&mut go, source).is_ok());
+    }
+
+    #[test]
+    fn map_keys_are_comparable() {
+        let source = r#"
+#[typeshare]
+pub struct Index<K, V> {
+    pub entries: HashMap<K, Vec<V>>,
+}
+"#;
+        let mut go = super::Go {
+            package: "proto".into(),
+            no_version_header: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            generate(&mut go, source).unwrap(),
+            "package proto
+
+import \"encoding/json\"
+
+type Index[K comparable, V any] struct {
+\tEntries map[K][]V `json:\"entries\"`
+}
+"
+        );
+    }
 }
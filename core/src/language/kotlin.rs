@@ -1,7 +1,13 @@
-use super::{Language, ScopedCrateTypes};
+use super::{
+    check_untagged_variants, escape_string_literal, is_int64, CrateName, Int64Strategy, Language,
+    ScopedCrateTypes,
+};
 use crate::language::SupportedLanguage;
 use crate::parser::{remove_dash_from_identifier, DecoratorKind, ParsedData};
-use crate::rust_types::{RustTypeFormatError, SpecialRustType};
+use crate::rust_types::{
+    Deprecation, RustConst, RustConstExpr, RustEnumShared, RustType, RustTypeFormatError,
+    SpecialRustType,
+};
 use crate::{
     rename::RenameExt,
     rust_types::{Id, RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias},
@@ -24,6 +30,8 @@ pub struct Kotlin {
     pub prefix: String,
     /// Conversions from Rust type names to Kotlin type names.
     pub type_mappings: HashMap<String, String>,
+    /// How 64 bit integers are represented.
+    pub int64_strategy: Int64Strategy,
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
@@ -54,6 +62,15 @@ impl Language for Kotlin {
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         Ok(match special_ty {
+            SpecialRustType::I64
+            | SpecialRustType::U64
+            | SpecialRustType::ISize
+            | SpecialRustType::USize
+                if self.int64_strategy != Int64Strategy::Number =>
+            {
+                self.int64_strategy
+                    .format_non_native(special_ty, "String", "Kotlin")?
+            }
             SpecialRustType::Vec(rtype) => {
                 format!("List<{}>", self.format_type(rtype, generic_types)?)
             }
@@ -66,6 +83,11 @@ impl Language for Kotlin {
             SpecialRustType::Option(rtype) => {
                 format!("{}?", self.format_type(rtype, generic_types)?)
             }
+            // Tuples are replaced by generated types in `name_tuple_types`
+            SpecialRustType::Tuple(_) => return Err(RustTypeFormatError::UnnamedTuple),
+            SpecialRustType::Set(rtype) => {
+                format!("Set<{}>", self.format_type(rtype, generic_types)?)
+            }
             SpecialRustType::HashMap(rtype1, rtype2) => {
                 format!(
                     "HashMap<{}, {}>",
@@ -73,6 +95,14 @@ impl Language for Kotlin {
                     self.format_type(rtype2, generic_types)?
                 )
             }
+            // Kotlin maps keep their insertion order
+            SpecialRustType::OrderedMap(rtype1, rtype2) => {
+                format!(
+                    "Map<{}, {}>",
+                    self.format_type(rtype1, generic_types)?,
+                    self.format_type(rtype2, generic_types)?
+                )
+            }
             SpecialRustType::Unit => "Unit".into(),
             SpecialRustType::String => "String".into(),
             // Char in Kotlin is 16 bits long, so we need to use String
@@ -80,13 +110,13 @@ impl Language for Kotlin {
             // https://kotlinlang.org/docs/basic-types.html#integer-types
             SpecialRustType::I8 => "Byte".into(),
             SpecialRustType::I16 => "Short".into(),
-            SpecialRustType::ISize | SpecialRustType::I32 => "Int".into(),
-            SpecialRustType::I54 | SpecialRustType::I64 => "Long".into(),
+            SpecialRustType::I32 => "Int".into(),
+            SpecialRustType::I54 | SpecialRustType::I64 | SpecialRustType::ISize => "Long".into(),
             // https://kotlinlang.org/docs/basic-types.html#unsigned-integers
             SpecialRustType::U8 => "UByte".into(),
             SpecialRustType::U16 => "UShort".into(),
-            SpecialRustType::USize | SpecialRustType::U32 => "UInt".into(),
-            SpecialRustType::U53 | SpecialRustType::U64 => "ULong".into(),
+            SpecialRustType::U32 => "UInt".into(),
+            SpecialRustType::U53 | SpecialRustType::U64 | SpecialRustType::USize => "ULong".into(),
             SpecialRustType::Bool => "Boolean".into(),
             SpecialRustType::F32 => "Float".into(),
             SpecialRustType::F64 => "Double".into(),
@@ -109,14 +139,112 @@ impl Language for Kotlin {
             writeln!(w)?;
             writeln!(w, "import kotlinx.serialization.Serializable")?;
             writeln!(w, "import kotlinx.serialization.SerialName")?;
+            let has_internally_tagged_enums = parsed_data
+                .enums
+                .iter()
+                .any(|e| matches!(e, RustEnum::InternallyTagged { .. }));
+            let all_fields = || {
+                parsed_data.structs.iter().flat_map(|s| &s.fields).chain(
+                    parsed_data.enums.iter().flat_map(|e| {
+                        e.shared().variants.iter().flat_map(|v| match v {
+                            RustEnumVariant::AnonymousStruct { fields, .. } => fields.as_slice(),
+                            _ => &[],
+                        })
+                    }),
+                )
+            };
+            let has_field_aliases = all_fields().any(|f| f.id.has_deserialize_aliases());
+            let has_struct_tags = parsed_data.structs.iter().any(|s| s.tag_key.is_some());
+            let has_flattened_maps = all_fields().any(|f| f.flattened);
+            if has_internally_tagged_enums || has_field_aliases || has_struct_tags {
+                writeln!(
+                    w,
+                    "import kotlinx.serialization.ExperimentalSerializationApi"
+                )?;
+            }
+            if has_internally_tagged_enums {
+                writeln!(
+                    w,
+                    "import kotlinx.serialization.json.JsonClassDiscriminator"
+                )?;
+            }
+            if has_field_aliases {
+                writeln!(w, "import kotlinx.serialization.json.JsonNames")?;
+            }
+            if has_struct_tags {
+                writeln!(w, "import kotlinx.serialization.EncodeDefault")?;
+            }
+            if parsed_data.enums.iter().any(has_custom_serializer)
+                || has_flattened_maps
+                || parsed_data
+                    .aliases
+                    .iter()
+                    .any(|a| matches!(a.r#type, RustType::Special(SpecialRustType::Tuple(_))))
+            {
+                // Imports used by custom serializers
+                writeln!(w, "import kotlinx.serialization.KSerializer")?;
+                writeln!(w, "import kotlinx.serialization.SerializationException")?;
+                writeln!(w, "import kotlinx.serialization.builtins.*")?;
+                writeln!(w, "import kotlinx.serialization.descriptors.*")?;
+                writeln!(w, "import kotlinx.serialization.encoding.*")?;
+                writeln!(w, "import kotlinx.serialization.json.*")?;
+                writeln!(w, "import kotlinx.serialization.serializer")?;
+            }
             writeln!(w)?;
         }
 
         Ok(())
     }
 
+    fn write_const(&mut self, w: &mut dyn Write, c: &RustConst) -> std::io::Result<()> {
+        self.write_comments(w, 0, &c.comments)?;
+
+        let value = match &c.expr {
+            RustConstExpr::Int(value)
+                if self.int64_strategy == Int64Strategy::String && is_int64(&c.r#type) =>
+            {
+                format!("\"{value}\"")
+            }
+            // Kotlin needs a suffix for unsigned and `Long` literals
+            RustConstExpr::Int(value) => match &c.r#type {
+                RustType::Special(
+                    SpecialRustType::U8 | SpecialRustType::U16 | SpecialRustType::U32,
+                ) => format!("{value}u"),
+                RustType::Special(SpecialRustType::U64 | SpecialRustType::USize) => {
+                    format!("{value}uL")
+                }
+                RustType::Special(SpecialRustType::I64 | SpecialRustType::ISize) => {
+                    format!("{value}L")
+                }
+                _ => value.to_string(),
+            },
+            RustConstExpr::Float(value) => match &c.r#type {
+                RustType::Special(SpecialRustType::F32) => format!("{value}f"),
+                _ => value.clone(),
+            },
+            RustConstExpr::Bool(value) => value.to_string(),
+            RustConstExpr::String(value) => {
+                format!("\"{}\"", escape_kotlin_string_literal(value))
+            }
+        };
+
+        writeln!(
+            w,
+            "const val {}: {} = {}\n",
+            c.id.renamed,
+            self.format_type(&c.r#type, &[])
+                .map_err(std::io::Error::other)?,
+            value
+        )
+    }
+
     fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> std::io::Result<()> {
         self.write_comments(w, 0, &ty.comments)?;
+        self.write_deprecation(w, 0, &ty.deprecated)?;
+
+        if let RustType::Special(SpecialRustType::Tuple(elements)) = &ty.r#type {
+            return self.write_tuple_class(w, ty, elements);
+        }
         let type_name = format!("{}{}", &self.prefix, ty.id.original);
 
         if self.is_inline(&ty.decorators) {
@@ -130,11 +258,16 @@ impl Language for Kotlin {
                     id: Id {
                         original: String::from("value"),
                         renamed: String::from("value"),
+                        deserialize_renamed: None,
+                        aliases: Vec::new(),
                     },
                     ty: ty.r#type.clone(),
                     comments: vec![],
                     has_default: false,
+                    skip_serializing_if: false,
+                    flattened: false,
                     decorators: HashMap::new(),
+                    deprecated: None,
                 },
                 &[],
                 false,
@@ -175,7 +308,21 @@ impl Language for Kotlin {
 
     fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> std::io::Result<()> {
         self.write_comments(w, 0, &rs.comments)?;
-        writeln!(w, "@Serializable")?;
+        self.write_deprecation(w, 0, &rs.deprecated)?;
+        // Flattened maps are all that is left after inlining flattened fields
+        let has_flattened_map = rs.fields.iter().any(|f| f.flattened);
+        if has_flattened_map {
+            writeln!(
+                w,
+                "@Serializable(with = {}{}Serializer::class)",
+                self.prefix, rs.id.renamed
+            )?;
+        } else {
+            writeln!(w, "@Serializable")?;
+        }
+        if rs.tag_key.is_some() {
+            writeln!(w, "@SerialName({:?})", rs.id.renamed)?;
+        }
 
         if rs.fields.is_empty() {
             // If the struct has no fields, we can define it as an static object.
@@ -221,27 +368,75 @@ impl Language for Kotlin {
                 writeln!(w)?;
             }
 
-            if rs.is_redacted {
+            if rs.is_redacted || rs.tag_key.is_some() {
                 writeln!(w, ") {{")?;
-                writeln!(w, "\toverride fun toString(): String = {:?}", rs.id.renamed)?;
+                if let Some(tag_key) = &rs.tag_key {
+                    // The tag has a constant value, which must be encoded even
+                    // though it is the default
+                    writeln!(w, "\t@OptIn(ExperimentalSerializationApi::class)")?;
+                    writeln!(w, "\t@EncodeDefault")?;
+                    writeln!(w, "\t@SerialName({tag_key:?})")?;
+                    writeln!(
+                        w,
+                        "\tval {}: String = {:?}",
+                        remove_dash_from_identifier(tag_key),
+                        rs.id.renamed
+                    )?;
+                }
+                if rs.is_redacted {
+                    writeln!(w, "\toverride fun toString(): String = {:?}", rs.id.renamed)?;
+                }
                 writeln!(w, "}}")?;
             } else {
                 writeln!(w, ")")?;
             }
 
             writeln!(w)?;
+
+            if has_flattened_map {
+                self.write_flattened_map_serializer(
+                    w,
+                    &format!("{}{}", self.prefix, rs.id.renamed),
+                    rs.generic_types.as_slice(),
+                    &rs.fields,
+                    rs.tag_key
+                        .as_deref()
+                        .map(|tag_key| (tag_key, rs.id.renamed.as_str())),
+                )?;
+            }
         }
         Ok(())
     }
 
     fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> std::io::Result<()> {
-        // Generate named types for any anonymous struct variants of this enum
-        self.write_types_for_anonymous_structs(w, e, &|variant_name| {
-            format!("{}{}Inner", &e.shared().id.renamed, variant_name)
-        })?;
+        if let RustEnum::Untagged(_) = e {
+            check_untagged_variants(e).map_err(std::io::Error::other)?;
+        }
+
+        // Generate named types for any anonymous struct variants of this enum.
+        // Internally tagged enums hold the fields in the variant itself.
+        if !matches!(e, RustEnum::InternallyTagged { .. }) {
+            self.write_types_for_anonymous_structs(w, e, &|variant_name| {
+                format!("{}{}Inner", &e.shared().id.renamed, variant_name)
+            })?;
+        }
 
         self.write_comments(w, 0, &e.shared().comments)?;
-        writeln!(w, "@Serializable")?;
+        self.write_deprecation(w, 0, &e.shared().deprecated)?;
+        match e {
+            _ if has_custom_serializer(e) => writeln!(
+                w,
+                "@Serializable(with = {}{}Serializer::class)",
+                self.prefix,
+                e.shared().id.renamed
+            )?,
+            RustEnum::InternallyTagged { tag_key, .. } => {
+                writeln!(w, "@OptIn(ExperimentalSerializationApi::class)")?;
+                writeln!(w, "@Serializable")?;
+                writeln!(w, "@JsonClassDiscriminator({tag_key:?})")?;
+            }
+            _ => writeln!(w, "@Serializable")?,
+        }
 
         let generic_parameters = (!e.shared().generic_types.is_empty())
             .then(|| format!("<{}>", e.shared().generic_types.join(", ")))
@@ -257,7 +452,19 @@ impl Language for Kotlin {
                     generic_parameters
                 )?;
             }
-            RustEnum::Algebraic { .. } => {
+            RustEnum::Numeric { repr, .. } => {
+                write!(
+                    w,
+                    "enum class {}{}(val value: {}) ",
+                    self.prefix,
+                    &e.shared().id.renamed,
+                    numeric_value_type(repr).0
+                )?;
+            }
+            RustEnum::Algebraic { .. }
+            | RustEnum::ExternallyTagged(_)
+            | RustEnum::InternallyTagged { .. }
+            | RustEnum::Untagged(_) => {
                 write!(
                     w,
                     "sealed class {}{}{} ",
@@ -272,12 +479,48 @@ impl Language for Kotlin {
 
         self.write_enum_variants(w, e)?;
 
-        writeln!(w, "}}\n")
+        writeln!(w, "}}\n")?;
+
+        match e {
+            RustEnum::ExternallyTagged(shared) => {
+                self.write_externally_tagged_serializer(w, shared)?
+            }
+            RustEnum::Untagged(shared) => self.write_untagged_serializer(w, shared)?,
+            RustEnum::Numeric { repr, shared } => {
+                self.write_value_serializer(w, shared, numeric_value_type(repr), "value")?
+            }
+            RustEnum::Unit(shared) if shared.other_variant().is_some() => {
+                self.write_value_serializer(w, shared, ("String", "STRING"), "string")?
+            }
+            RustEnum::Algebraic { .. } | RustEnum::InternallyTagged { .. }
+                if has_custom_serializer(e) =>
+            {
+                self.write_tagged_serializer(w, e)?
+            }
+            _ => (),
+        }
+
+        if let RustEnum::InternallyTagged { shared, .. } = e {
+            for v in shared.variants.iter().filter(|v| has_flattened_map(v)) {
+                if let RustEnumVariant::AnonymousStruct { fields, .. } = v {
+                    self.write_flattened_map_serializer(
+                        w,
+                        &format!("{}{}.{}", self.prefix, shared.id.original, variant_name(v)),
+                        shared.generic_types.as_slice(),
+                        fields,
+                        None,
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
     }
 
     fn write_imports(
         &mut self,
         w: &mut dyn Write,
+        _crate_name: &CrateName,
         imports: ScopedCrateTypes<'_>,
     ) -> std::io::Result<()> {
         for (path, ty) in imports {
@@ -288,11 +531,80 @@ impl Language for Kotlin {
         writeln!(w)
     }
 
+    fn inlines_flattened_fields(&self) -> bool {
+        true
+    }
+
+    fn names_tuple_types(&self) -> bool {
+        true
+    }
+
     fn ignored_reference_types(&self) -> Vec<&str> {
         self.type_mappings.keys().map(|s| s.as_str()).collect()
     }
 }
 
+/// Escape `value` to be written in a Kotlin string literal, where `$` would
+/// otherwise start a string template.
+fn escape_kotlin_string_literal(value: &str) -> String {
+    escape_string_literal(value).replace('$', "\\$")
+}
+
+/// The Kotlin type holding the discriminants of a numeric enum with the given
+/// representation, and the matching primitive kind for its serializer.
+fn numeric_value_type(repr: &SpecialRustType) -> (&'static str, &'static str) {
+    match repr {
+        SpecialRustType::I8
+        | SpecialRustType::I16
+        | SpecialRustType::I32
+        | SpecialRustType::U8
+        | SpecialRustType::U16 => ("Int", "INT"),
+        _ => ("Long", "LONG"),
+    }
+}
+
+/// Whether the enum is serialized by a custom serializer rather than the one
+/// generated by kotlinx.serialization.
+fn has_custom_serializer(e: &RustEnum) -> bool {
+    match e {
+        RustEnum::ExternallyTagged(_) | RustEnum::Untagged(_) | RustEnum::Numeric { .. } => true,
+        // The generated serializers can't fall back to a `serde(other)` variant
+        RustEnum::Unit(shared) | RustEnum::Algebraic { shared, .. } => {
+            shared.other_variant().is_some()
+        }
+        // nor use the serializers of variants with flattened maps
+        RustEnum::InternallyTagged { shared, .. } => {
+            shared.other_variant().is_some() || shared.variants.iter().any(has_flattened_map)
+        }
+    }
+}
+
+/// Whether the variant holds a flattened map, and so has a custom serializer.
+fn has_flattened_map(v: &RustEnumVariant) -> bool {
+    match v {
+        RustEnumVariant::AnonymousStruct { fields, .. } => fields.iter().any(|f| f.flattened),
+        _ => false,
+    }
+}
+
+/// The name of the class generated for an algebraic enum variant.
+fn variant_name(v: &RustEnumVariant) -> String {
+    let variant_name = v.shared().id.original.to_pascal_case();
+
+    if variant_name
+        .chars()
+        .next()
+        .map(|c| c.is_ascii_digit())
+        .unwrap_or(false)
+    {
+        // If the name starts with a digit just add an underscore
+        // to the front and make it valid
+        format!("_{}", variant_name)
+    } else {
+        variant_name
+    }
+}
+
 enum Visibility {
     Public,
     Private,
@@ -304,6 +616,7 @@ impl Kotlin {
             RustEnum::Unit(shared) => {
                 for v in &shared.variants {
                     self.write_comments(w, 1, &v.shared().comments)?;
+                    self.write_deprecation(w, 1, &v.shared().deprecated)?;
                     writeln!(w, "\t@SerialName({:?})", &v.shared().id.renamed)?;
                     writeln!(
                         w,
@@ -313,33 +626,45 @@ impl Kotlin {
                     )?;
                 }
             }
-            RustEnum::Algebraic {
-                content_key,
-                shared,
-                ..
-            } => {
+            RustEnum::Numeric { repr, shared } => {
+                let suffix = if numeric_value_type(repr).0 == "Long" {
+                    "L"
+                } else {
+                    ""
+                };
+                for v in &shared.variants {
+                    self.write_comments(w, 1, &v.shared().comments)?;
+                    self.write_deprecation(w, 1, &v.shared().deprecated)?;
+                    writeln!(
+                        w,
+                        "\t{}({}{suffix}),",
+                        &v.shared().id.original,
+                        v.shared().discriminant.unwrap_or_default()
+                    )?;
+                }
+            }
+            RustEnum::Algebraic { shared, .. }
+            | RustEnum::ExternallyTagged(shared)
+            | RustEnum::Untagged(shared) => {
+                let content_key = match e {
+                    RustEnum::Algebraic { content_key, .. } => content_key.as_str(),
+                    _ => "content",
+                };
+
                 for v in &shared.variants {
                     let printed_value = format!(r##""{}""##, &v.shared().id.renamed);
                     self.write_comments(w, 1, &v.shared().comments)?;
+                    self.write_deprecation(w, 1, &v.shared().deprecated)?;
+
+                    if v.shared().is_other && !matches!(e, RustEnum::Untagged(_)) {
+                        self.write_other_variant(w, v, shared)?;
+                        continue;
+                    }
+
                     writeln!(w, "\t@Serializable")?;
                     writeln!(w, "\t@SerialName({})", printed_value)?;
 
-                    let variant_name = {
-                        let mut variant_name = v.shared().id.original.to_pascal_case();
-
-                        if variant_name
-                            .chars()
-                            .next()
-                            .map(|c| c.is_ascii_digit())
-                            .unwrap_or(false)
-                        {
-                            // If the name starts with a digit just add an underscore
-                            // to the front and make it valid
-                            variant_name = format!("_{}", variant_name);
-                        }
-
-                        variant_name
-                    };
+                    let variant_name = variant_name(v);
 
                     match v {
                         RustEnumVariant::Unit(_) => {
@@ -414,11 +739,789 @@ impl Kotlin {
                     )?;
                 }
             }
+            RustEnum::InternallyTagged { shared, .. } => {
+                let generic_parameters = if shared.generic_types.is_empty() {
+                    String::new()
+                } else {
+                    format!("<{}>", shared.generic_types.join(", "))
+                };
+
+                for v in &shared.variants {
+                    self.write_comments(w, 1, &v.shared().comments)?;
+                    self.write_deprecation(w, 1, &v.shared().deprecated)?;
+
+                    if v.shared().is_other {
+                        self.write_other_variant(w, v, shared)?;
+                        continue;
+                    }
+
+                    if has_flattened_map(v) {
+                        writeln!(
+                            w,
+                            "\t@Serializable(with = {}{}{}Serializer::class)",
+                            self.prefix,
+                            shared.id.original,
+                            variant_name(v)
+                        )?;
+                    } else {
+                        writeln!(w, "\t@Serializable")?;
+                    }
+                    writeln!(w, "\t@SerialName({:?})", &v.shared().id.renamed)?;
+
+                    match v {
+                        RustEnumVariant::Unit(_) => {
+                            write!(w, "\tobject {}", variant_name(v))?;
+                        }
+                        // The fields are serialized next to the class discriminator
+                        RustEnumVariant::AnonymousStruct { fields, .. } => {
+                            writeln!(w, "\tdata class {}{generic_parameters}(", variant_name(v))?;
+
+                            let requires_serial_name = fields
+                                .iter()
+                                .any(|f| f.id.renamed.chars().any(|c| c == '-'));
+
+                            // Written to a buffer first so the fields can be
+                            // indented inside the sealed class
+                            let mut elements = Vec::new();
+                            for f in fields {
+                                let mut element = Vec::new();
+                                self.write_element(
+                                    &mut element,
+                                    f,
+                                    shared.generic_types.as_slice(),
+                                    requires_serial_name,
+                                    Visibility::Public,
+                                )?;
+                                elements
+                                    .push(String::from_utf8_lossy(&element).replace('\t', "\t\t"));
+                            }
+
+                            writeln!(w, "{}", elements.join(",\n"))?;
+                            write!(w, "\t)")?;
+                        }
+                        // Tuple variants are turned into anonymous struct
+                        // variants by `inline_flattened_fields`
+                        RustEnumVariant::Tuple { .. } => {
+                            return Err(std::io::Error::other(format!(
+                                "tuple variant {} of internally tagged enum {} was not inlined",
+                                v.shared().id.original,
+                                shared.id.original
+                            )));
+                        }
+                    }
+
+                    writeln!(
+                        w,
+                        ": {}{}{generic_parameters}()",
+                        self.prefix, shared.id.original,
+                    )?;
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// Write the `serde(other)` variant of an enum with tagged variants, which
+    /// holds the JSON of any variant we don't know about.
+    fn write_other_variant(
+        &mut self,
+        w: &mut dyn Write,
+        v: &RustEnumVariant,
+        shared: &RustEnumShared,
+    ) -> std::io::Result<()> {
+        let generic_parameters = if shared.generic_types.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", shared.generic_types.join(", "))
+        };
+
+        writeln!(
+            w,
+            "\tdata class {}{generic_parameters}(val json: JsonElement): {}{}{generic_parameters}()",
+            variant_name(v),
+            self.prefix,
+            shared.id.original,
+        )
+    }
+
+    /// Write the declaration of a custom serializer for an enum, up to its
+    /// descriptor. Returns the name of the enum and its type.
+    fn write_serializer_header(
+        &mut self,
+        w: &mut dyn Write,
+        id: &Id,
+        generic_types: &[String],
+    ) -> std::io::Result<(String, String)> {
+        let type_name = format!("{}{}", self.prefix, id.renamed);
+        self.write_named_serializer_header(w, &type_name, generic_types)
+    }
+
+    /// Write the declaration of a custom serializer for `type_name`, which may
+    /// be a nested class like `Enum.Variant`.
+    fn write_named_serializer_header(
+        &mut self,
+        w: &mut dyn Write,
+        type_name: &str,
+        generic_types: &[String],
+    ) -> std::io::Result<(String, String)> {
+        let generic_parameters = if generic_types.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", generic_types.join(", "))
+        };
+        let serializer_name = format!("{}Serializer", type_name.replace('.', ""));
+        let full_type = format!("{type_name}{generic_parameters}");
+
+        if generic_types.is_empty() {
+            writeln!(w, "object {serializer_name} : KSerializer<{full_type}> {{")?;
+        } else {
+            // The serialization plugin passes a serializer for each type argument
+            writeln!(
+                w,
+                "class {serializer_name}{generic_parameters}({}) : KSerializer<{full_type}> {{",
+                generic_types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, g)| format!("private val typeSerial{i}: KSerializer<{g}>"))
+                    .join(", ")
+            )?;
+        }
+        writeln!(
+            w,
+            "\toverride val descriptor: SerialDescriptor = buildClassSerialDescriptor({type_name:?})"
+        )?;
+        writeln!(w)?;
+
+        Ok((type_name.to_owned(), full_type))
+    }
+
+    /// Write a data class for a tuple, like a tuple struct with several
+    /// fields, along with a serializer that reads and writes a JSON array.
+    fn write_tuple_class(
+        &mut self,
+        w: &mut dyn Write,
+        ty: &RustTypeAlias,
+        elements: &[RustType],
+    ) -> std::io::Result<()> {
+        let generic_types = ty.generic_types.as_slice();
+        let generic_parameters = if generic_types.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", generic_types.join(", "))
+        };
+
+        writeln!(
+            w,
+            "@Serializable(with = {}{}Serializer::class)",
+            self.prefix, ty.id.renamed
+        )?;
+        writeln!(
+            w,
+            "data class {}{}{generic_parameters} (",
+            self.prefix, ty.id.renamed
+        )?;
+        let element_types = elements
+            .iter()
+            .map(|element| self.format_type(element, generic_types))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(std::io::Error::other)?;
+        writeln!(
+            w,
+            "{}",
+            element_types
+                .iter()
+                .enumerate()
+                .map(|(i, element_type)| format!("\tval _{i}: {element_type}"))
+                .join(",\n")
+        )?;
+        writeln!(w, ")")?;
+        writeln!(w)?;
+
+        let element_serializers = elements
+            .iter()
+            .map(|element| self.format_serializer(element, generic_types))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(std::io::Error::other)?;
+        let (type_name, full_type) = self.write_serializer_header(w, &ty.id, generic_types)?;
+
+        writeln!(
+            w,
+            "\toverride fun serialize(encoder: Encoder, value: {full_type}) {{"
+        )?;
+        writeln!(w, "\t\tval output = encoder as JsonEncoder")?;
+        writeln!(w, "\t\toutput.encodeJsonElement(buildJsonArray {{")?;
+        for (i, serializer) in element_serializers.iter().enumerate() {
+            writeln!(
+                w,
+                "\t\t\tadd(output.json.encodeToJsonElement({serializer}, value._{i}))"
+            )?;
+        }
+        writeln!(w, "\t\t}})")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w)?;
+
+        writeln!(
+            w,
+            "\toverride fun deserialize(decoder: Decoder): {full_type} {{"
+        )?;
+        writeln!(w, "\t\tval input = decoder as JsonDecoder")?;
+        writeln!(w, "\t\tval elements = input.decodeJsonElement().jsonArray")?;
+        writeln!(w, "\t\treturn {type_name}(")?;
+        writeln!(
+            w,
+            "{}",
+            element_serializers
+                .iter()
+                .enumerate()
+                .map(|(i, serializer)| format!(
+                    "\t\t\tinput.json.decodeFromJsonElement({serializer}, elements[{i}])"
+                ))
+                .join(",\n")
+        )?;
+        writeln!(w, "\t\t)")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}\n")
+    }
+
+    /// Write a serializer for a class with flattened maps, which collect all
+    /// the keys that don't belong to one of its other `fields`. A struct with
+    /// a `tag` writes its name under the tag key.
+    fn write_flattened_map_serializer(
+        &mut self,
+        w: &mut dyn Write,
+        type_name: &str,
+        generic_types: &[String],
+        fields: &[RustField],
+        tag: Option<(&str, &str)>,
+    ) -> std::io::Result<()> {
+        let serializers = fields
+            .iter()
+            .map(|f| match f.type_override(SupportedLanguage::Kotlin) {
+                Some(type_override) => Ok(format!("serializer<{type_override}>()")),
+                None => self.format_serializer(&f.ty, generic_types),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(std::io::Error::other)?;
+        let (struct_name, struct_type) =
+            self.write_named_serializer_header(w, type_name, generic_types)?;
+
+        writeln!(
+            w,
+            "\toverride fun serialize(encoder: Encoder, value: {struct_type}) {{"
+        )?;
+        writeln!(w, "\t\tval output = encoder as JsonEncoder")?;
+        writeln!(w, "\t\toutput.encodeJsonElement(buildJsonObject {{")?;
+        if let Some((tag_key, tag)) = tag {
+            writeln!(w, "\t\t\tput({tag_key:?}, {tag:?})")?;
+        }
+        for (f, serializer) in fields.iter().zip(&serializers) {
+            let property = remove_dash_from_identifier(&f.id.renamed);
+            let key = &f.id.renamed;
+            if f.flattened {
+                writeln!(
+                    w,
+                    "\t\t\toutput.json.encodeToJsonElement({serializer}, value.{property}).jsonObject.forEach {{ (key, element) -> put(key, element) }}"
+                )?;
+            } else if f.may_be_absent() || f.ty.is_optional() {
+                // Fields that default to `null` are left out
+                writeln!(
+                    w,
+                    "\t\t\tvalue.{property}?.let {{ put({key:?}, output.json.encodeToJsonElement({serializer}, it)) }}"
+                )?;
+            } else {
+                writeln!(
+                    w,
+                    "\t\t\tput({key:?}, output.json.encodeToJsonElement({serializer}, value.{property}))"
+                )?;
+            }
+        }
+        writeln!(w, "\t\t}})")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w)?;
+
+        writeln!(
+            w,
+            "\toverride fun deserialize(decoder: Decoder): {struct_type} {{"
+        )?;
+        writeln!(w, "\t\tval input = decoder as JsonDecoder")?;
+        writeln!(w, "\t\tval element = input.decodeJsonElement().jsonObject")?;
+        // The keys of the struct's own fields are left out of the flattened maps
+        writeln!(
+            w,
+            "\t\tval rest = JsonObject(element - setOf<String>({}))",
+            tag.map(|(tag_key, _)| tag_key)
+                .into_iter()
+                .chain(
+                    fields
+                        .iter()
+                        .filter(|f| !f.flattened)
+                        .flat_map(|f| f.id.deserialize_names())
+                )
+                .map(|key| format!("{key:?}"))
+                .join(", ")
+        )?;
+        writeln!(w, "\t\treturn {struct_name}(")?;
+        for (f, serializer) in fields.iter().zip(&serializers) {
+            let property = remove_dash_from_identifier(&f.id.renamed);
+            let names = f.id.deserialize_names();
+            let element = names
+                .iter()
+                .map(|key| format!("element[{key:?}]"))
+                .join(" ?: ");
+            if f.flattened {
+                writeln!(
+                    w,
+                    "\t\t\t{property} = input.json.decodeFromJsonElement({serializer}, rest),"
+                )?;
+            } else if f.may_be_absent() || f.ty.is_optional() {
+                writeln!(
+                    w,
+                    "\t\t\t{property} = {}?.let {{ input.json.decodeFromJsonElement({serializer}, it) }},",
+                    if names.len() > 1 {
+                        format!("({element})")
+                    } else {
+                        element
+                    }
+                )?;
+            } else {
+                writeln!(
+                    w,
+                    "\t\t\t{property} = input.json.decodeFromJsonElement({serializer}, {element} ?: throw SerializationException(\"Missing field {} of {struct_name}\")),",
+                    f.id.renamed
+                )?;
+            }
+        }
+        writeln!(w, "\t\t)")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}\n")
+    }
+
+    /// Write a serializer for an externally tagged enum. kotlinx.serialization
+    /// can't express this representation, so the JSON is built by hand.
+    fn write_externally_tagged_serializer(
+        &mut self,
+        w: &mut dyn Write,
+        shared: &RustEnumShared,
+    ) -> std::io::Result<()> {
+        let (enum_name, enum_type) =
+            self.write_serializer_header(w, &shared.id, &shared.generic_types)?;
+
+        writeln!(
+            w,
+            "\toverride fun serialize(encoder: Encoder, value: {enum_type}) {{"
+        )?;
+        writeln!(w, "\t\tval output = encoder as JsonEncoder")?;
+        writeln!(w, "\t\toutput.encodeJsonElement(when (value) {{")?;
+        for v in &shared.variants {
+            let variant_name = variant_name(v);
+            let serial_name = &v.shared().id.renamed;
+            match v {
+                _ if v.shared().is_other => {
+                    writeln!(w, "\t\t\tis {enum_name}.{variant_name} -> value.json")?
+                }
+                RustEnumVariant::Unit(_) => writeln!(
+                    w,
+                    "\t\t\tis {enum_name}.{variant_name} -> JsonPrimitive({serial_name:?})"
+                )?,
+                _ => writeln!(
+                    w,
+                    "\t\t\tis {enum_name}.{variant_name} -> buildJsonObject {{ put({serial_name:?}, output.json.encodeToJsonElement({}, value.content)) }}",
+                    self.format_variant_serializer(v, shared)?
+                )?,
+            }
+        }
+        writeln!(w, "\t\t}})")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w)?;
+
+        writeln!(
+            w,
+            "\toverride fun deserialize(decoder: Decoder): {enum_type} {{"
+        )?;
+        writeln!(w, "\t\tval input = decoder as JsonDecoder")?;
+        writeln!(w, "\t\tval element = input.decodeJsonElement()")?;
+        if shared
+            .variants
+            .iter()
+            .any(|v| matches!(v, RustEnumVariant::Unit(_)) && !v.shared().is_other)
+        {
+            writeln!(
+                w,
+                "\t\tif (element is JsonPrimitive && element.isString) {{"
+            )?;
+            writeln!(w, "\t\t\twhen (element.content) {{")?;
+            for v in &shared.variants {
+                if let RustEnumVariant::Unit(variant_shared) = v {
+                    if variant_shared.is_other {
+                        continue;
+                    }
+                    writeln!(
+                        w,
+                        "\t\t\t\t{:?} -> return {enum_name}.{}",
+                        variant_shared.id.renamed,
+                        variant_name(v)
+                    )?;
+                }
+            }
+            writeln!(w, "\t\t\t}}")?;
+            writeln!(w, "\t\t}}")?;
+        }
+        writeln!(w, "\t\tif (element is JsonObject && element.size == 1) {{")?;
+        writeln!(w, "\t\t\tval (key, content) = element.entries.first()")?;
+        writeln!(w, "\t\t\twhen (key) {{")?;
+        for v in &shared.variants {
+            if !matches!(v, RustEnumVariant::Unit(_)) {
+                writeln!(
+                    w,
+                    "\t\t\t\t{:?} -> return {enum_name}.{}(input.json.decodeFromJsonElement({}, content))",
+                    v.shared().id.renamed,
+                    variant_name(v),
+                    self.format_variant_serializer(v, shared)?
+                )?;
+            }
+        }
+        writeln!(w, "\t\t\t}}")?;
+        writeln!(w, "\t\t}}")?;
+        match shared.other_variant() {
+            Some(other) => writeln!(w, "\t\treturn {enum_name}.{}(element)", variant_name(other))?,
+            None => writeln!(
+                w,
+                "\t\tthrow SerializationException(\"Unknown variant for {enum_name}: $element\")"
+            )?,
+        }
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}\n")
+    }
+
+    /// Write a serializer for an untagged enum, which only serializes the
+    /// content of each variant. Deserializing tries each variant in order.
+    fn write_untagged_serializer(
+        &mut self,
+        w: &mut dyn Write,
+        shared: &RustEnumShared,
+    ) -> std::io::Result<()> {
+        let (enum_name, enum_type) =
+            self.write_serializer_header(w, &shared.id, &shared.generic_types)?;
+
+        writeln!(
+            w,
+            "\toverride fun serialize(encoder: Encoder, value: {enum_type}) {{"
+        )?;
+        writeln!(w, "\t\tval output = encoder as JsonEncoder")?;
+        writeln!(w, "\t\toutput.encodeJsonElement(when (value) {{")?;
+        for v in &shared.variants {
+            let variant_name = variant_name(v);
+            match v {
+                RustEnumVariant::Unit(_) => {
+                    writeln!(w, "\t\t\tis {enum_name}.{variant_name} -> JsonNull")?
+                }
+                _ => writeln!(
+                    w,
+                    "\t\t\tis {enum_name}.{variant_name} -> output.json.encodeToJsonElement({}, value.content)",
+                    self.format_variant_serializer(v, shared)?
+                )?,
+            }
+        }
+        writeln!(w, "\t\t}})")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w)?;
+
+        writeln!(
+            w,
+            "\toverride fun deserialize(decoder: Decoder): {enum_type} {{"
+        )?;
+        writeln!(w, "\t\tval input = decoder as JsonDecoder")?;
+        writeln!(w, "\t\tval element = input.decodeJsonElement()")?;
+        for v in &shared.variants {
+            let variant_name = variant_name(v);
+            match v {
+                RustEnumVariant::Unit(_) => writeln!(
+                    w,
+                    "\t\tif (element is JsonNull) return {enum_name}.{variant_name}"
+                )?,
+                _ => writeln!(
+                    w,
+                    "\t\trunCatching {{ return {enum_name}.{variant_name}(input.json.decodeFromJsonElement({}, element)) }}",
+                    self.format_variant_serializer(v, shared)?
+                )?,
+            }
+        }
+        writeln!(
+            w,
+            "\t\tthrow SerializationException(\"No variant of {enum_name} matches $element\")"
+        )?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}\n")
+    }
+
+    /// Write a serializer encoding the variants of an enum class as the value of
+    /// `property`, like the discriminant of a numeric enum. Unknown values decode
+    /// into the `serde(other)` variant if there is one.
+    fn write_value_serializer(
+        &mut self,
+        w: &mut dyn Write,
+        shared: &RustEnumShared,
+        (value_type, primitive_kind): (&str, &str),
+        property: &str,
+    ) -> std::io::Result<()> {
+        let enum_name = format!("{}{}", self.prefix, shared.id.renamed);
+
+        writeln!(
+            w,
+            "object {enum_name}Serializer : KSerializer<{enum_name}> {{"
+        )?;
+        writeln!(
+            w,
+            "\toverride val descriptor: SerialDescriptor = PrimitiveSerialDescriptor({enum_name:?}, PrimitiveKind.{primitive_kind})"
+        )?;
+        writeln!(w)?;
+
+        writeln!(
+            w,
+            "\toverride fun serialize(encoder: Encoder, value: {enum_name}) {{"
+        )?;
+        writeln!(w, "\t\tencoder.encode{value_type}(value.{property})")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w)?;
+
+        writeln!(
+            w,
+            "\toverride fun deserialize(decoder: Decoder): {enum_name} {{"
+        )?;
+        writeln!(w, "\t\tval value = decoder.decode{value_type}()")?;
+        writeln!(
+            w,
+            "\t\treturn {enum_name}.values().firstOrNull {{ it.{property} == value }}"
+        )?;
+        match shared.other_variant() {
+            Some(other) => writeln!(w, "\t\t\t?: {enum_name}.{}", other.shared().id.original)?,
+            None => writeln!(
+                w,
+                "\t\t\t?: throw SerializationException(\"No variant of {enum_name} has the value $value\")"
+            )?,
+        }
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}\n")
+    }
+
+    /// Write a serializer for an adjacently or internally tagged enum with a
+    /// `serde(other)` variant or variants holding flattened maps, which the
+    /// generated serializer can't handle.
+    fn write_tagged_serializer(&mut self, w: &mut dyn Write, e: &RustEnum) -> std::io::Result<()> {
+        let shared = e.shared();
+        let (tag_key, content_key) = match e {
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                ..
+            } => (tag_key, Some(content_key)),
+            RustEnum::InternallyTagged { tag_key, .. } => (tag_key, None),
+            _ => unreachable!("only tagged enums have a tag key"),
+        };
+        let (enum_name, enum_type) =
+            self.write_serializer_header(w, &shared.id, &shared.generic_types)?;
+
+        writeln!(
+            w,
+            "\toverride fun serialize(encoder: Encoder, value: {enum_type}) {{"
+        )?;
+        writeln!(w, "\t\tval output = encoder as JsonEncoder")?;
+        writeln!(w, "\t\toutput.encodeJsonElement(when (value) {{")?;
+        for v in &shared.variants {
+            let variant_name = variant_name(v);
+            let serial_name = &v.shared().id.renamed;
+            match (v, content_key) {
+                _ if v.shared().is_other => {
+                    writeln!(w, "\t\t\tis {enum_name}.{variant_name} -> value.json")?
+                }
+                (RustEnumVariant::Unit(_), _) => writeln!(
+                    w,
+                    "\t\t\tis {enum_name}.{variant_name} -> buildJsonObject {{ put({tag_key:?}, {serial_name:?}) }}"
+                )?,
+                (_, Some(content_key)) => writeln!(
+                    w,
+                    "\t\t\tis {enum_name}.{variant_name} -> buildJsonObject {{ put({tag_key:?}, {serial_name:?}); put({content_key:?}, output.json.encodeToJsonElement({}, value.{content_key})) }}",
+                    self.format_variant_serializer(v, shared)?
+                )?,
+                // The fields are serialized next to the tag
+                (_, None) => writeln!(
+                    w,
+                    "\t\t\tis {enum_name}.{variant_name} -> JsonObject(mapOf({tag_key:?} to JsonPrimitive({serial_name:?})) + output.json.encodeToJsonElement({}, value).jsonObject)",
+                    self.format_internal_variant_serializer(v, shared)
+                )?,
+            }
+        }
+        writeln!(w, "\t\t}})")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w)?;
+
+        writeln!(
+            w,
+            "\toverride fun deserialize(decoder: Decoder): {enum_type} {{"
+        )?;
+        writeln!(w, "\t\tval input = decoder as JsonDecoder")?;
+        writeln!(w, "\t\tval element = input.decodeJsonElement()")?;
+        writeln!(
+            w,
+            "\t\twhen ((element as? JsonObject)?.get({tag_key:?})?.jsonPrimitive?.contentOrNull) {{"
+        )?;
+        for v in &shared.variants {
+            let variant_name = variant_name(v);
+            let serial_name = &v.shared().id.renamed;
+            match (v, content_key) {
+                _ if v.shared().is_other => continue,
+                (RustEnumVariant::Unit(_), _) => writeln!(
+                    w,
+                    "\t\t\t{serial_name:?} -> return {enum_name}.{variant_name}"
+                )?,
+                (_, Some(content_key)) => writeln!(
+                    w,
+                    "\t\t\t{serial_name:?} -> return {enum_name}.{variant_name}(input.json.decodeFromJsonElement({}, element.jsonObject.getValue({content_key:?})))",
+                    self.format_variant_serializer(v, shared)?
+                )?,
+                (_, None) => writeln!(
+                    w,
+                    "\t\t\t{serial_name:?} -> return input.json.decodeFromJsonElement({}, JsonObject(element.jsonObject - {tag_key:?}))",
+                    self.format_internal_variant_serializer(v, shared)
+                )?,
+            }
+        }
+        writeln!(w, "\t\t}}")?;
+        match shared.other_variant() {
+            Some(other) => writeln!(w, "\t\treturn {enum_name}.{}(element)", variant_name(other))?,
+            None => writeln!(
+                w,
+                "\t\tthrow SerializationException(\"Unknown variant for {enum_name}: $element\")"
+            )?,
+        }
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}\n")
+    }
+
+    /// Format the serializer for a variant of an internally tagged enum, which
+    /// holds its fields itself.
+    fn format_internal_variant_serializer(
+        &self,
+        v: &RustEnumVariant,
+        shared: &RustEnumShared,
+    ) -> String {
+        let generics = (0..shared.generic_types.len())
+            .map(|i| format!("typeSerial{i}"))
+            .join(", ");
+
+        format!(
+            "{}{}.{}.serializer({generics})",
+            self.prefix,
+            shared.id.original,
+            variant_name(v)
+        )
+    }
+
+    /// Format the serializer for the content of an enum variant.
+    fn format_variant_serializer(
+        &mut self,
+        v: &RustEnumVariant,
+        shared: &RustEnumShared,
+    ) -> std::io::Result<String> {
+        match v {
+            RustEnumVariant::Tuple { ty, .. } => self
+                .format_serializer(ty, shared.generic_types.as_slice())
+                .map_err(std::io::Error::other),
+            RustEnumVariant::AnonymousStruct {
+                fields,
+                shared: variant_shared,
+            } => {
+                let generics = fields
+                    .iter()
+                    .flat_map(|field| {
+                        shared
+                            .generic_types
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, g)| field.ty.contains_type(g))
+                    })
+                    .unique()
+                    .map(|(i, _)| format!("typeSerial{i}"))
+                    .join(", ");
+                let inner_type = format!(
+                    "{}{}{}Inner",
+                    self.prefix, shared.id.original, variant_shared.id.original
+                );
+
+                Ok(if generics.is_empty() {
+                    format!("{inner_type}.serializer()")
+                } else {
+                    format!("{inner_type}.serializer({generics})")
+                })
+            }
+            RustEnumVariant::Unit(_) => unreachable!("unit variants have no content"),
+        }
+    }
+
+    /// Format an expression for the serializer of `ty`. Generic parameters
+    /// use the serializers passed to the enclosing serializer class.
+    fn format_serializer(
+        &mut self,
+        ty: &RustType,
+        generic_types: &[String],
+    ) -> Result<String, RustTypeFormatError> {
+        if !generic_types.iter().any(|g| ty.contains_type(g)) {
+            return Ok(format!(
+                "serializer<{}>()",
+                self.format_type(ty, generic_types)?
+            ));
+        }
+
+        Ok(match ty {
+            RustType::Simple { id } => format!(
+                "typeSerial{}",
+                generic_types
+                    .iter()
+                    .position(|g| g == id)
+                    .unwrap_or_default()
+            ),
+            RustType::Generic { id, parameters } => format!(
+                "{}.serializer({})",
+                self.format_simple_type(id, generic_types)?,
+                parameters
+                    .iter()
+                    .map(|p| self.format_serializer(p, generic_types))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            RustType::Special(
+                SpecialRustType::Vec(ty)
+                | SpecialRustType::Array(ty, _)
+                | SpecialRustType::Slice(ty),
+            ) => format!(
+                "ListSerializer({})",
+                self.format_serializer(ty, generic_types)?
+            ),
+            RustType::Special(SpecialRustType::Set(ty)) => {
+                format!(
+                    "SetSerializer({})",
+                    self.format_serializer(ty, generic_types)?
+                )
+            }
+            RustType::Special(
+                SpecialRustType::HashMap(key, value) | SpecialRustType::OrderedMap(key, value),
+            ) => format!(
+                "MapSerializer({}, {})",
+                self.format_serializer(key, generic_types)?,
+                self.format_serializer(value, generic_types)?
+            ),
+            RustType::Special(SpecialRustType::Option(ty)) => {
+                format!("{}.nullable", self.format_serializer(ty, generic_types)?)
+            }
+            RustType::Special(_) => {
+                return Err(RustTypeFormatError::UnsupportedGenericSerializer(
+                    ty.id().to_owned(),
+                ))
+            }
+        })
+    }
+
     fn write_element(
         &mut self,
         w: &mut dyn Write,
@@ -428,9 +1531,22 @@ impl Kotlin {
         visibility: Visibility,
     ) -> std::io::Result<()> {
         self.write_comments(w, 1, &f.comments)?;
+        self.write_deprecation(w, 1, &f.deprecated)?;
         if requires_serial_name {
             writeln!(w, "\t@SerialName({:?})", &f.id.renamed)?;
         }
+        if f.id.has_deserialize_aliases() {
+            writeln!(w, "\t@OptIn(ExperimentalSerializationApi::class)")?;
+            writeln!(
+                w,
+                "\t@JsonNames({})",
+                f.id.deserialize_names()
+                    .into_iter()
+                    .filter(|name| *name != f.id.renamed)
+                    .map(|name| format!("{name:?}"))
+                    .join(", ")
+            )?;
+        }
         let ty = match f.type_override(SupportedLanguage::Kotlin) {
             Some(type_override) => type_override.to_owned(),
             None => self
@@ -444,7 +1560,7 @@ impl Kotlin {
                 "\tval {}: {}{}",
                 remove_dash_from_identifier(&f.id.renamed),
                 ty,
-                (f.has_default && !f.ty.is_optional())
+                (f.may_be_absent() && !f.ty.is_optional())
                     .then_some("? = null")
                     .or_else(|| f.ty.is_optional().then_some(" = null"))
                     .unwrap_or_default()
@@ -454,7 +1570,7 @@ impl Kotlin {
                 "\tprivate val {}: {}{}",
                 remove_dash_from_identifier(&f.id.renamed),
                 ty,
-                (f.has_default && !f.ty.is_optional())
+                (f.may_be_absent() && !f.ty.is_optional())
                     .then_some("? = null")
                     .or_else(|| f.ty.is_optional().then_some(" = null"))
                     .unwrap_or_default()
@@ -462,6 +1578,26 @@ impl Kotlin {
         }
     }
 
+    /// Write a `@Deprecated` annotation on a deprecated item, so the Kotlin
+    /// compiler warns about its uses.
+    fn write_deprecation(
+        &self,
+        w: &mut dyn Write,
+        indent: usize,
+        deprecated: &Option<Deprecation>,
+    ) -> std::io::Result<()> {
+        if let Some(deprecated) = deprecated {
+            // Kotlin requires a message, even an empty one
+            writeln!(
+                w,
+                "{}@Deprecated(\"{}\")",
+                "\t".repeat(indent),
+                escape_kotlin_string_literal(&deprecated.message().unwrap_or_default())
+            )?;
+        }
+        Ok(())
+    }
+
     fn write_comment(
         &self,
         w: &mut dyn Write,
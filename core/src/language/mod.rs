@@ -1,8 +1,9 @@
 use crate::{
     parser::{ParseError, ParsedData},
+    rename::RenameExt,
     rust_types::{
-        Id, RustEnum, RustEnumVariant, RustItem, RustStruct, RustType, RustTypeAlias,
-        RustTypeFormatError, SpecialRustType,
+        Id, RustConst, RustEnum, RustEnumVariant, RustField, RustItem, RustStruct, RustType,
+        RustTypeAlias, RustTypeFormatError, SpecialRustType,
     },
     topsort::topsort,
     visitors::ImportedType,
@@ -25,7 +26,7 @@ mod scala;
 mod swift;
 mod typescript;
 
-pub use go::Go;
+pub use go::{Go, GoVersion, InvalidGoVersion};
 pub use kotlin::Kotlin;
 pub use scala::Scala;
 pub use swift::GenericConstraints;
@@ -144,6 +145,85 @@ impl TryFrom<&Ident> for SupportedLanguage {
     }
 }
 
+/// How 64 bit integers (`i64`, `u64`, `isize` and `usize`) are represented in
+/// the generated code.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum Int64Strategy {
+    /// Refuse to generate code for types using 64 bit integers.
+    Reject,
+    /// Use the language's number type. This is the native 64 bit integer type in
+    /// every language except Typescript, where `number` loses precision above 2^53.
+    #[default]
+    Number,
+    /// Use Typescript's `bigint`. The JSON must be parsed with a library that
+    /// produces bigints, since serde still writes these integers as numbers.
+    BigInt,
+    /// Use strings. The Rust side must serialize the integer as a string, for
+    /// example with `#[serde(with = "serde_with::rust::display_fromstr")]`.
+    String,
+}
+
+impl Int64Strategy {
+    /// The name of this strategy in the configuration.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Reject => "reject",
+            Self::Number => "number",
+            Self::BigInt => "bigint",
+            Self::String => "string",
+        }
+    }
+
+    /// Format a 64 bit integer type for the `reject`, `bigint` and `string` strategies in
+    /// a language without a standard big integer type. The `number` strategy is left to the
+    /// language, since it maps to its native 64 bit integer types.
+    pub(crate) fn format_non_native(
+        &self,
+        special_ty: &SpecialRustType,
+        string_type: &str,
+        language: &str,
+    ) -> Result<String, RustTypeFormatError> {
+        match self {
+            Self::Reject => Err(RustTypeFormatError::Int64Rejected(special_ty.id().into())),
+            Self::String => Ok(string_type.into()),
+            Self::Number | Self::BigInt => Err(RustTypeFormatError::UnsupportedInt64Strategy(
+                self.name().into(),
+                language.into(),
+            )),
+        }
+    }
+}
+
+/// Whether `ty` is one of the 64 bit integer types that are represented according
+/// to an [`Int64Strategy`].
+pub(crate) fn is_int64(ty: &RustType) -> bool {
+    matches!(
+        ty,
+        RustType::Special(
+            SpecialRustType::I64
+                | SpecialRustType::U64
+                | SpecialRustType::ISize
+                | SpecialRustType::USize
+        )
+    )
+}
+
+/// Escape `value` to be written in a double quoted string literal. Languages with
+/// string interpolation escape their own special characters on top of these.
+pub(crate) fn escape_string_literal(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '\\' => "\\\\".to_string(),
+            '"' => "\\\"".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
 /// Language-specific state and processing.
 ///
 /// The `Language` implementation is allowed to maintain mutable state, and it
@@ -158,23 +238,33 @@ pub trait Language {
         all_types: &CrateTypes,
         data: ParsedData,
     ) -> std::io::Result<()> {
+        let mut data = data;
+        if self.inlines_flattened_fields() {
+            inline_flattened_fields(&mut data).map_err(std::io::Error::other)?;
+        }
+        if self.names_tuple_types() {
+            name_tuple_types(&mut data);
+        }
+
         self.begin_file(writable, &data)?;
 
         if data.multi_file {
-            self.write_imports(writable, used_imports(&data, all_types))?;
+            self.write_imports(writable, &data.crate_name, used_imports(&data, all_types))?;
         }
 
         let ParsedData {
             structs,
             enums,
             aliases,
+            consts,
             ..
         } = data;
 
         let mut items = Vec::from_iter(
-            aliases
+            consts
                 .into_iter()
-                .map(RustItem::Alias)
+                .map(RustItem::Const)
+                .chain(aliases.into_iter().map(RustItem::Alias))
                 .chain(structs.into_iter().map(RustItem::Struct))
                 .chain(enums.into_iter().map(RustItem::Enum)),
         );
@@ -186,6 +276,7 @@ pub trait Language {
                 RustItem::Enum(e) => self.write_enum(writable, e)?,
                 RustItem::Struct(s) => self.write_struct(writable, s)?,
                 RustItem::Alias(a) => self.write_type_alias(writable, a)?,
+                RustItem::Const(c) => self.write_const(writable, c)?,
             }
         }
 
@@ -273,10 +364,11 @@ pub trait Language {
         Ok(())
     }
 
-    /// For generating import statements.
+    /// For generating import statements, in the file generated for `crate_name`.
     fn write_imports(
         &mut self,
         _writer: &mut dyn Write,
+        _crate_name: &CrateName,
         _imports: ScopedCrateTypes<'_>,
     ) -> std::io::Result<()>;
 
@@ -294,6 +386,15 @@ pub trait Language {
         Ok(())
     }
 
+    /// Write a constant by converting it.
+    /// Example of a constant:
+    /// ```
+    /// const MAX_PAYLOAD_SIZE: u32 = 1024;
+    /// ```
+    fn write_const(&mut self, _w: &mut dyn Write, _c: &RustConst) -> std::io::Result<()> {
+        Ok(())
+    }
+
     /// Write a struct by converting it
     /// Example of a struct:
     /// ```ignore
@@ -357,21 +458,7 @@ pub trait Language {
             _ => None,
         }) {
             let struct_name = make_struct_name(&shared.id.original);
-
-            // Builds the list of generic types (e.g [T, U, V]), by digging
-            // through the fields recursively and comparing against the
-            // enclosing enum's list of generic parameters.
-            let generic_types = fields
-                .iter()
-                .flat_map(|field| {
-                    e.shared()
-                        .generic_types
-                        .iter()
-                        .filter(|g| field.ty.contains_type(g))
-                })
-                .unique()
-                .cloned()
-                .collect();
+            let generic_types = anonymous_struct_generic_types(e, fields);
 
             self.write_struct(
                 w,
@@ -379,6 +466,8 @@ pub trait Language {
                     id: Id {
                         original: struct_name.clone(),
                         renamed: struct_name.clone(),
+                        deserialize_renamed: None,
+                        aliases: Vec::new(),
                     },
                     fields: fields.clone(),
                     generic_types,
@@ -389,6 +478,8 @@ pub trait Language {
                     )],
                     decorators: e.shared().decorators.clone(),
                     is_redacted: e.shared().is_redacted,
+                    tag_key: None,
+                    deprecated: None,
                 },
             )?;
         }
@@ -396,6 +487,21 @@ pub trait Language {
         Ok(())
     }
 
+    /// Whether `#[serde(flatten)]` fields should be replaced with the fields of
+    /// the flattened struct before any code is generated. Languages that have no
+    /// way to express flattening in their serialization library should return `true`.
+    fn inlines_flattened_fields(&self) -> bool {
+        false
+    }
+
+    /// Whether tuple types should be replaced with generated named types before
+    /// any code is generated. Type aliases of a tuple, like multi-field tuple
+    /// structs, are left in place and written by `write_type_alias`. Languages
+    /// with no tuple type that serializes as an array should return `true`.
+    fn names_tuple_types(&self) -> bool {
+        false
+    }
+
     /// Types that are remapped will be excluded from import references.
     fn ignored_reference_types(&self) -> Vec<&str> {
         Vec::new()
@@ -408,6 +514,555 @@ pub trait Language {
     }
 }
 
+/// Check that every variant of an untagged enum serializes to a different
+/// shape, so generated decoders that try each variant in turn can tell them
+/// apart. Unit variants are all serialized as `null`.
+pub(crate) fn check_untagged_variants(e: &RustEnum) -> Result<(), RustTypeFormatError> {
+    #[derive(PartialEq)]
+    enum Content<'a> {
+        Null,
+        Type(&'a RustType),
+        Fields(Vec<(&'a str, &'a RustType)>),
+    }
+
+    let contents = e
+        .shared()
+        .variants
+        .iter()
+        .map(|v| match v {
+            RustEnumVariant::Unit(_)
+            | RustEnumVariant::Tuple {
+                ty: RustType::Special(SpecialRustType::Unit),
+                ..
+            } => (Content::Null, v),
+            RustEnumVariant::Tuple { ty, .. } => (Content::Type(ty), v),
+            RustEnumVariant::AnonymousStruct { fields, .. } => (
+                Content::Fields(
+                    fields
+                        .iter()
+                        .map(|f| (f.id.renamed.as_str(), &f.ty))
+                        .sorted_by_key(|(name, _)| *name)
+                        .collect(),
+                ),
+                v,
+            ),
+        })
+        .collect::<Vec<_>>();
+
+    for (i, (content, variant)) in contents.iter().enumerate() {
+        if let Some((_, other)) = contents[i + 1..].iter().find(|(c, _)| c == content) {
+            return Err(RustTypeFormatError::IndistinguishableUntaggedVariants(
+                e.shared().id.original.clone(),
+                variant.shared().id.original.clone(),
+                other.shared().id.original.clone(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Replace every tuple type nested in `data` with a reference to a generated
+/// type alias of that tuple, named after where the tuple is used. Type aliases
+/// whose type is a tuple are the generated types themselves and are kept.
+pub(crate) fn name_tuple_types(data: &mut ParsedData) {
+    /// Context for naming the tuples found in one place.
+    struct TupleNames<'a> {
+        /// The Rust type the tuples are used in
+        owner: &'a str,
+        /// The name the generated types start with
+        base_name: String,
+        /// How many tuples have been named so far
+        count: usize,
+        /// Generic parameters of the owner
+        generic_types: &'a [String],
+    }
+
+    fn name_tuples(ty: &mut RustType, names: &mut TupleNames, aliases: &mut Vec<RustTypeAlias>) {
+        match ty {
+            RustType::Special(SpecialRustType::Tuple(elements)) => {
+                // Several tuples in the same place are numbered
+                names.count += 1;
+                let base_name = match names.count {
+                    1 => names.base_name.clone(),
+                    count => format!("{}{count}", names.base_name),
+                };
+                name_tuple_elements(
+                    elements,
+                    names.owner,
+                    &base_name,
+                    names.generic_types,
+                    aliases,
+                );
+
+                let tuple_name = format!("{base_name}Tuple");
+                let generic_types = names
+                    .generic_types
+                    .iter()
+                    .filter(|g| elements.iter().any(|e| e.contains_type(g)))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                aliases.push(RustTypeAlias {
+                    id: Id {
+                        original: tuple_name.clone(),
+                        renamed: tuple_name.clone(),
+                        deserialize_renamed: None,
+                        aliases: Vec::new(),
+                    },
+                    r#type: ty.clone(),
+                    comments: vec![format!(
+                        "Generated type representing a tuple in the `{}` Rust type",
+                        names.owner
+                    )],
+                    generic_types: generic_types.clone(),
+                    decorators: HashMap::new(),
+                    is_redacted: false,
+                    deprecated: None,
+                });
+
+                *ty = if generic_types.is_empty() {
+                    RustType::Simple { id: tuple_name }
+                } else {
+                    RustType::Generic {
+                        id: tuple_name,
+                        parameters: generic_types
+                            .into_iter()
+                            .map(|id| RustType::Simple { id })
+                            .collect(),
+                    }
+                };
+            }
+            RustType::Generic { parameters, .. } => {
+                for parameter in parameters {
+                    name_tuples(parameter, names, aliases);
+                }
+            }
+            RustType::Special(
+                SpecialRustType::Vec(ty)
+                | SpecialRustType::Array(ty, _)
+                | SpecialRustType::Slice(ty)
+                | SpecialRustType::Option(ty)
+                | SpecialRustType::Set(ty),
+            ) => name_tuples(ty, names, aliases),
+            RustType::Special(
+                SpecialRustType::HashMap(key, value) | SpecialRustType::OrderedMap(key, value),
+            ) => {
+                name_tuples(key, names, aliases);
+                name_tuples(value, names, aliases);
+            }
+            RustType::Special(_) | RustType::Simple { .. } => {}
+        }
+    }
+
+    fn name_tuple_elements(
+        elements: &mut [RustType],
+        owner: &str,
+        base_name: &str,
+        generic_types: &[String],
+        aliases: &mut Vec<RustTypeAlias>,
+    ) {
+        for (i, element) in elements.iter_mut().enumerate() {
+            let mut names = TupleNames {
+                owner,
+                base_name: format!("{base_name}{i}"),
+                count: 0,
+                generic_types,
+            };
+            name_tuples(element, &mut names, aliases);
+        }
+    }
+
+    let mut aliases = Vec::new();
+
+    data.structs = std::mem::take(&mut data.structs)
+        .into_iter()
+        .map(|mut s| {
+            for f in s.fields.iter_mut() {
+                let mut names = TupleNames {
+                    owner: &s.id.original,
+                    base_name: format!("{}{}", s.id.original, f.id.original.to_pascal_case()),
+                    count: 0,
+                    generic_types: &s.generic_types,
+                };
+                name_tuples(&mut f.ty, &mut names, &mut aliases);
+            }
+            s
+        })
+        .collect();
+
+    data.enums = std::mem::take(&mut data.enums)
+        .into_iter()
+        .map(|mut e| {
+            let shared = e.shared_mut();
+            for v in shared.variants.iter_mut() {
+                let variant_name = format!("{}{}", shared.id.original, v.shared().id.original);
+                match v {
+                    RustEnumVariant::Unit(_) => {}
+                    RustEnumVariant::Tuple { ty, .. } => {
+                        let mut names = TupleNames {
+                            owner: &shared.id.original,
+                            base_name: variant_name,
+                            count: 0,
+                            generic_types: &shared.generic_types,
+                        };
+                        name_tuples(ty, &mut names, &mut aliases);
+                    }
+                    RustEnumVariant::AnonymousStruct { fields, .. } => {
+                        for f in fields.iter_mut() {
+                            let mut names = TupleNames {
+                                owner: &shared.id.original,
+                                base_name: format!(
+                                    "{variant_name}{}",
+                                    f.id.original.to_pascal_case()
+                                ),
+                                count: 0,
+                                generic_types: &shared.generic_types,
+                            };
+                            name_tuples(&mut f.ty, &mut names, &mut aliases);
+                        }
+                    }
+                }
+            }
+            e
+        })
+        .collect();
+
+    data.aliases = std::mem::take(&mut data.aliases)
+        .into_iter()
+        .map(|mut a| {
+            match &mut a.r#type {
+                RustType::Special(SpecialRustType::Tuple(elements)) => name_tuple_elements(
+                    elements,
+                    &a.id.original,
+                    &a.id.original,
+                    &a.generic_types,
+                    &mut aliases,
+                ),
+                ty => {
+                    let mut names = TupleNames {
+                        owner: &a.id.original,
+                        base_name: a.id.original.clone(),
+                        count: 0,
+                        generic_types: &a.generic_types,
+                    };
+                    name_tuples(ty, &mut names, &mut aliases);
+                }
+            }
+            a
+        })
+        .collect();
+
+    data.aliases.extend(aliases);
+}
+
+/// A struct that flattened fields may refer to.
+struct FlattenableStruct {
+    /// The crate defining the struct, if it isn't the one being generated
+    crate_name: Option<CrateName>,
+    generic_types: Vec<String>,
+    fields: Vec<RustField>,
+}
+
+/// Replace every `#[serde(flatten)]` field in the structs and anonymous struct
+/// variants of `data` with the fields of the struct it references, which may
+/// be generic or defined in another crate. Fields that come from an optional
+/// flattened struct become optional themselves. Flattened maps are kept, as
+/// they collect the keys that don't belong to any other field.
+///
+/// The tuple variants of internally tagged enums are serialized the same way
+/// as a flattened struct, so they become anonymous struct variants.
+pub(crate) fn inline_flattened_fields(data: &mut ParsedData) -> Result<(), RustTypeFormatError> {
+    fn inline_fields(
+        fields: &[RustField],
+        structs: &HashMap<String, FlattenableStruct>,
+        seen: &mut Vec<String>,
+        import_types: &mut HashSet<ImportedType>,
+    ) -> Result<Vec<RustField>, RustTypeFormatError> {
+        let mut inlined = Vec::with_capacity(fields.len());
+
+        for field in fields {
+            if !field.flattened {
+                inlined.push(field.clone());
+                continue;
+            }
+
+            let (ty, optional) = match &field.ty {
+                RustType::Special(SpecialRustType::Option(ty)) => (ty.as_ref(), true),
+                ty => (ty, false),
+            };
+
+            let (flattened, arguments) = match ty {
+                RustType::Special(
+                    SpecialRustType::HashMap(..) | SpecialRustType::OrderedMap(..),
+                ) => {
+                    inlined.push(field.clone());
+                    continue;
+                }
+                RustType::Simple { id } | RustType::Generic { id, .. } if !seen.contains(id) => (
+                    structs
+                        .get(id)
+                        .ok_or_else(|| RustTypeFormatError::UnresolvedFlattenedType(id.clone()))?,
+                    ty.parameters().cloned().collect::<Vec<_>>(),
+                ),
+                _ => {
+                    return Err(RustTypeFormatError::UnresolvedFlattenedType(
+                        ty.id().to_owned(),
+                    ))
+                }
+            };
+
+            let flattened_fields = flattened
+                .fields
+                .iter()
+                .map(|f| RustField {
+                    ty: substitute_generics(&f.ty, &flattened.generic_types, &arguments),
+                    ..f.clone()
+                })
+                .collect::<Vec<_>>();
+
+            seen.push(ty.id().to_owned());
+            for f in inline_fields(&flattened_fields, structs, seen, import_types)? {
+                // The types of fields from another crate are imported from there
+                if let Some(crate_name) = &flattened.crate_name {
+                    import_types.extend(f.ty.all_reference_type_names().map(|type_name| {
+                        ImportedType {
+                            base_crate: crate_name.clone(),
+                            type_name: type_name.to_owned(),
+                        }
+                    }));
+                }
+                inlined.push(RustField {
+                    ty: if optional && !f.ty.is_optional() && !f.flattened {
+                        RustType::Special(SpecialRustType::Option(Box::new(f.ty)))
+                    } else {
+                        f.ty
+                    },
+                    has_default: f.has_default || field.has_default,
+                    skip_serializing_if: f.skip_serializing_if || field.skip_serializing_if,
+                    ..f
+                });
+            }
+            seen.pop();
+        }
+
+        Ok(inlined)
+    }
+
+    let structs = data
+        .external_structs
+        .iter()
+        .flat_map(|(crate_name, structs)| structs.iter().map(move |s| (Some(crate_name), s)))
+        // The structs of this crate come last, so they win over ones with the same name
+        .chain(data.structs.iter().map(|s| (None, s)))
+        .map(|(crate_name, s)| {
+            (
+                s.id.original.clone(),
+                FlattenableStruct {
+                    crate_name: crate_name.cloned(),
+                    generic_types: s.generic_types.clone(),
+                    fields: s.fields.clone(),
+                },
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let mut import_types = HashSet::new();
+
+    data.structs = std::mem::take(&mut data.structs)
+        .into_iter()
+        .map(|mut s| {
+            s.fields = inline_fields(
+                &s.fields,
+                &structs,
+                &mut vec![s.id.original.clone()],
+                &mut import_types,
+            )?;
+            Ok(s)
+        })
+        .collect::<Result<_, _>>()?;
+
+    data.enums = std::mem::take(&mut data.enums)
+        .into_iter()
+        .map(|mut e| {
+            let internally_tagged = matches!(e, RustEnum::InternallyTagged { .. });
+
+            for variant in e.shared_mut().variants.iter_mut() {
+                if let (true, RustEnumVariant::Tuple { ty, shared }) = (internally_tagged, &variant)
+                {
+                    *variant = RustEnumVariant::AnonymousStruct {
+                        fields: vec![RustField {
+                            id: Id {
+                                original: "content".into(),
+                                renamed: "content".into(),
+                                deserialize_renamed: None,
+                                aliases: Vec::new(),
+                            },
+                            ty: ty.clone(),
+                            comments: Vec::new(),
+                            has_default: false,
+                            skip_serializing_if: false,
+                            flattened: true,
+                            decorators: HashMap::new(),
+                            deprecated: None,
+                        }],
+                        shared: shared.clone(),
+                    };
+                }
+
+                if let RustEnumVariant::AnonymousStruct { fields, .. } = variant {
+                    *fields = inline_fields(fields, &structs, &mut Vec::new(), &mut import_types)?;
+                }
+            }
+            Ok(e)
+        })
+        .collect::<Result<_, _>>()?;
+
+    data.import_types.extend(
+        import_types
+            .into_iter()
+            .filter(|imp| !data.type_names.contains(&imp.type_name)),
+    );
+
+    Ok(())
+}
+
+/// The keys of the JSON object of each struct in `data` and the other crates,
+/// including its tag and the keys of the structs flattened into it.
+pub(crate) fn struct_keys(data: &ParsedData) -> HashMap<String, Vec<String>> {
+    fn object_keys<'a>(
+        s: &'a RustStruct,
+        structs: &HashMap<&str, &'a RustStruct>,
+        seen: &mut Vec<&'a str>,
+    ) -> Vec<String> {
+        let mut keys = Vec::new();
+        for f in &s.fields {
+            if !f.flattened {
+                keys.extend(f.id.deserialize_names().into_iter().map(str::to_owned));
+                continue;
+            }
+            let flattened = flattened_type(f).id();
+            if let Some(flattened) = structs
+                .get(flattened)
+                .filter(|_| !seen.contains(&flattened))
+            {
+                seen.push(&flattened.id.original);
+                keys.extend(object_keys(flattened, structs, seen));
+                seen.pop();
+            }
+        }
+        keys.extend(s.tag_key.iter().cloned());
+        keys
+    }
+
+    let structs = data
+        .structs
+        .iter()
+        .chain(data.external_structs.values().flatten())
+        .map(|s| (s.id.original.as_str(), s))
+        .collect::<HashMap<_, _>>();
+
+    structs
+        .iter()
+        .map(|(id, s)| (id.to_string(), object_keys(s, &structs, &mut vec![*id])))
+        .collect()
+}
+
+/// The keys of the JSON object holding `fields` that don't go into its
+/// flattened maps, given the keys of every struct from [`struct_keys`].
+pub(crate) fn keys_outside_flattened_maps(
+    fields: &[RustField],
+    struct_keys: &HashMap<String, Vec<String>>,
+) -> Vec<String> {
+    fields
+        .iter()
+        .flat_map(|f| {
+            if !f.flattened {
+                f.id.deserialize_names()
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect()
+            } else {
+                struct_keys
+                    .get(flattened_type(f).id())
+                    .cloned()
+                    .unwrap_or_default()
+            }
+        })
+        .collect()
+}
+
+/// The type of a flattened field, without the `Option` it may be wrapped in.
+fn flattened_type(f: &RustField) -> &RustType {
+    match &f.ty {
+        RustType::Special(SpecialRustType::Option(ty)) => ty,
+        ty => ty,
+    }
+}
+
+/// Replace the generic parameters `generic_types` in `ty` with the matching
+/// type of `arguments`.
+fn substitute_generics(
+    ty: &RustType,
+    generic_types: &[String],
+    arguments: &[RustType],
+) -> RustType {
+    let substitute = |ty: &RustType| Box::new(substitute_generics(ty, generic_types, arguments));
+
+    match ty {
+        RustType::Simple { id } => generic_types
+            .iter()
+            .position(|g| g == id)
+            .and_then(|i| arguments.get(i))
+            .unwrap_or(ty)
+            .clone(),
+        RustType::Generic { id, parameters } => RustType::Generic {
+            id: id.clone(),
+            parameters: parameters
+                .iter()
+                .map(|p| substitute_generics(p, generic_types, arguments))
+                .collect(),
+        },
+        RustType::Special(special) => RustType::Special(match special {
+            SpecialRustType::Vec(ty) => SpecialRustType::Vec(substitute(ty)),
+            SpecialRustType::Array(ty, len) => SpecialRustType::Array(substitute(ty), *len),
+            SpecialRustType::Slice(ty) => SpecialRustType::Slice(substitute(ty)),
+            SpecialRustType::Option(ty) => SpecialRustType::Option(substitute(ty)),
+            SpecialRustType::Set(ty) => SpecialRustType::Set(substitute(ty)),
+            SpecialRustType::HashMap(key, value) => {
+                SpecialRustType::HashMap(substitute(key), substitute(value))
+            }
+            SpecialRustType::OrderedMap(key, value) => {
+                SpecialRustType::OrderedMap(substitute(key), substitute(value))
+            }
+            SpecialRustType::Tuple(types) => SpecialRustType::Tuple(
+                types
+                    .iter()
+                    .map(|ty| substitute_generics(ty, generic_types, arguments))
+                    .collect(),
+            ),
+            special => special.clone(),
+        }),
+    }
+}
+
+/// Builds the list of generic types (e.g [T, U, V]) of the struct generated
+/// for an anonymous struct variant of `e`, by digging through the fields
+/// recursively and comparing against the enclosing enum's list of generic
+/// parameters.
+fn anonymous_struct_generic_types(e: &RustEnum, fields: &[RustField]) -> Vec<String> {
+    fields
+        .iter()
+        .flat_map(|field| {
+            e.shared()
+                .generic_types
+                .iter()
+                .filter(|g| field.ty.contains_type(g))
+        })
+        .unique()
+        .cloned()
+        .collect()
+}
+
 /// Lookup any refeferences to other typeshared types in order to build
 /// a list of imports for the generated module.
 fn used_imports<'a, 'b: 'a>(
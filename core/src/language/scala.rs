@@ -1,7 +1,13 @@
-use super::{CrateTypes, Language};
+use super::{
+    check_untagged_variants, escape_string_literal, inline_flattened_fields, is_int64,
+    used_imports, CrateName, CrateTypes, Int64Strategy, Language, ScopedCrateTypes,
+};
 use crate::language::SupportedLanguage;
 use crate::parser::{remove_dash_from_identifier, ParsedData};
-use crate::rust_types::{RustEnum, RustEnumVariant, RustField, RustStruct, RustTypeAlias};
+use crate::rust_types::{
+    Deprecation, RustConst, RustConstExpr, RustEnum, RustEnumVariant, RustField, RustStruct,
+    RustTypeAlias,
+};
 use crate::rust_types::{RustType, RustTypeFormatError, SpecialRustType};
 use itertools::Itertools;
 use joinery::JoinableIterator;
@@ -18,6 +24,8 @@ pub struct Scala {
     pub module_name: String,
     /// Conversions from Rust type names to Scala type names.
     pub type_mappings: HashMap<String, String>,
+    /// How 64 bit integers are represented.
+    pub int64_strategy: Int64Strategy,
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
@@ -27,18 +35,32 @@ impl Language for Scala {
     fn generate_types(
         &mut self,
         writable: &mut dyn Write,
-        _imports: &CrateTypes,
+        imports: &CrateTypes,
         data: ParsedData,
     ) -> std::io::Result<()> {
+        let mut data = data;
+        if self.inlines_flattened_fields() {
+            inline_flattened_fields(&mut data).map_err(std::io::Error::other)?;
+        }
+
         self.begin_file(writable, &data)?;
 
-        // Package object to hold type aliases: aliases must be in class or object in Scala 2)
+        if data.multi_file {
+            self.write_imports(writable, &data.crate_name, used_imports(&data, imports))?;
+        }
+
+        let package = self.file_package(&data);
+
+        // Package object to hold type aliases and constants: they must be in class or object in Scala 2)
         let unsigned_used = self.unsigned_integer_used(&data);
-        if unsigned_used || !data.aliases.is_empty() {
-            self.begin_package_object(writable)?;
+        if unsigned_used || !data.aliases.is_empty() || !data.consts.is_empty() {
+            self.begin_package_object(writable, &package)?;
             if unsigned_used {
                 self.write_unsigned_aliases(writable)?;
             }
+            for c in data.consts.iter() {
+                self.write_const(writable, c)?;
+            }
             for a in data.aliases.iter() {
                 self.write_type_alias(writable, a)?;
             }
@@ -46,7 +68,7 @@ impl Language for Scala {
         }
 
         if !data.structs.is_empty() || !data.enums.is_empty() {
-            self.begin_package(writable)?;
+            self.begin_package(writable, &package)?;
             for s in data.structs.iter() {
                 self.write_struct(writable, s)?;
             }
@@ -75,6 +97,15 @@ impl Language for Scala {
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         Ok(match special_ty {
+            SpecialRustType::I64
+            | SpecialRustType::U64
+            | SpecialRustType::ISize
+            | SpecialRustType::USize
+                if self.int64_strategy != Int64Strategy::Number =>
+            {
+                self.int64_strategy
+                    .format_non_native(special_ty, "String", "Scala")?
+            }
             SpecialRustType::Vec(rtype) => {
                 format!("Vector[{}]", self.format_type(rtype, generic_types)?)
             }
@@ -87,7 +118,19 @@ impl Language for Scala {
             SpecialRustType::Option(rtype) => {
                 format!("Option[{}]", self.format_type(rtype, generic_types)?)
             }
-            SpecialRustType::HashMap(rtype1, rtype2) => {
+            SpecialRustType::Tuple(rtypes) => format!(
+                "({})",
+                rtypes
+                    .iter()
+                    .map(|rtype| self.format_type(rtype, generic_types))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            SpecialRustType::Set(rtype) => {
+                format!("Set[{}]", self.format_type(rtype, generic_types)?)
+            }
+            SpecialRustType::HashMap(rtype1, rtype2)
+            | SpecialRustType::OrderedMap(rtype1, rtype2) => {
                 format!(
                     "Map[{}, {}]",
                     self.format_type(rtype1, generic_types)?,
@@ -101,20 +144,20 @@ impl Language for Scala {
             // https://docs.scala-lang.org/scala3/book/first-look-at-types.html#scalas-value-types
             SpecialRustType::I8 => "Byte".into(),
             SpecialRustType::I16 => "Short".into(),
-            SpecialRustType::ISize | SpecialRustType::I32 => "Int".into(),
-            SpecialRustType::I54 | SpecialRustType::I64 => "Long".into(),
+            SpecialRustType::I32 => "Int".into(),
+            SpecialRustType::I54 | SpecialRustType::I64 | SpecialRustType::ISize => "Long".into(),
             // Scala does not support unsigned integers, so upcast it to the closest one
             SpecialRustType::U8 => "UByte".into(),
             SpecialRustType::U16 => "UShort".into(),
-            SpecialRustType::USize | SpecialRustType::U32 => "UInt".into(),
-            SpecialRustType::U53 | SpecialRustType::U64 => "ULong".into(),
+            SpecialRustType::U32 => "UInt".into(),
+            SpecialRustType::U53 | SpecialRustType::U64 | SpecialRustType::USize => "ULong".into(),
             SpecialRustType::Bool => "Boolean".into(),
             SpecialRustType::F32 => "Float".into(),
             SpecialRustType::F64 => "Double".into(),
         })
     }
 
-    fn begin_file(&mut self, w: &mut dyn Write, _parsed_data: &ParsedData) -> std::io::Result<()> {
+    fn begin_file(&mut self, w: &mut dyn Write, parsed_data: &ParsedData) -> std::io::Result<()> {
         if !self.no_version_header {
             writeln!(w, "/**")?;
             writeln!(w, " * Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
@@ -123,7 +166,7 @@ impl Language for Scala {
         if self.package.is_empty() {
             panic!("package name must be provided")
         }
-        match self.package.rsplit_once('.') {
+        match self.file_package(parsed_data).rsplit_once('.') {
             None => {}
             Some((parent, _last)) => {
                 writeln!(w, "package {}", parent)?;
@@ -135,6 +178,7 @@ impl Language for Scala {
 
     fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> std::io::Result<()> {
         self.write_comments(w, 0, &ty.comments)?;
+        self.write_deprecation(w, 0, &ty.deprecated)?;
 
         writeln!(
             w,
@@ -150,8 +194,39 @@ impl Language for Scala {
         Ok(())
     }
 
+    fn write_const(&mut self, w: &mut dyn Write, c: &RustConst) -> std::io::Result<()> {
+        self.write_comments(w, 0, &c.comments)?;
+
+        let value = match &c.expr {
+            RustConstExpr::Int(value)
+                if self.int64_strategy == Int64Strategy::String && is_int64(&c.r#type) =>
+            {
+                format!("\"{value}\"")
+            }
+            // `ULong` is an alias for `Long`, whose literals need a suffix
+            RustConstExpr::Int(value) if is_int64(&c.r#type) => format!("{value}L"),
+            RustConstExpr::Int(value) => value.to_string(),
+            RustConstExpr::Float(value) => match &c.r#type {
+                RustType::Special(SpecialRustType::F32) => format!("{value}f"),
+                _ => value.clone(),
+            },
+            RustConstExpr::Bool(value) => value.to_string(),
+            RustConstExpr::String(value) => format!("\"{}\"", escape_string_literal(value)),
+        };
+
+        writeln!(
+            w,
+            "val {}: {} = {}\n",
+            c.id.renamed,
+            self.format_type(&c.r#type, &[])
+                .map_err(std::io::Error::other)?,
+            value
+        )
+    }
+
     fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> std::io::Result<()> {
         self.write_comments(w, 0, &rs.comments)?;
+        self.write_deprecation(w, 0, &rs.deprecated)?;
 
         if !rs.fields.is_empty() {
             writeln!(
@@ -179,12 +254,20 @@ impl Language for Scala {
     }
 
     fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> std::io::Result<()> {
-        // Generate named types for any anonymous struct variants of this enum
-        self.write_types_for_anonymous_structs(w, e, &|variant_name| {
-            format!("{}{}Inner", &e.shared().id.renamed, variant_name)
-        })?;
+        if let RustEnum::Untagged(_) = e {
+            check_untagged_variants(e).map_err(std::io::Error::other)?;
+        }
+
+        // Generate named types for any anonymous struct variants of this enum.
+        // Internally tagged enums hold the fields in the variant itself.
+        if !matches!(e, RustEnum::InternallyTagged { .. }) {
+            self.write_types_for_anonymous_structs(w, e, &|variant_name| {
+                format!("{}{}Inner", &e.shared().id.renamed, variant_name)
+            })?;
+        }
 
         self.write_comments(w, 0, &e.shared().comments)?;
+        self.write_deprecation(w, 0, &e.shared().deprecated)?;
 
         let generic_parameters = (!e.shared().generic_types.is_empty())
             .then(|| format!("[{}]", e.shared().generic_types.join(", ")))
@@ -198,7 +281,11 @@ impl Language for Scala {
                     shared.id.renamed, generic_parameters
                 )?;
             }
-            RustEnum::Algebraic { shared, .. } => {
+            RustEnum::Algebraic { shared, .. }
+            | RustEnum::ExternallyTagged(shared)
+            | RustEnum::InternallyTagged { shared, .. }
+            | RustEnum::Untagged(shared)
+            | RustEnum::Numeric { shared, .. } => {
                 writeln!(
                     w,
                     "sealed trait {}{} {{",
@@ -206,7 +293,11 @@ impl Language for Scala {
                 )?;
             }
         }
-        writeln!(w, "\tdef serialName: String")?;
+        if let RustEnum::Numeric { repr, .. } = e {
+            writeln!(w, "\tdef value: {}", numeric_value_type(repr))?;
+        } else {
+            writeln!(w, "\tdef serialName: String")?;
+        }
         writeln!(w, "}}")?;
 
         writeln!(w, "object {} {{", &e.shared().id.renamed)?;
@@ -216,10 +307,23 @@ impl Language for Scala {
 
     fn write_imports(
         &mut self,
-        _writer: &mut dyn Write,
-        _imports: super::ScopedCrateTypes<'_>,
+        w: &mut dyn Write,
+        _crate_name: &CrateName,
+        imports: ScopedCrateTypes<'_>,
     ) -> std::io::Result<()> {
-        unimplemented!()
+        if imports.is_empty() {
+            return Ok(());
+        }
+        for (path, ty) in imports {
+            for t in ty {
+                writeln!(w, "import {}.{path}.{t}", self.package)?;
+            }
+        }
+        writeln!(w)
+    }
+
+    fn inlines_flattened_fields(&self) -> bool {
+        true
     }
 }
 
@@ -229,6 +333,7 @@ impl Scala {
             RustEnum::Unit(shared) => {
                 for v in shared.variants.iter() {
                     self.write_comments(w, 1, &v.shared().comments)?;
+                    self.write_deprecation(w, 1, &v.shared().deprecated)?;
                     writeln!(
                         w,
                         "\tcase object {} extends {} {{",
@@ -243,14 +348,44 @@ impl Scala {
                     writeln!(w, "\t}}")?;
                 }
             }
-            RustEnum::Algebraic {
-                content_key,
-                shared,
-                ..
-            } => {
+            RustEnum::Numeric { repr, shared } => {
+                let value_type = numeric_value_type(repr);
+                let suffix = if value_type == "Long" { "L" } else { "" };
+                for v in shared.variants.iter() {
+                    self.write_comments(w, 1, &v.shared().comments)?;
+                    self.write_deprecation(w, 1, &v.shared().deprecated)?;
+                    writeln!(
+                        w,
+                        "\tcase object {} extends {} {{",
+                        &v.shared().id.original,
+                        &e.shared().id.renamed
+                    )?;
+                    writeln!(
+                        w,
+                        "\t\tval value: {value_type} = {}{suffix}",
+                        v.shared().discriminant.unwrap_or_default()
+                    )?;
+                    writeln!(w, "\t}}")?;
+                }
+            }
+            RustEnum::Algebraic { shared, .. }
+            | RustEnum::ExternallyTagged(shared)
+            | RustEnum::InternallyTagged { shared, .. }
+            | RustEnum::Untagged(shared) => {
+                let content_key = match e {
+                    RustEnum::Algebraic { content_key, .. } => content_key.as_str(),
+                    _ => "content",
+                };
+                let generic_parameters = if shared.generic_types.is_empty() {
+                    String::new()
+                } else {
+                    format!("[{}]", shared.generic_types.join(", "))
+                };
+
                 for v in shared.variants.iter() {
                     let printed_value = format!(r##"{:?}"##, &v.shared().id.renamed);
                     self.write_comments(w, 1, &v.shared().comments)?;
+                    self.write_deprecation(w, 1, &v.shared().deprecated)?;
 
                     let variant_name = {
                         let mut variant_name = v.shared().id.original.to_string();
@@ -288,6 +423,29 @@ impl Scala {
                             write!(w, "{}: {}", content_key, variant_type)?;
                             write!(w, ")")?;
                         }
+                        // The fields are serialized next to the tag
+                        RustEnumVariant::AnonymousStruct { fields, .. }
+                            if matches!(e, RustEnum::InternallyTagged { .. }) =>
+                        {
+                            writeln!(w, "\tcase class {}{}(", variant_name, generic_parameters)?;
+
+                            // Written to a buffer first so the fields can be
+                            // indented inside the companion object
+                            let mut elements = Vec::new();
+                            for f in fields {
+                                let mut element = Vec::new();
+                                self.write_element(
+                                    &mut element,
+                                    f,
+                                    e.shared().generic_types.as_slice(),
+                                )?;
+                                elements
+                                    .push(String::from_utf8_lossy(&element).replace('\t', "\t\t"));
+                            }
+
+                            writeln!(w, "{}", elements.join(",\n"))?;
+                            write!(w, "\t)")?;
+                        }
                         RustEnumVariant::AnonymousStruct { shared, fields } => {
                             write!(
                                 w,
@@ -354,6 +512,7 @@ impl Scala {
         generic_types: &[String],
     ) -> std::io::Result<()> {
         self.write_comments(w, 1, &f.comments)?;
+        self.write_deprecation(w, 1, &f.deprecated)?;
 
         let ty = match f.type_override(SupportedLanguage::Scala) {
             Some(type_override) => type_override.to_owned(),
@@ -367,7 +526,7 @@ impl Scala {
             "\t{}: {}{}",
             remove_dash_from_identifier(&f.id.renamed),
             ty,
-            (f.has_default && !f.ty.is_optional())
+            (f.may_be_absent() && !f.ty.is_optional())
                 .then_some(" = _")
                 .or_else(|| f.ty.is_optional().then_some(" = None"))
                 .unwrap_or_default()
@@ -384,6 +543,31 @@ impl Scala {
         Ok(())
     }
 
+    /// Write a `@deprecated` annotation on a deprecated item, so the Scala
+    /// compiler warns about its uses.
+    fn write_deprecation(
+        &mut self,
+        w: &mut dyn Write,
+        indent: usize,
+        deprecated: &Option<Deprecation>,
+    ) -> std::io::Result<()> {
+        let Some(deprecated) = deprecated else {
+            return Ok(());
+        };
+
+        let indent = "\t".repeat(indent);
+        if deprecated.note.is_none() && deprecated.since.is_none() {
+            writeln!(w, "{indent}@deprecated")
+        } else {
+            writeln!(
+                w,
+                "{indent}@deprecated(\"{}\", \"{}\")",
+                escape_string_literal(deprecated.note.as_deref().unwrap_or_default()),
+                escape_string_literal(deprecated.since.as_deref().unwrap_or_default())
+            )
+        }
+    }
+
     fn write_comments(
         &mut self,
         w: &mut dyn Write,
@@ -395,8 +579,18 @@ impl Scala {
             .try_for_each(|comment| self.write_comment(w, indent, comment))
     }
 
-    fn begin_package_object(&mut self, w: &mut dyn Write) -> std::io::Result<()> {
-        match self.package.rsplit_once('.') {
+    /// The package of the file generated for `data`. Each crate gets its own
+    /// package inside the configured one when generating multiple files.
+    fn file_package(&self, data: &ParsedData) -> String {
+        if data.multi_file {
+            format!("{}.{}", self.package, data.crate_name)
+        } else {
+            self.package.clone()
+        }
+    }
+
+    fn begin_package_object(&mut self, w: &mut dyn Write, package: &str) -> std::io::Result<()> {
+        match package.rsplit_once('.') {
             None => {}
             Some((_parent, last)) => {
                 writeln!(w, "package object {} {{", last)?;
@@ -406,8 +600,8 @@ impl Scala {
         Ok(())
     }
 
-    fn begin_package(&mut self, w: &mut dyn Write) -> std::io::Result<()> {
-        match self.package.rsplit_once('.') {
+    fn begin_package(&mut self, w: &mut dyn Write, package: &str) -> std::io::Result<()> {
+        match package.rsplit_once('.') {
             None => {}
             Some((_parent, last)) => {
                 writeln!(w, "package {} {{", last)?;
@@ -421,7 +615,7 @@ impl Scala {
         writeln!(w, "type UByte = Byte")?;
         writeln!(w, "type UShort = Short")?;
         writeln!(w, "type UInt = Int")?;
-        writeln!(w, "type ULong = Int")?;
+        writeln!(w, "type ULong = Long")?;
         writeln!(w)?;
         Ok(())
     }
@@ -438,6 +632,7 @@ impl Scala {
 
     fn unsigned_integer_used(&mut self, data: &ParsedData) -> bool {
         let types_in_aliases = data.aliases.iter().map(|f| f.r#type.clone()).collect_vec();
+        let types_in_consts = data.consts.iter().map(|c| c.r#type.clone()).collect_vec();
         let types_in_structs = data
             .structs
             .iter()
@@ -457,31 +652,108 @@ impl Scala {
                 })
             })
             .collect_vec();
-        itertools::concat(vec![types_in_aliases, types_in_structs, types_in_enum])
-            .iter()
-            .flat_map(|ty| match ty {
-                RustType::Generic { id: _, parameters } => parameters.clone(),
-                RustType::Special(SpecialRustType::Option(ty) | SpecialRustType::Vec(ty)) => {
-                    vec![ty.deref().clone()]
-                }
-                RustType::Special(SpecialRustType::HashMap(kty, vty)) => {
-                    vec![kty.deref().clone(), vty.deref().clone()]
-                }
-                RustType::Special(_) => vec![ty.clone()],
-                RustType::Simple { .. } => vec![],
-            })
-            .any(|ty| {
-                matches!(
-                    ty,
-                    RustType::Special(
-                        SpecialRustType::U8
-                            | SpecialRustType::U16
-                            | SpecialRustType::U32
-                            | SpecialRustType::U53
-                            | SpecialRustType::U64
-                            | SpecialRustType::USize,
-                    )
+        itertools::concat(vec![
+            types_in_aliases,
+            types_in_consts,
+            types_in_structs,
+            types_in_enum,
+        ])
+        .iter()
+        .flat_map(|ty| match ty {
+            RustType::Generic { id: _, parameters } => parameters.clone(),
+            RustType::Special(
+                SpecialRustType::Option(ty) | SpecialRustType::Vec(ty) | SpecialRustType::Set(ty),
+            ) => {
+                vec![ty.deref().clone()]
+            }
+            RustType::Special(
+                SpecialRustType::HashMap(kty, vty) | SpecialRustType::OrderedMap(kty, vty),
+            ) => {
+                vec![kty.deref().clone(), vty.deref().clone()]
+            }
+            RustType::Special(_) => vec![ty.clone()],
+            RustType::Simple { .. } => vec![],
+        })
+        .any(|ty| {
+            matches!(
+                ty,
+                RustType::Special(
+                    SpecialRustType::U8
+                        | SpecialRustType::U16
+                        | SpecialRustType::U32
+                        | SpecialRustType::U53
+                        | SpecialRustType::U64
+                        | SpecialRustType::USize,
                 )
-            })
+            )
+        })
+    }
+}
+
+/// The Scala type holding the discriminants of a numeric enum with the given representation.
+fn numeric_value_type(repr: &SpecialRustType) -> &'static str {
+    match repr {
+        SpecialRustType::I8
+        | SpecialRustType::I16
+        | SpecialRustType::I32
+        | SpecialRustType::U8
+        | SpecialRustType::U16 => "Int",
+        _ => "Long",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{HashMap, HashSet};
+
+    use super::Scala;
+    use crate::{language::Language, parser::parse};
+
+    #[test]
+    fn multi_file_uses_crate_packages_and_imports() {
+        let source = r#"
+use core_types::Id;
+
+#[typeshare]
+pub struct User {
+    pub id: Id,
+}
+"#;
+        let data = parse(
+            source,
+            "user_types".into(),
+            "user_types.scala".into(),
+            "user_types/src/lib.rs".into(),
+            &[],
+            true,
+            &[],
+        )
+        .unwrap()
+        .unwrap();
+        let all_types = HashMap::from([("core_types".into(), HashSet::from(["Id".into()]))]);
+
+        let mut scala = Scala {
+            package: "com.example".into(),
+            no_version_header: true,
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        scala.generate_types(&mut out, &all_types, data).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "package com.example
+
+import com.example.core_types.Id
+
+package user_types {
+
+case class User (
+\tid: Id
+)
+
+}
+"
+        );
     }
 }
@@ -1,10 +1,13 @@
 use crate::{
-    language::{Language, SupportedLanguage},
+    language::{
+        check_untagged_variants, escape_string_literal, is_int64, keys_outside_flattened_maps,
+        struct_keys, CrateName, Int64Strategy, Language, ScopedCrateTypes, SupportedLanguage,
+    },
     parser::{remove_dash_from_identifier, DecoratorKind, ParsedData},
     rename::RenameExt,
     rust_types::{
-        DecoratorMap, RustEnum, RustEnumVariant, RustStruct, RustTypeAlias, RustTypeFormatError,
-        SpecialRustType,
+        DecoratorMap, Deprecation, RustConst, RustConstExpr, RustEnum, RustEnumVariant, RustField,
+        RustStruct, RustType, RustTypeAlias, RustTypeFormatError, SpecialRustType,
     },
     GenerationError,
 };
@@ -84,9 +87,30 @@ const CODABLE: &str = "Codable";
 /// TODO: expand on this.
 #[derive(Debug)]
 struct CodingKeysInfo {
+    /// Decoding of the unit variants of an externally tagged enum, which are
+    /// serialized as a plain string.
+    unit_decoding_cases: Vec<String>,
     decoding_cases: Vec<String>,
     encoding_cases: Vec<String>,
     coding_keys: Vec<String>,
+    /// The `serde(other)` case that unknown variants decode into.
+    other_case: Option<String>,
+}
+
+/// Where an algebraic enum keeps the content of its variants.
+#[derive(Debug, Clone, Copy)]
+enum VariantContent<'a> {
+    /// Under the content key, next to the tag.
+    Adjacent {
+        tag_key: &'a str,
+        content_key: &'a str,
+    },
+    /// Under the name of the variant.
+    External,
+    /// In the same container as the tag.
+    Internal { tag_key: &'a str },
+    /// On its own, without any tag.
+    Untagged,
 }
 
 /// A container for generic constraints.
@@ -147,6 +171,10 @@ pub struct Swift {
     /// This will add a definition of a `CodableVoid` type to the generated Swift code and
     /// use `CodableVoid` to replace `()`.
     pub should_emit_codable_void: AtomicBool,
+    /// Will be set to true if one of your typeshared enums has a `serde(other)` variant that
+    /// carries data. This will add a definition of a `CodableJSON` type to the generated Swift
+    /// code, which holds the raw JSON of an unknown variant.
+    pub should_emit_codable_json: AtomicBool,
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
@@ -154,6 +182,18 @@ pub struct Swift {
     pub multi_file: bool,
     /// The constraints to apply to `CodableVoid`.
     pub codablevoid_constraints: Vec<String>,
+    /// How 64 bit integers are represented.
+    pub int64_strategy: Int64Strategy,
+    /// The Swift module each crate's types are generated into, for multi file output.
+    /// Crates without a module use their name in PascalCase.
+    pub module_names: HashMap<String, String>,
+    /// The Swift module `Codable.swift` is added to, for multi file output. Every
+    /// other module imports it for the `CodableVoid` and `CodableJSON` types.
+    pub codable_module: Option<String>,
+    /// The keys of the JSON object of each struct, including those of the
+    /// structs flattened into it. This is filled in when a file is begun, and
+    /// keeps the keys of fields out of flattened maps.
+    pub struct_keys: HashMap<String, Vec<String>>,
 }
 
 impl Language for Swift {
@@ -181,6 +221,15 @@ impl Language for Swift {
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         Ok(match special_ty {
+            SpecialRustType::I64
+            | SpecialRustType::U64
+            | SpecialRustType::ISize
+            | SpecialRustType::USize
+                if self.int64_strategy != Int64Strategy::Number =>
+            {
+                self.int64_strategy
+                    .format_non_native(special_ty, "String", "Swift")?
+            }
             SpecialRustType::Vec(rtype) => format!("[{}]", self.format_type(rtype, generic_types)?),
             SpecialRustType::Array(rtype, _) => {
                 format!("[{}]", self.format_type(rtype, generic_types)?)
@@ -191,7 +240,13 @@ impl Language for Swift {
             SpecialRustType::Option(rtype) => {
                 format!("{}?", self.format_type(rtype, generic_types)?)
             }
-            SpecialRustType::HashMap(rtype1, rtype2) => format!(
+            // Tuples are replaced by generated types in `name_tuple_types`
+            SpecialRustType::Tuple(_) => return Err(RustTypeFormatError::UnnamedTuple),
+            SpecialRustType::Set(rtype) => {
+                format!("Set<{}>", self.format_type(rtype, generic_types)?)
+            }
+            SpecialRustType::HashMap(rtype1, rtype2)
+            | SpecialRustType::OrderedMap(rtype1, rtype2) => format!(
                 "[{}: {}]",
                 self.format_type(rtype1, generic_types)?,
                 self.format_type(rtype2, generic_types)?
@@ -218,7 +273,9 @@ impl Language for Swift {
         })
     }
 
-    fn begin_file(&mut self, w: &mut dyn Write, _parsed_data: &ParsedData) -> io::Result<()> {
+    fn begin_file(&mut self, w: &mut dyn Write, parsed_data: &ParsedData) -> io::Result<()> {
+        self.struct_keys = struct_keys(parsed_data);
+
         if !self.no_version_header {
             writeln!(w, "/*")?;
             writeln!(w, " Generated by typeshare {}", env!("CARGO_PKG_VERSION"))?;
@@ -230,7 +287,7 @@ impl Language for Swift {
     }
 
     fn end_file(&mut self, w: &mut dyn Write) -> io::Result<()> {
-        if self.should_emit_codable_void.load(Ordering::SeqCst) && !self.multi_file {
+        if self.should_emit_codable() && !self.multi_file {
             self.write_codable(w, &self.get_codable_contents())?;
         }
 
@@ -240,10 +297,15 @@ impl Language for Swift {
     fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> io::Result<()> {
         writeln!(w)?;
         self.write_comments(w, 0, &ty.comments)?;
+        self.write_deprecation(w, 0, &ty.deprecated)?;
 
         let swift_prefix = &self.prefix;
         let type_name = swift_keyword_aware_rename(format!("{}{}", swift_prefix, ty.id.renamed));
 
+        if let RustType::Special(SpecialRustType::Tuple(elements)) = &ty.r#type {
+            return self.write_tuple_struct(w, ty, &type_name, elements);
+        }
+
         writeln!(
             w,
             "public typealias {}{} = {}",
@@ -258,33 +320,42 @@ impl Language for Swift {
         Ok(())
     }
 
+    fn write_const(&mut self, w: &mut dyn Write, c: &RustConst) -> io::Result<()> {
+        writeln!(w)?;
+        self.write_comments(w, 0, &c.comments)?;
+
+        let value = match &c.expr {
+            RustConstExpr::Int(value)
+                if self.int64_strategy == Int64Strategy::String && is_int64(&c.r#type) =>
+            {
+                format!("\"{value}\"")
+            }
+            RustConstExpr::Int(value) => value.to_string(),
+            RustConstExpr::Float(value) => value.clone(),
+            RustConstExpr::Bool(value) => value.to_string(),
+            RustConstExpr::String(value) => format!("\"{}\"", escape_string_literal(value)),
+        };
+
+        writeln!(
+            w,
+            "public let {}: {} = {}",
+            swift_keyword_aware_rename(&c.id.renamed),
+            self.format_type(&c.r#type, &[]).map_err(io::Error::other)?,
+            value
+        )
+    }
+
     fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
         let mut coding_keys = vec![];
         let mut should_write_coding_keys = false;
 
         writeln!(w)?;
         self.write_comments(w, 0, &rs.comments)?;
+        self.write_deprecation(w, 0, &rs.deprecated)?;
 
         let type_name = swift_keyword_aware_rename(format!("{}{}", self.prefix, rs.id.renamed));
 
-        // If there are no decorators found for this struct, still write `Codable` and default decorators for structs
-        // Check if this struct's decorators contains swift in the hashmap
-        let decs = if let Some(swift_decs) = rs.decorators.get(&DecoratorKind::Swift) {
-            // For reach item in the received decorators in the typeshared struct add it to the original vector
-            // this avoids duplicated of `Codable` without needing to `.sort()` then `.dedup()`
-            // Note: the list received from `rs.decorators` is already deduped
-            Either::Left(
-                self.get_default_decorators().chain(
-                    swift_decs
-                        .iter()
-                        .filter(|d| d.as_str() != CODABLE)
-                        .map(|s| s.as_str()),
-                ),
-            )
-        } else {
-            Either::Right(self.get_default_decorators())
-        }
-        .join(", ");
+        let decs = self.struct_decorators(&rs.decorators);
 
         let generic_names_and_constraints =
             self.generic_constraints(&rs.decorators, &rs.generic_types);
@@ -298,14 +369,36 @@ impl Language for Swift {
             decs
         )?;
 
+        // Flattened fields are decoded from the same container as the struct,
+        // fields with aliases from any of their keys, and a struct tag has no
+        // property, so the synthesized `Codable` conformance won't do.
+        let needs_custom_codable = rs.tag_key.is_some()
+            || rs
+                .fields
+                .iter()
+                .any(|f| f.flattened || f.id.has_deserialize_aliases());
+
+        if let Some(tag_key) = &rs.tag_key {
+            let tag_case =
+                remove_dash_from_identifier(swift_keyword_aware_rename(tag_key).as_ref());
+            if tag_key.contains('-') {
+                coding_keys.push(format!(r##"{tag_case} = "{tag_key}""##));
+            } else {
+                coding_keys.push(tag_case);
+            }
+        }
+
         for f in &rs.fields {
             self.write_comments(w, 1, &f.comments)?;
+            self.write_deprecation(w, 1, &f.deprecated)?;
 
             // Create coding keys for serialization / deserialization
             //
             // As of right now this was only written to handle fields
             // that get renamed to an ident with - in it
-            if f.id.renamed.chars().any(|c| c == '-') {
+            if f.flattened {
+                // Flattened fields don't have a key of their own
+            } else if f.id.renamed.chars().any(|c| c == '-') {
                 coding_keys.push(format!(
                     r##"{} = "{}""##,
                     remove_dash_from_identifier(swift_keyword_aware_rename(&f.id.renamed).as_ref()),
@@ -321,6 +414,19 @@ impl Language for Swift {
                 ));
             }
 
+            // Each other name accepted when deserializing gets a key of its own
+            if !f.flattened {
+                for (i, name) in alias_names(f).enumerate() {
+                    coding_keys.push(format!(
+                        r##"{}Alias{} = "{}""##,
+                        remove_dash_from_identifier(&f.id.renamed),
+                        i + 1,
+                        name
+                    ));
+                    should_write_coding_keys = true;
+                }
+            }
+
             let case_type: String = match f.type_override(SupportedLanguage::Swift) {
                 Some(type_override) => type_override.to_owned(),
                 None => self
@@ -333,13 +439,13 @@ impl Language for Swift {
                 "\tpublic let {}: {}{}",
                 remove_dash_from_identifier(swift_keyword_aware_rename(&f.id.renamed).as_ref()),
                 case_type,
-                (f.has_default && !f.ty.is_optional())
+                (f.may_be_absent() && !f.ty.is_optional())
                     .then_some("?")
                     .unwrap_or_default()
             )?;
         }
 
-        if should_write_coding_keys {
+        if should_write_coding_keys || (needs_custom_codable && !coding_keys.is_empty()) {
             writeln!(
                 w,
                 r#"
@@ -350,6 +456,10 @@ impl Language for Swift {
             )?;
         }
 
+        if needs_custom_codable {
+            self.write_custom_codable(w, rs, !coding_keys.is_empty())?;
+        }
+
         if !rs.fields.is_empty() {
             writeln!(w)?;
         }
@@ -363,13 +473,15 @@ impl Language for Swift {
                     .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
             };
 
+            // A field that serde skips can be left out of the initializer too
             init_params.push(format!(
-                "{}: {}{}",
+                "{}: {}{}{}",
                 remove_dash_from_identifier(&f.id.renamed),
                 swift_ty,
-                (f.has_default && !f.ty.is_optional())
+                (f.may_be_absent() && !f.ty.is_optional())
                     .then_some("?")
-                    .unwrap_or_default()
+                    .unwrap_or_default(),
+                if f.skip_serializing_if { " = nil" } else { "" }
             ));
         }
 
@@ -422,7 +534,14 @@ impl Language for Swift {
                 .into_iter()
                 .chain(self.get_default_decorators())
                 .collect::<Vec<_>>(),
-            RustEnum::Algebraic { .. } => self.get_default_decorators().collect::<Vec<_>>(),
+            RustEnum::Numeric { repr, .. } => [numeric_raw_type(repr)]
+                .into_iter()
+                .chain(self.get_default_decorators())
+                .collect::<Vec<_>>(),
+            RustEnum::Algebraic { .. }
+            | RustEnum::ExternallyTagged(_)
+            | RustEnum::InternallyTagged { .. }
+            | RustEnum::Untagged(_) => self.get_default_decorators().collect::<Vec<_>>(),
         };
         let decs = determine_decorators(&always_present, e).join(", ");
 
@@ -432,10 +551,23 @@ impl Language for Swift {
 
         writeln!(w)?;
 
+        // The tag of an internally tagged enum is a key of the anonymous structs
+        for v in &shared.variants {
+            if let RustEnumVariant::AnonymousStruct { fields, shared } = v {
+                let mut keys = keys_outside_flattened_maps(fields, &self.struct_keys);
+                if let RustEnum::InternallyTagged { tag_key, .. } = e {
+                    keys.push(tag_key.clone());
+                }
+                self.struct_keys
+                    .insert(make_anonymous_struct_name(&shared.id.original), keys);
+            }
+        }
+
         // Generate named types for any anonymous struct variants of this enum
         self.write_types_for_anonymous_structs(w, e, &make_anonymous_struct_name)?;
 
         self.write_comments(w, 0, &shared.comments)?;
+        self.write_deprecation(w, 0, &shared.deprecated)?;
         let indirect = if shared.is_recursive { "indirect " } else { "" };
 
         let generic_names_and_constraints =
@@ -463,17 +595,46 @@ impl Language for Swift {
             )?;
         }
 
-        if let RustEnum::Algebraic {
-            tag_key,
-            content_key,
-            ..
-        } = e
-        {
+        if let (RustEnum::Unit(_), Some(other_case)) = (e, &coding_keys_info.other_case) {
+            writeln!(
+                w,
+                r#"
+	public init(from decoder: Decoder) throws {{
+		let container = try decoder.singleValueContainer()
+		let rawValue = try container.decode(String.self)
+		self = {enum_name}(rawValue: rawValue) ?? .{other_case}
+	}}"#
+            )?;
+        }
+
+        let container_keys = match e {
+            RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                ..
+            } => Some((tag_key, format!("{tag_key}, {content_key}"))),
+            RustEnum::InternallyTagged { tag_key, .. } => Some((tag_key, tag_key.to_owned())),
+            _ => None,
+        };
+
+        if let RustEnum::InternallyTagged { shared, .. } = e {
+            if shared
+                .variants
+                .iter()
+                .any(|v| matches!(v, RustEnumVariant::Tuple { ty, .. } if ty.is_map()))
+            {
+                writeln!(w)?;
+                writeln!(w, "\t/// Any key of the JSON object, for the map variants")?;
+                write_any_key(w)?;
+            }
+        }
+
+        if let Some((tag_key, container_keys)) = container_keys {
             writeln!(
                 w,
                 r#"
 	private enum ContainerCodingKeys: String, CodingKey {{
-		case {tag_key}, {content_key}
+		case {container_keys}
 	}}
 
 	public init(from decoder: Decoder) throws {{
@@ -482,7 +643,7 @@ impl Language for Swift {
 			switch type {{{decoding_switch}
 			}}
 		}}
-		throw DecodingError.typeMismatch({type_name}.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for {type_name}"))
+		{fallback}
 	}}
 
 	public func encode(to encoder: Encoder) throws {{
@@ -491,9 +652,71 @@ impl Language for Swift {
 		}}
 	}}"#,
                 tag_key = tag_key,
-                content_key = content_key,
-                type_name = enum_name,
+                container_keys = container_keys,
+                decoding_switch = coding_keys_info.decoding_cases.join(""),
+                encoding_switch = coding_keys_info.encoding_cases.join(""),
+                fallback = decoding_fallback(&enum_name, coding_keys_info.other_case.as_deref()),
+            )?;
+        }
+
+        if let RustEnum::ExternallyTagged(_) = e {
+            let has_unit_variants = !coding_keys_info.unit_decoding_cases.is_empty();
+
+            writeln!(w)?;
+            writeln!(w, "\tpublic init(from decoder: Decoder) throws {{")?;
+            if has_unit_variants {
+                write!(
+                    w,
+                    r#"		if let container = try? decoder.singleValueContainer(), let type = try? container.decode(CodingKeys.self) {{
+			switch type {{{unit_decoding_switch}
+			default:
+				break
+			}}
+		}}
+"#,
+                    unit_decoding_switch = coding_keys_info.unit_decoding_cases.join(""),
+                )?;
+            }
+            writeln!(
+                w,
+                r#"		if let container = try? decoder.container(keyedBy: CodingKeys.self), let type = container.allKeys.first {{
+			switch type {{{decoding_switch}{default_case}
+			}}
+		}}
+		{fallback}
+	}}
+
+	public func encode(to encoder: Encoder) throws {{
+		switch self {{{encoding_switch}
+		}}
+	}}"#,
                 decoding_switch = coding_keys_info.decoding_cases.join(""),
+                default_case = if has_unit_variants {
+                    "\n\t\t\tdefault:\n\t\t\t\tbreak"
+                } else {
+                    ""
+                },
+                encoding_switch = coding_keys_info.encoding_cases.join(""),
+                fallback = decoding_fallback(&enum_name, coding_keys_info.other_case.as_deref()),
+            )?;
+        }
+
+        if let RustEnum::Untagged(_) = e {
+            writeln!(
+                w,
+                r#"
+	public init(from decoder: Decoder) throws {{
+		let container = try decoder.singleValueContainer(){decoding_attempts}
+		throw DecodingError.typeMismatch({type_name}.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for {type_name}"))
+	}}
+
+	public func encode(to encoder: Encoder) throws {{
+		var container = encoder.singleValueContainer()
+		switch self {{{encoding_switch}
+		}}
+	}}"#,
+                type_name = enum_name,
+                decoding_attempts = coding_keys_info.decoding_cases.join(""),
                 encoding_switch = coding_keys_info.encoding_cases.join(""),
             )?;
         }
@@ -501,30 +724,34 @@ impl Language for Swift {
         writeln!(w, "}}")
     }
 
-    // TODO: This will be added in the future.
-    // fn write_imports(
-    //     &mut self,
-    //     w: &mut dyn Write,
-    //     imports: super::ScopedCrateTypes<'_>,
-    // ) -> std::io::Result<()> {
-    //     for module in imports.keys() {
-    //         writeln!(w, "import {}", module.0.to_pascal_case())?;
-    //     }
-    //     writeln!(w)
-    // }
-
     fn write_imports(
         &mut self,
-        _writer: &mut dyn Write,
-        _imports: super::ScopedCrateTypes<'_>,
-    ) -> std::io::Result<()> {
-        // This will be added to Foundation for now.
+        w: &mut dyn Write,
+        crate_name: &CrateName,
+        imports: ScopedCrateTypes<'_>,
+    ) -> io::Result<()> {
+        // Swift imports whole modules rather than single types, and several
+        // crates can share a module
+        let own_module = self.module_name(crate_name);
+        let modules = imports
+            .keys()
+            .map(|crate_name| self.module_name(crate_name))
+            .chain(self.codable_module.clone())
+            .filter(|module| *module != own_module)
+            .collect::<BTreeSet<_>>();
+        for module in modules {
+            writeln!(w, "import {module}")?;
+        }
         Ok(())
     }
 
+    fn names_tuple_types(&self) -> bool {
+        true
+    }
+
     fn post_generation(&self, output_folder: &str) -> Result<(), GenerationError> {
         //
-        if self.should_emit_codable_void.load(Ordering::SeqCst) && self.multi_file {
+        if self.should_emit_codable() && self.multi_file {
             self.write_codable_file(output_folder)
                 .map_err(|e| GenerationError::PostGeneration(e.to_string()))?;
         }
@@ -533,22 +760,308 @@ impl Language for Swift {
 }
 
 impl Swift {
+    /// The Swift module the types of a crate are generated into.
+    fn module_name(&self, crate_name: &CrateName) -> String {
+        self.module_names
+            .get(crate_name.as_str())
+            .cloned()
+            .unwrap_or_else(|| crate_name.to_string().to_pascal_case())
+    }
+
+    /// The protocols a struct conforms to. If there are no decorators found for
+    /// this struct, still write `Codable` and default decorators for structs.
+    fn struct_decorators(&self, decorators: &DecoratorMap) -> String {
+        // Check if this struct's decorators contains swift in the hashmap
+        if let Some(swift_decs) = decorators.get(&DecoratorKind::Swift) {
+            // For reach item in the received decorators in the typeshared struct add it to the original vector
+            // this avoids duplicated of `Codable` without needing to `.sort()` then `.dedup()`
+            // Note: the list received from `decorators` is already deduped
+            Either::Left(
+                self.get_default_decorators().chain(
+                    swift_decs
+                        .iter()
+                        .filter(|d| d.as_str() != CODABLE)
+                        .map(|s| s.as_str()),
+                ),
+            )
+        } else {
+            Either::Right(self.get_default_decorators())
+        }
+        .join(", ")
+    }
+
+    /// Write a struct for a tuple, like a tuple struct with several fields.
+    /// Tuples are serialized as arrays, so the struct is coded with an unkeyed
+    /// container.
+    fn write_tuple_struct(
+        &mut self,
+        w: &mut dyn Write,
+        ty: &RustTypeAlias,
+        type_name: &str,
+        elements: &[RustType],
+    ) -> io::Result<()> {
+        let generic_names_and_constraints =
+            self.generic_constraints(&ty.decorators, &ty.generic_types);
+        let generic_parameters = if ty.generic_types.is_empty() {
+            String::new()
+        } else {
+            format!("<{generic_names_and_constraints}>")
+        };
+
+        writeln!(
+            w,
+            "public struct {type_name}{generic_parameters}: {} {{",
+            self.struct_decorators(&ty.decorators)
+        )?;
+
+        let element_types = elements
+            .iter()
+            .map(|element| self.format_type(element, ty.generic_types.as_slice()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(io::Error::other)?;
+
+        for (i, element_type) in element_types.iter().enumerate() {
+            writeln!(w, "\tpublic let _{i}: {element_type}")?;
+        }
+        writeln!(w)?;
+
+        writeln!(
+            w,
+            "\tpublic init({}) {{",
+            element_types
+                .iter()
+                .enumerate()
+                .map(|(i, element_type)| format!("_ _{i}: {element_type}"))
+                .join(", ")
+        )?;
+        for i in 0..elements.len() {
+            writeln!(w, "\t\tself._{i} = _{i}")?;
+        }
+        writeln!(w, "\t}}")?;
+        writeln!(w)?;
+
+        writeln!(w, "\tpublic init(from decoder: Decoder) throws {{")?;
+        writeln!(w, "\t\tvar container = try decoder.unkeyedContainer()")?;
+        for (i, (element, element_type)) in elements.iter().zip(&element_types).enumerate() {
+            match element {
+                RustType::Special(SpecialRustType::Option(inner)) => writeln!(
+                    w,
+                    "\t\tself._{i} = try container.decodeIfPresent({}.self)",
+                    self.format_type(inner, ty.generic_types.as_slice())
+                        .map_err(io::Error::other)?
+                )?,
+                _ => writeln!(
+                    w,
+                    "\t\tself._{i} = try container.decode({element_type}.self)"
+                )?,
+            }
+        }
+        writeln!(w, "\t}}")?;
+        writeln!(w)?;
+
+        writeln!(w, "\tpublic func encode(to encoder: Encoder) throws {{")?;
+        writeln!(w, "\t\tvar container = encoder.unkeyedContainer()")?;
+        for i in 0..elements.len() {
+            writeln!(w, "\t\ttry container.encode(_{i})")?;
+        }
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")
+    }
+
+    /// Write `Codable` conformance for a struct with flattened fields, fields
+    /// with aliases or a tag. Each flattened field is decoded from and encoded
+    /// to the struct's own decoder, a field with aliases is decoded from the
+    /// first of its keys that is present, and the tag is checked when decoding.
+    fn write_custom_codable(
+        &mut self,
+        w: &mut dyn Write,
+        rs: &RustStruct,
+        has_coding_keys: bool,
+    ) -> io::Result<()> {
+        let mut decoding = Vec::new();
+        let mut encoding = Vec::new();
+
+        for f in &rs.fields {
+            let field_name = remove_dash_from_identifier(&f.id.renamed);
+            let optional = f.ty.is_optional() || f.may_be_absent();
+            let swift_ty = match (f.type_override(SupportedLanguage::Swift), &f.ty) {
+                (Some(type_override), _) => type_override.trim_end_matches('?').to_owned(),
+                (None, RustType::Special(SpecialRustType::Option(ty))) => self
+                    .format_type(ty, rs.generic_types.as_slice())
+                    .map_err(io::Error::other)?,
+                (None, ty) => self
+                    .format_type(ty, rs.generic_types.as_slice())
+                    .map_err(io::Error::other)?,
+            };
+
+            let decoding_key = if f.id.has_deserialize_aliases() {
+                format!(
+                    "[CodingKeys.{field_name}{}].first(where: container.contains) ?? .{field_name}",
+                    alias_names(f)
+                        .enumerate()
+                        .map(|(i, _)| format!(", .{field_name}Alias{}", i + 1))
+                        .collect::<String>()
+                )
+            } else {
+                format!(".{field_name}")
+            };
+
+            if f.flattened {
+                if let RustType::Special(
+                    SpecialRustType::HashMap(key, value) | SpecialRustType::OrderedMap(key, value),
+                ) = &f.ty
+                {
+                    if !matches!(key.as_ref(), RustType::Special(SpecialRustType::String)) {
+                        return Err(io::Error::other(format!(
+                            "Flattened map `{}` must have `String` keys in Swift",
+                            f.id.original
+                        )));
+                    }
+                    let value_ty = self
+                        .format_type(value, rs.generic_types.as_slice())
+                        .map_err(io::Error::other)?;
+                    decoding.push(format!(
+                        "self.{field_name} = try Dictionary(uniqueKeysWithValues: rest.allKeys.filter {{ !Self.fieldKeys.contains($0.stringValue) }}.map {{ ($0.stringValue, try rest.decode({value_ty}.self, forKey: $0)) }})"
+                    ));
+                    encoding.push(format!(
+                        "for (key, value) in self.{field_name} {{\n\t\t\ttry rest.encode(value, forKey: AnyKey(stringValue: key))\n\t\t}}"
+                    ));
+                    continue;
+                }
+            }
+
+            match (f.flattened, optional) {
+                (true, false) => {
+                    decoding.push(format!("self.{field_name} = try {swift_ty}(from: decoder)"));
+                    encoding.push(format!("try self.{field_name}.encode(to: encoder)"));
+                }
+                (true, true) => {
+                    decoding.push(format!(
+                        "self.{field_name} = try? {swift_ty}(from: decoder)"
+                    ));
+                    encoding.push(format!("try self.{field_name}?.encode(to: encoder)"));
+                }
+                (false, false) => {
+                    decoding.push(format!(
+                        "self.{field_name} = try container.decode({swift_ty}.self, forKey: {decoding_key})"
+                    ));
+                    encoding.push(format!(
+                        "try container.encode(self.{field_name}, forKey: .{field_name})"
+                    ));
+                }
+                (false, true) => {
+                    decoding.push(format!(
+                        "self.{field_name} = try container.decodeIfPresent({swift_ty}.self, forKey: {decoding_key})"
+                    ));
+                    encoding.push(format!(
+                        "try container.encodeIfPresent(self.{field_name}, forKey: .{field_name})"
+                    ));
+                }
+            }
+        }
+
+        let has_flattened_maps = rs.fields.iter().any(|f| f.flattened && f.ty.is_map());
+        if has_flattened_maps {
+            // The flattened maps hold every key that isn't one of these
+            let keys = self
+                .struct_keys
+                .get(&rs.id.original)
+                .cloned()
+                .unwrap_or_else(|| keys_outside_flattened_maps(&rs.fields, &self.struct_keys));
+            writeln!(w)?;
+            writeln!(
+                w,
+                "\tprivate static let fieldKeys: Set<String> = [{}]",
+                keys.iter().map(|key| format!("{key:?}")).join(", ")
+            )?;
+            writeln!(w)?;
+            writeln!(
+                w,
+                "\t/// Any key of the JSON object, for the flattened maps"
+            )?;
+            write_any_key(w)?;
+        }
+
+        writeln!(w)?;
+        writeln!(w, "\tpublic init(from decoder: Decoder) throws {{")?;
+        if has_coding_keys {
+            writeln!(
+                w,
+                "\t\tlet container = try decoder.container(keyedBy: CodingKeys.self)"
+            )?;
+        }
+        if has_flattened_maps {
+            writeln!(
+                w,
+                "\t\tlet rest = try decoder.container(keyedBy: AnyKey.self)"
+            )?;
+        }
+        if let Some(tag_key) = &rs.tag_key {
+            let tag_case = remove_dash_from_identifier(tag_key);
+            writeln!(
+                w,
+                "\t\tlet tag = try container.decode(String.self, forKey: .{tag_case})"
+            )?;
+            writeln!(w, "\t\tguard tag == {:?} else {{", rs.id.renamed)?;
+            writeln!(
+                w,
+                "\t\t\tthrow DecodingError.dataCorruptedError(forKey: .{tag_case}, in: container, debugDescription: \"Wrong {tag_key} tag for {}: \\(tag)\")",
+                rs.id.renamed
+            )?;
+            writeln!(w, "\t\t}}")?;
+        }
+        for line in decoding {
+            writeln!(w, "\t\t{line}")?;
+        }
+        writeln!(w, "\t}}")?;
+        writeln!(w)?;
+        writeln!(w, "\tpublic func encode(to encoder: Encoder) throws {{")?;
+        if has_coding_keys {
+            writeln!(
+                w,
+                "\t\tvar container = encoder.container(keyedBy: CodingKeys.self)"
+            )?;
+        }
+        if has_flattened_maps {
+            writeln!(w, "\t\tvar rest = encoder.container(keyedBy: AnyKey.self)")?;
+        }
+        if let Some(tag_key) = &rs.tag_key {
+            writeln!(
+                w,
+                "\t\ttry container.encode({:?}, forKey: .{})",
+                rs.id.renamed,
+                remove_dash_from_identifier(tag_key)
+            )?;
+        }
+        for line in encoding {
+            writeln!(w, "\t\t{line}")?;
+        }
+        writeln!(w, "\t}}")
+    }
+
     fn write_enum_variants(
         &mut self,
         w: &mut dyn Write,
         e: &RustEnum,
         make_anonymous_struct_name: impl Fn(&str) -> String,
     ) -> io::Result<CodingKeysInfo> {
+        let mut unit_decoding_cases = Vec::new();
         let mut decoding_cases = Vec::new();
         let mut encoding_cases = Vec::new();
         let mut coding_keys = Vec::new();
+        let mut other_case = None;
 
         match e {
             RustEnum::Unit(shared) => {
                 for v in &shared.variants {
                     let variant_name = v.shared().id.original.to_camel_case();
 
+                    if v.shared().is_other {
+                        other_case = Some(swift_keyword_aware_rename(&variant_name).into_owned());
+                    }
+
                     self.write_comments(w, 1, &v.shared().comments)?;
+                    self.write_deprecation(w, 1, &v.shared().deprecated)?;
                     if v.shared().id.renamed == variant_name {
                         // We don't need to handle any renaming
                         writeln!(w, "\tcase {}", &swift_keyword_aware_rename(&variant_name))?;
@@ -563,14 +1076,44 @@ impl Swift {
                     }
                 }
             }
-            RustEnum::Algebraic {
-                tag_key,
-                content_key,
-                shared,
-            } => {
+            RustEnum::Numeric { shared, .. } => {
+                for v in &shared.variants {
+                    self.write_comments(w, 1, &v.shared().comments)?;
+                    self.write_deprecation(w, 1, &v.shared().deprecated)?;
+                    writeln!(
+                        w,
+                        "\tcase {} = {}",
+                        swift_keyword_aware_rename(v.shared().id.original.to_camel_case()),
+                        v.shared().discriminant.unwrap_or_default()
+                    )?;
+                }
+            }
+            RustEnum::Algebraic { shared, .. }
+            | RustEnum::ExternallyTagged(shared)
+            | RustEnum::InternallyTagged { shared, .. }
+            | RustEnum::Untagged(shared) => {
+                let variant_content = match e {
+                    RustEnum::Algebraic {
+                        tag_key,
+                        content_key,
+                        ..
+                    } => VariantContent::Adjacent {
+                        tag_key,
+                        content_key,
+                    },
+                    RustEnum::InternallyTagged { tag_key, .. } => {
+                        VariantContent::Internal { tag_key }
+                    }
+                    RustEnum::Untagged(_) => {
+                        check_untagged_variants(e).map_err(io::Error::other)?;
+                        VariantContent::Untagged
+                    }
+                    _ => VariantContent::External,
+                };
                 let generics = &shared.generic_types;
                 for v in &shared.variants {
                     self.write_comments(w, 1, &v.shared().comments)?;
+                    self.write_deprecation(w, 1, &v.shared().deprecated)?;
 
                     let variant_name = {
                         let mut variant_name = v.shared().id.original.to_camel_case();
@@ -589,82 +1132,152 @@ impl Swift {
                         variant_name
                     };
 
-                    coding_keys.push(if variant_name == v.shared().id.renamed {
-                        swift_keyword_aware_rename(&variant_name).into_owned()
-                    } else {
-                        format!(
-                            r##"{} = "{}""##,
-                            swift_keyword_aware_rename(&variant_name),
-                            &v.shared().id.renamed
-                        )
-                    });
+                    // The `serde(other)` variant keeps the raw JSON of any variant we don't know about
+                    if v.shared().is_other && !matches!(variant_content, VariantContent::Untagged) {
+                        self.should_emit_codable_json.store(true, Ordering::SeqCst);
+                        writeln!(
+                            w,
+                            "\tcase {}(CodableJSON)",
+                            swift_keyword_aware_rename(&variant_name)
+                        )?;
+                        encoding_cases.push(format!(
+                            "
+		case .{case_name}(let json):
+			try json.encode(to: encoder)",
+                            case_name = swift_keyword_aware_rename(&variant_name),
+                        ));
+                        other_case = Some(swift_keyword_aware_rename(&variant_name).into_owned());
+                        continue;
+                    }
+
+                    // Untagged variants are told apart by their content alone
+                    if !matches!(variant_content, VariantContent::Untagged) {
+                        coding_keys.push(if variant_name == v.shared().id.renamed {
+                            swift_keyword_aware_rename(&variant_name).into_owned()
+                        } else {
+                            format!(
+                                r##"{} = "{}""##,
+                                swift_keyword_aware_rename(&variant_name),
+                                &v.shared().id.renamed
+                            )
+                        });
+                    }
 
                     write!(w, "\tcase {}", swift_keyword_aware_rename(&variant_name))?;
 
+                    let content_key = match variant_content {
+                        VariantContent::Adjacent { content_key, .. } => content_key,
+                        VariantContent::External
+                        | VariantContent::Internal { .. }
+                        | VariantContent::Untagged => &variant_name,
+                    };
+
                     match v {
                         RustEnumVariant::Unit(_) => {
-                            decoding_cases.push(format!(
+                            let decoding_case = format!(
                                 "
 			case .{case_name}:
 				self = .{case_name}
 				return",
                                 case_name = &variant_name,
-                            ));
+                            );
 
-                            encoding_cases.push(format!(
-                                "
+                            match variant_content {
+                                VariantContent::Adjacent { tag_key, .. }
+                                | VariantContent::Internal { tag_key } => {
+                                    decoding_cases.push(decoding_case);
+                                    encoding_cases.push(format!(
+                                        "
 		case .{case_name}:
 			try container.encode(CodingKeys.{case_name}, forKey: .{tag_key})",
-                                tag_key = tag_key,
-                                case_name = swift_keyword_aware_rename(&variant_name),
-                            ));
+                                        tag_key = tag_key,
+                                        case_name = swift_keyword_aware_rename(&variant_name),
+                                    ));
+                                }
+                                VariantContent::External => {
+                                    unit_decoding_cases.push(decoding_case);
+                                    encoding_cases.push(format!(
+                                        "
+		case .{case_name}:
+			var container = encoder.singleValueContainer()
+			try container.encode(CodingKeys.{case_name})",
+                                        case_name = swift_keyword_aware_rename(&variant_name),
+                                    ));
+                                }
+                                // Unit variants are serialized as `null`
+                                VariantContent::Untagged => {
+                                    decoding_cases.push(format!(
+                                        "
+		if container.decodeNil() {{
+			self = .{case_name}
+			return
+		}}",
+                                        case_name = &variant_name,
+                                    ));
+                                    encoding_cases.push(format!(
+                                        "
+		case .{case_name}:
+			try container.encodeNil()",
+                                        case_name = swift_keyword_aware_rename(&variant_name),
+                                    ));
+                                }
+                            }
                         }
                         RustEnumVariant::Tuple { ty, .. } => {
-                            let content_optional = ty.is_optional();
                             let case_type = self
                                 .format_type(ty, e.shared().generic_types.as_slice())
                                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
                             write!(w, "({})", swift_keyword_aware_rename(&case_type))?;
 
-                            if content_optional {
+                            // A map shares its object with the tag, which it must leave out
+                            if let (
+                                VariantContent::Internal { tag_key },
+                                RustType::Special(
+                                    SpecialRustType::HashMap(key, value)
+                                    | SpecialRustType::OrderedMap(key, value),
+                                ),
+                            ) = (variant_content, ty)
+                            {
+                                if !matches!(
+                                    key.as_ref(),
+                                    RustType::Special(SpecialRustType::String)
+                                ) {
+                                    return Err(io::Error::other(format!(
+                                        "Map of variant `{}` must have `String` keys in Swift",
+                                        v.shared().id.original
+                                    )));
+                                }
+                                let value_ty = self
+                                    .format_type(value, e.shared().generic_types.as_slice())
+                                    .map_err(io::Error::other)?;
                                 decoding_cases.push(format!(
                                     "
-            case .{case_name}:
-				if let content = try? container.decode({case_type}.self, forKey: .{content_key}) {{
-					self = .{case_name}(content)
-					return
-				}}
-				else if let isNil = try? container.decodeNil(forKey: .{content_key}), isNil {{
-					self = .{case_name}(nil)
-					return
-				}}",
-                                    content_key = content_key,
-                                    case_type = swift_keyword_aware_rename(&case_type),
-                                    case_name = &variant_name
-                                ))
-                            } else {
-                                decoding_cases.push(format!(
+			case .{variant_name}:
+				let rest = try decoder.container(keyedBy: AnyKey.self)
+				let content = try Dictionary(uniqueKeysWithValues: rest.allKeys.filter {{ $0.stringValue != {tag_key:?} }}.map {{ ($0.stringValue, try rest.decode({value_ty}.self, forKey: $0)) }})
+				self = .{variant_name}(content)
+				return"
+                                ));
+                                encoding_cases.push(format!(
                                     "
-			case .{case_name}:
-				if let content = try? container.decode({case_type}.self, forKey: .{content_key}) {{
-					self = .{case_name}(content)
-					return
-				}}",
-                                    content_key = content_key,
-                                    case_type = swift_keyword_aware_rename(&case_type),
-                                    case_name = &variant_name,
+		case .{variant_name}(let content):
+			try container.encode(CodingKeys.{variant_name}, forKey: .{tag_key})
+			var rest = encoder.container(keyedBy: AnyKey.self)
+			for (key, value) in content {{
+				try rest.encode(value, forKey: AnyKey(stringValue: key))
+			}}"
+                                ));
+                            } else {
+                                decoding_cases.push(decode_content_case(
+                                    variant_content,
+                                    &swift_keyword_aware_rename(&case_type),
+                                    content_key,
+                                    &variant_name,
                                 ));
-                            }
 
-                            encoding_cases.push(format!(
-                                "
-		case .{case_name}(let content):
-			try container.encode(CodingKeys.{case_name}, forKey: .{tag_key})
-			try container.encode(content, forKey: .{content_key})",
-                                tag_key = tag_key,
-                                content_key = content_key,
-                                case_name = &variant_name,
-                            ));
+                                encoding_cases
+                                    .push(encode_content_case(variant_content, &variant_name));
+                            }
                         }
                         RustEnumVariant::AnonymousStruct { shared, fields } => {
                             let anonymous_struct_name = format!(
@@ -692,28 +1305,15 @@ impl Swift {
 
                             write!(w, "({}{})", &anonymous_struct_name, generic_types)?;
 
-                            decoding_cases.push(format!(
-                                "
-			case .{case_name}:
-				if let content = try? container.decode({case_type}{generic_list}.self, forKey: .{content_key}) {{
-					self = .{case_name}(content)
-					return
-				}}",
-                                content_key = content_key,
-                                case_type = &anonymous_struct_name,
-                                case_name = &variant_name,
-                                generic_list = &generic_types,
+                            decoding_cases.push(decode_content_case(
+                                variant_content,
+                                &format!("{anonymous_struct_name}{generic_types}"),
+                                content_key,
+                                &variant_name,
                             ));
 
-                            encoding_cases.push(format!(
-                                "
-		case .{case_name}(let content):
-			try container.encode(CodingKeys.{case_name}, forKey: .{tag_key})
-			try container.encode(content, forKey: .{content_key})",
-                                tag_key = tag_key,
-                                content_key = content_key,
-                                case_name = &variant_name,
-                            ));
+                            encoding_cases
+                                .push(encode_content_case(variant_content, &variant_name));
                         }
                     }
 
@@ -723,9 +1323,11 @@ impl Swift {
         }
 
         Ok(CodingKeysInfo {
+            unit_decoding_cases,
             decoding_cases,
             encoding_cases,
             coding_keys,
+            other_case,
         })
     }
 
@@ -769,6 +1371,12 @@ impl Swift {
         self.write_codable(&mut w, &output_string)
     }
 
+    /// Whether any of the `Codable` helper types need to be written.
+    fn should_emit_codable(&self) -> bool {
+        self.should_emit_codable_void.load(Ordering::SeqCst)
+            || self.should_emit_codable_json.load(Ordering::SeqCst)
+    }
+
     fn get_codable_contents(&self) -> String {
         let mut decs = self
             .get_default_decorators()
@@ -780,7 +1388,124 @@ impl Swift {
             decs.push(CODABLE);
         }
 
-        format!("\n/// () isn't codable, so we use this instead to represent Rust's unit type\npublic struct CodableVoid: {} {{}}", decs.join(", "))
+        let mut contents = String::new();
+
+        if self.should_emit_codable_void.load(Ordering::SeqCst) {
+            contents.push_str(&format!("\n/// () isn't codable, so we use this instead to represent Rust's unit type\npublic struct CodableVoid: {} {{}}", decs.join(", ")));
+        }
+
+        if self.should_emit_codable_json.load(Ordering::SeqCst) {
+            contents.push_str(&self.get_codable_json_contents());
+        }
+
+        contents
+    }
+
+    /// The `CodableJSON` type holds the raw JSON of `serde(other)` variants.
+    fn get_codable_json_contents(&self) -> String {
+        let decs = self.get_default_decorators().collect::<Vec<_>>();
+
+        format!(
+            r#"
+/// Holds the raw JSON of a value that doesn't match any known variant
+public enum CodableJSON: {decs} {{
+	case null
+	case bool(Bool)
+	case number(Double)
+	case string(String)
+	case array([CodableJSON])
+	case object([String: CodableJSON])
+
+	private struct Key: CodingKey {{
+		var stringValue: String
+		var intValue: Int? {{ nil }}
+
+		init(stringValue: String) {{
+			self.stringValue = stringValue
+		}}
+
+		init?(intValue: Int) {{
+			return nil
+		}}
+	}}
+
+	public init(from decoder: Decoder) throws {{
+		if let container = try? decoder.container(keyedBy: Key.self) {{
+			var object = [String: CodableJSON]()
+			for key in container.allKeys {{
+				object[key.stringValue] = try container.decode(CodableJSON.self, forKey: key)
+			}}
+			self = .object(object)
+		}} else if var container = try? decoder.unkeyedContainer() {{
+			var array = [CodableJSON]()
+			while !container.isAtEnd {{
+				array.append(try container.decode(CodableJSON.self))
+			}}
+			self = .array(array)
+		}} else {{
+			let container = try decoder.singleValueContainer()
+			if container.decodeNil() {{
+				self = .null
+			}} else if let value = try? container.decode(Bool.self) {{
+				self = .bool(value)
+			}} else if let value = try? container.decode(Double.self) {{
+				self = .number(value)
+			}} else {{
+				self = .string(try container.decode(String.self))
+			}}
+		}}
+	}}
+
+	public func encode(to encoder: Encoder) throws {{
+		switch self {{
+		case .object(let object):
+			var container = encoder.container(keyedBy: Key.self)
+			for (key, value) in object {{
+				try container.encode(value, forKey: Key(stringValue: key))
+			}}
+		case .array(let array):
+			var container = encoder.unkeyedContainer()
+			try container.encode(contentsOf: array)
+		case .null:
+			var container = encoder.singleValueContainer()
+			try container.encodeNil()
+		case .bool(let value):
+			var container = encoder.singleValueContainer()
+			try container.encode(value)
+		case .number(let value):
+			var container = encoder.singleValueContainer()
+			try container.encode(value)
+		case .string(let value):
+			var container = encoder.singleValueContainer()
+			try container.encode(value)
+		}}
+	}}
+}}"#,
+            decs = decs.join(", ")
+        )
+    }
+
+    /// Write an `@available` attribute marking a deprecated item, so the Swift
+    /// compiler warns about its uses.
+    fn write_deprecation(
+        &self,
+        w: &mut dyn Write,
+        indent: usize,
+        deprecated: &Option<Deprecation>,
+    ) -> io::Result<()> {
+        let Some(deprecated) = deprecated else {
+            return Ok(());
+        };
+
+        match deprecated.message() {
+            Some(message) => writeln!(
+                w,
+                "{}@available(*, deprecated, message: \"{}\")",
+                "\t".repeat(indent),
+                escape_string_literal(&message)
+            ),
+            None => writeln!(w, "{}@available(*, deprecated)", "\t".repeat(indent)),
+        }
     }
 
     /// Write the `CodableVoid` type.
@@ -836,6 +1561,106 @@ impl Swift {
     }
 }
 
+/// The end of an enum's decoder, reached when no known variant matched.
+fn decoding_fallback(type_name: &str, other_case: Option<&str>) -> String {
+    match other_case {
+        Some(other_case) => format!("self = .{other_case}(try CodableJSON(from: decoder))"),
+        None => format!(
+            r#"throw DecodingError.typeMismatch({type_name}.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Wrong type for {type_name}"))"#
+        ),
+    }
+}
+
+/// Writes a coding key that matches any key of a JSON object.
+fn write_any_key(w: &mut dyn Write) -> io::Result<()> {
+    writeln!(w, "\tprivate struct AnyKey: CodingKey {{")?;
+    writeln!(w, "\t\tvar stringValue: String")?;
+    writeln!(w, "\t\tvar intValue: Int? {{ nil }}")?;
+    writeln!(w)?;
+    writeln!(w, "\t\tinit(stringValue: String) {{")?;
+    writeln!(w, "\t\t\tself.stringValue = stringValue")?;
+    writeln!(w, "\t\t}}")?;
+    writeln!(w)?;
+    writeln!(w, "\t\tinit?(intValue: Int) {{")?;
+    writeln!(w, "\t\t\treturn nil")?;
+    writeln!(w, "\t\t}}")?;
+    writeln!(w, "\t}}")
+}
+
+/// Decoding case of an enum variant with content.
+fn decode_content_case(
+    variant_content: VariantContent,
+    case_type: &str,
+    content_key: &str,
+    case_name: &str,
+) -> String {
+    // The tag is known at this point, so a malformed payload is an error rather
+    // than a reason to try the next variant
+    let decode_content = match variant_content {
+        VariantContent::Adjacent { .. } | VariantContent::External => {
+            format!("try container.decode({case_type}.self, forKey: .{content_key})")
+        }
+        VariantContent::Internal { .. } => format!("try {case_type}(from: decoder)"),
+        // Each variant is tried in turn rather than switching on a tag
+        VariantContent::Untagged => {
+            return format!(
+                "
+		if let content = try? container.decode({case_type}.self) {{
+			self = .{case_name}(content)
+			return
+		}}"
+            )
+        }
+    };
+
+    format!(
+        "
+			case .{case_name}:
+				let content = {decode_content}
+				self = .{case_name}(content)
+				return"
+    )
+}
+
+/// Encoding of an enum variant with content.
+fn encode_content_case(variant_content: VariantContent, case_name: &str) -> String {
+    match variant_content {
+        VariantContent::Adjacent {
+            tag_key,
+            content_key,
+        } => format!(
+            "
+		case .{case_name}(let content):
+			try container.encode(CodingKeys.{case_name}, forKey: .{tag_key})
+			try container.encode(content, forKey: .{content_key})"
+        ),
+        VariantContent::External => format!(
+            "
+		case .{case_name}(let content):
+			var container = encoder.container(keyedBy: CodingKeys.self)
+			try container.encode(content, forKey: .{case_name})"
+        ),
+        VariantContent::Internal { tag_key } => format!(
+            "
+		case .{case_name}(let content):
+			try container.encode(CodingKeys.{case_name}, forKey: .{tag_key})
+			try content.encode(to: encoder)"
+        ),
+        VariantContent::Untagged => format!(
+            "
+		case .{case_name}(let content):
+			try container.encode(content)"
+        ),
+    }
+}
+
+/// The names a field is deserialized from other than its serialized name.
+fn alias_names(f: &RustField) -> impl Iterator<Item = &str> {
+    f.id.deserialize_names()
+        .into_iter()
+        .filter(move |name| *name != f.id.renamed)
+}
+
 fn swift_keyword_aware_rename<'a, T>(name: T) -> Cow<'a, str>
 where
     T: Into<Cow<'a, str>>,
@@ -847,3 +1672,63 @@ where
         name
     }
 }
+
+/// The raw value type of a numeric enum with the given representation.
+///
+/// Discriminants are always serialized as numbers, whatever the 64 bit integer strategy.
+fn numeric_raw_type(repr: &SpecialRustType) -> &'static str {
+    match repr {
+        SpecialRustType::I8 => "Int8",
+        SpecialRustType::U8 => "UInt8",
+        SpecialRustType::I16 => "Int16",
+        SpecialRustType::U16 => "UInt16",
+        SpecialRustType::I32 => "Int32",
+        SpecialRustType::U32 => "UInt32",
+        SpecialRustType::I64 => "Int64",
+        SpecialRustType::U64 => "UInt64",
+        SpecialRustType::USize => "UInt",
+        _ => "Int",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+    use super::Swift;
+    use crate::language::{CrateName, Language};
+
+    #[test]
+    fn imports_modules_of_other_crates() {
+        let mut swift = Swift {
+            no_version_header: true,
+            multi_file: true,
+            module_names: HashMap::from([("core_types".into(), "CoreKit".into())]),
+            codable_module: Some("CoreKit".into()),
+            ..Default::default()
+        };
+        let (core_types, user_types, billing_types) = (
+            CrateName::from("core_types"),
+            CrateName::from("user_types"),
+            CrateName::from("billing_types"),
+        );
+        let imports = BTreeMap::from([
+            (&core_types, BTreeSet::from(["Id"])),
+            (&billing_types, BTreeSet::from(["Invoice", "Plan"])),
+        ]);
+
+        let mut out = Vec::new();
+        swift.write_imports(&mut out, &user_types, imports).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "import BillingTypes\nimport CoreKit\n"
+        );
+
+        // The module of the file's own crate isn't imported
+        let mut out = Vec::new();
+        swift
+            .write_imports(&mut out, &core_types, BTreeMap::new())
+            .unwrap();
+        assert!(out.is_empty());
+    }
+}
@@ -1,9 +1,11 @@
 use crate::{
-    language::{Language, SupportedLanguage},
+    language::{
+        escape_string_literal, is_int64, CrateName, Int64Strategy, Language, SupportedLanguage,
+    },
     parser::ParsedData,
     rust_types::{
-        RustEnum, RustEnumVariant, RustField, RustStruct, RustType, RustTypeAlias,
-        RustTypeFormatError, SpecialRustType,
+        Deprecation, RustConst, RustConstExpr, RustEnum, RustEnumVariant, RustField, RustStruct,
+        RustType, RustTypeAlias, RustTypeFormatError, SpecialRustType,
     },
 };
 use itertools::Itertools;
@@ -16,13 +18,25 @@ use std::{
 use super::ScopedCrateTypes;
 
 /// All information needed to generate Typescript type-code
-#[derive(Default)]
 pub struct TypeScript {
     /// Mappings from Rust type names to Typescript type names
     pub type_mappings: HashMap<String, String>,
     /// Whether or not to exclude the version header that normally appears at the top of generated code.
     /// If you aren't generating a snapshot test, this setting can just be left as a default (false)
     pub no_version_header: bool,
+    /// How 64 bit integers are represented. Rejected by default, since `number` can't
+    /// represent all of their values.
+    pub int64_strategy: Int64Strategy,
+}
+
+impl Default for TypeScript {
+    fn default() -> Self {
+        Self {
+            type_mappings: HashMap::new(),
+            no_version_header: false,
+            int64_strategy: Int64Strategy::Reject,
+        }
+    }
 }
 
 impl Language for TypeScript {
@@ -36,7 +50,8 @@ impl Language for TypeScript {
         generic_types: &[String],
     ) -> Result<String, RustTypeFormatError> {
         match special_ty {
-            SpecialRustType::Vec(rtype) => {
+            // Sets are serialized as arrays, which `JSON.parse` won't turn into a `Set`
+            SpecialRustType::Vec(rtype) | SpecialRustType::Set(rtype) => {
                 Ok(format!("{}[]", self.format_type(rtype, generic_types)?))
             }
             SpecialRustType::Array(rtype, len) => {
@@ -53,7 +68,8 @@ impl Language for TypeScript {
             }
             // We add optionality above the type formatting level
             SpecialRustType::Option(rtype) => self.format_type(rtype, generic_types),
-            SpecialRustType::HashMap(rtype1, rtype2) => Ok(format!(
+            SpecialRustType::HashMap(rtype1, rtype2)
+            | SpecialRustType::OrderedMap(rtype1, rtype2) => Ok(format!(
                 "Record<{}, {}>",
                 match rtype1.as_ref() {
                     RustType::Simple { id } if generic_types.contains(id) => {
@@ -63,6 +79,21 @@ impl Language for TypeScript {
                 },
                 self.format_type(rtype2, generic_types)?
             )),
+            SpecialRustType::Tuple(rtypes) => Ok(format!(
+                "[{}]",
+                rtypes
+                    .iter()
+                    .map(|rtype| {
+                        let formatted_type = self.format_type(rtype, generic_types)?;
+                        Ok(if rtype.is_optional() {
+                            format!("{formatted_type} | undefined")
+                        } else {
+                            formatted_type
+                        })
+                    })
+                    .collect::<Result<Vec<_>, RustTypeFormatError>>()?
+                    .join(", ")
+            )),
             SpecialRustType::Unit => Ok("undefined".into()),
             SpecialRustType::String => Ok("string".into()),
             SpecialRustType::Char => Ok("string".into()),
@@ -80,9 +111,14 @@ impl Language for TypeScript {
             SpecialRustType::U64
             | SpecialRustType::I64
             | SpecialRustType::ISize
-            | SpecialRustType::USize => {
-                panic!("64 bit types not allowed in Typeshare")
-            }
+            | SpecialRustType::USize => match self.int64_strategy {
+                Int64Strategy::Reject => {
+                    Err(RustTypeFormatError::Int64Rejected(special_ty.id().into()))
+                }
+                Int64Strategy::Number => Ok("number".into()),
+                Int64Strategy::BigInt => Ok("bigint".into()),
+                Int64Strategy::String => Ok("string".into()),
+            },
         }
     }
 
@@ -97,7 +133,7 @@ impl Language for TypeScript {
     }
 
     fn write_type_alias(&mut self, w: &mut dyn Write, ty: &RustTypeAlias) -> io::Result<()> {
-        self.write_comments(w, 0, &ty.comments)?;
+        self.write_comments(w, 0, &with_deprecation(&ty.comments, &ty.deprecated))?;
 
         let r#type = self
             .format_type(&ty.r#type, ty.generic_types.as_slice())
@@ -120,17 +156,67 @@ impl Language for TypeScript {
         Ok(())
     }
 
+    fn write_const(&mut self, w: &mut dyn Write, c: &RustConst) -> io::Result<()> {
+        self.write_comments(w, 0, &c.comments)?;
+
+        let r#type = self.format_type(&c.r#type, &[]).map_err(io::Error::other)?;
+
+        let value = match &c.expr {
+            RustConstExpr::Int(value) if is_int64(&c.r#type) => match self.int64_strategy {
+                Int64Strategy::BigInt => format!("{value}n"),
+                Int64Strategy::String => format!("\"{value}\""),
+                Int64Strategy::Reject | Int64Strategy::Number => value.to_string(),
+            },
+            RustConstExpr::Int(value) => value.to_string(),
+            RustConstExpr::Float(value) => value.clone(),
+            RustConstExpr::Bool(value) => value.to_string(),
+            RustConstExpr::String(value) => format!("\"{}\"", escape_string_literal(value)),
+        };
+
+        writeln!(
+            w,
+            "export const {}: {} = {};\n",
+            c.id.renamed, r#type, value
+        )
+    }
+
     fn write_struct(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
-        self.write_comments(w, 0, &rs.comments)?;
+        self.write_comments(w, 0, &with_deprecation(&rs.comments, &rs.deprecated))?;
+
+        let generic_parameters = (!rs.generic_types.is_empty())
+            .then(|| format!("<{}>", rs.generic_types.join(", ")))
+            .unwrap_or_default();
+
+        // Flattened fields can't be expressed with an interface, so the struct
+        // becomes an intersection of its own fields and the flattened types.
+        if rs.fields.iter().any(|f| f.flattened) {
+            write!(
+                w,
+                "export type {}{} = {{",
+                rs.id.renamed, generic_parameters
+            )?;
+
+            if rs.tag_key.is_some() || rs.fields.iter().any(|f| !f.flattened) {
+                writeln!(w)?;
+                self.write_tag_field(w, rs)?;
+                rs.fields
+                    .iter()
+                    .filter(|f| !f.flattened)
+                    .try_for_each(|f| self.write_field(w, f, rs.generic_types.as_slice()))?;
+            }
+
+            write!(w, "}}")?;
+            self.write_flattened_types(w, &rs.fields, rs.generic_types.as_slice())?;
+            return writeln!(w, ";\n");
+        }
+
         writeln!(
             w,
             "export interface {}{} {{",
-            rs.id.renamed,
-            (!rs.generic_types.is_empty())
-                .then(|| format!("<{}>", rs.generic_types.join(", ")))
-                .unwrap_or_default()
+            rs.id.renamed, generic_parameters
         )?;
 
+        self.write_tag_field(w, rs)?;
         rs.fields
             .iter()
             .try_for_each(|f| self.write_field(w, f, rs.generic_types.as_slice()))?;
@@ -139,14 +225,23 @@ impl Language for TypeScript {
     }
 
     fn write_enum(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
-        self.write_comments(w, 0, &e.shared().comments)?;
+        self.write_comments(
+            w,
+            0,
+            &with_deprecation(&e.shared().comments, &e.shared().deprecated),
+        )?;
 
         let generic_parameters = (!e.shared().generic_types.is_empty())
             .then(|| format!("<{}>", e.shared().generic_types.join(", ")))
             .unwrap_or_default();
 
+        // A Typescript enum can't hold other values, so unit enums with a
+        // `#[serde(other)]` variant are written as a union of strings instead
+        let accepts_other_values =
+            matches!(e, RustEnum::Unit(shared) if shared.other_variant().is_some());
+
         match e {
-            RustEnum::Unit(shared) => {
+            RustEnum::Unit(shared) | RustEnum::Numeric { shared, .. } if !accepts_other_values => {
                 write!(
                     w,
                     "export enum {}{} {{",
@@ -157,7 +252,12 @@ impl Language for TypeScript {
 
                 writeln!(w, "\n}}\n")
             }
-            RustEnum::Algebraic { shared, .. } => {
+            RustEnum::Unit(shared)
+            | RustEnum::Numeric { shared, .. }
+            | RustEnum::Algebraic { shared, .. }
+            | RustEnum::ExternallyTagged(shared)
+            | RustEnum::InternallyTagged { shared, .. }
+            | RustEnum::Untagged(shared) => {
                 write!(
                     w,
                     "export type {}{} = ",
@@ -176,6 +276,7 @@ impl Language for TypeScript {
     fn write_imports(
         &mut self,
         w: &mut dyn Write,
+        _crate_name: &CrateName,
         imports: ScopedCrateTypes<'_>,
     ) -> std::io::Result<()> {
         for (path, ty) in imports {
@@ -195,16 +296,50 @@ impl Language for TypeScript {
 impl TypeScript {
     fn write_enum_variants(&mut self, w: &mut dyn Write, e: &RustEnum) -> io::Result<()> {
         match e {
+            // Any other value deserializes into the `serde(other)` variant
+            RustEnum::Unit(shared) if shared.other_variant().is_some() => {
+                shared.variants.iter().try_for_each(|v| {
+                    writeln!(w)?;
+                    self.write_comments(
+                        w,
+                        1,
+                        &with_deprecation(&v.shared().comments, &v.shared().deprecated),
+                    )?;
+                    if v.shared().is_other {
+                        write!(w, "\t| string")
+                    } else {
+                        write!(w, "\t| {:?}", v.shared().id.renamed)
+                    }
+                })
+            }
             // Write all the unit variants out (there can only be unit variants in
             // this case)
             RustEnum::Unit(shared) => shared.variants.iter().try_for_each(|v| match v {
                 RustEnumVariant::Unit(shared) => {
                     writeln!(w)?;
-                    self.write_comments(w, 1, &shared.comments)?;
+                    self.write_comments(
+                        w,
+                        1,
+                        &with_deprecation(&shared.comments, &shared.deprecated),
+                    )?;
                     write!(w, "\t{} = {:?},", shared.id.original, &shared.id.renamed)
                 }
                 _ => unreachable!(),
             }),
+            RustEnum::Numeric { shared, .. } => shared.variants.iter().try_for_each(|v| {
+                writeln!(w)?;
+                self.write_comments(
+                    w,
+                    1,
+                    &with_deprecation(&v.shared().comments, &v.shared().deprecated),
+                )?;
+                write!(
+                    w,
+                    "\t{} = {},",
+                    v.shared().id.original,
+                    v.shared().discriminant.unwrap_or_default()
+                )
+            }),
 
             // Write all the algebraic variants out (all three variant types are possible
             // here)
@@ -214,8 +349,18 @@ impl TypeScript {
                 shared,
             } => shared.variants.iter().try_for_each(|v| {
                 writeln!(w)?;
-                self.write_comments(w, 1, &v.shared().comments)?;
+                self.write_comments(
+                    w,
+                    1,
+                    &with_deprecation(&v.shared().comments, &v.shared().deprecated),
+                )?;
                 match v {
+                    // Any other tag deserializes into the `serde(other)` variant
+                    _ if v.shared().is_other => write!(
+                        w,
+                        "\t| {{ {}: string, {}?: unknown }}",
+                        tag_key, content_key
+                    ),
                     RustEnumVariant::Unit(shared) => write!(
                         w,
                         "\t| {{ {}: {:?}, {}?: undefined }}",
@@ -242,15 +387,138 @@ impl TypeScript {
                             tag_key, shared.id.renamed, content_key
                         )?;
 
-                        fields.iter().try_for_each(|f| {
+                        fields.iter().filter(|f| !f.flattened).try_for_each(|f| {
                             self.write_field(w, f, e.shared().generic_types.as_slice())
                         })?;
 
                         write!(w, "}}")?;
+                        self.write_flattened_types(w, fields, e.shared().generic_types.as_slice())?;
                         write!(w, "}}")
                     }
                 }
             }),
+
+            // Unit variants are written as a string literal, all the other
+            // variants as an object with the variant name as its only key
+            RustEnum::ExternallyTagged(shared) => shared.variants.iter().try_for_each(|v| {
+                writeln!(w)?;
+                self.write_comments(
+                    w,
+                    1,
+                    &with_deprecation(&v.shared().comments, &v.shared().deprecated),
+                )?;
+                match v {
+                    _ if v.shared().is_other => write!(w, "\t| string"),
+                    RustEnumVariant::Unit(shared) => write!(w, "\t| {:?}", shared.id.renamed),
+                    RustEnumVariant::Tuple { ty, shared } => {
+                        let r#type = self
+                            .format_type(ty, e.shared().generic_types.as_slice())
+                            .map_err(io::Error::other)?;
+                        // serde always writes the key of the variant, with
+                        // `null` for a `None` payload
+                        write!(
+                            w,
+                            "\t| {{ {}: {}{} }}",
+                            typescript_property_aware_rename(&shared.id.renamed),
+                            r#type,
+                            if ty.is_optional() { " | null" } else { "" }
+                        )
+                    }
+                    RustEnumVariant::AnonymousStruct { fields, shared } => {
+                        writeln!(
+                            w,
+                            "\t| {{ {}: {{",
+                            typescript_property_aware_rename(&shared.id.renamed)
+                        )?;
+
+                        fields.iter().filter(|f| !f.flattened).try_for_each(|f| {
+                            self.write_field(w, f, e.shared().generic_types.as_slice())
+                        })?;
+
+                        write!(w, "}}")?;
+                        self.write_flattened_types(w, fields, e.shared().generic_types.as_slice())?;
+                        write!(w, "}}")
+                    }
+                }
+            }),
+
+            // The tag is merged into the object the variant serializes to
+            RustEnum::InternallyTagged { tag_key, shared } => {
+                shared.variants.iter().try_for_each(|v| {
+                    writeln!(w)?;
+                    self.write_comments(
+                        w,
+                        1,
+                        &with_deprecation(&v.shared().comments, &v.shared().deprecated),
+                    )?;
+                    if v.shared().is_other {
+                        return write!(
+                            w,
+                            "\t| {{ {}: string }}",
+                            typescript_property_aware_rename(tag_key)
+                        );
+                    }
+                    write!(
+                        w,
+                        "\t| {{ {}: {:?} }}",
+                        typescript_property_aware_rename(tag_key),
+                        v.shared().id.renamed
+                    )?;
+                    match v {
+                        RustEnumVariant::Unit(_) => Ok(()),
+                        RustEnumVariant::Tuple { ty, .. } => {
+                            let r#type = self
+                                .format_type(ty, e.shared().generic_types.as_slice())
+                                .map_err(io::Error::other)?;
+                            write!(w, " & {}", r#type)
+                        }
+                        RustEnumVariant::AnonymousStruct { fields, .. } => {
+                            writeln!(w, " & {{")?;
+
+                            fields.iter().filter(|f| !f.flattened).try_for_each(|f| {
+                                self.write_field(w, f, e.shared().generic_types.as_slice())
+                            })?;
+
+                            write!(w, "}}")?;
+                            self.write_flattened_types(
+                                w,
+                                fields,
+                                e.shared().generic_types.as_slice(),
+                            )
+                        }
+                    }
+                })
+            }
+
+            // Only the content of each variant is serialized, unit variants
+            // as `null`
+            RustEnum::Untagged(shared) => shared.variants.iter().try_for_each(|v| {
+                writeln!(w)?;
+                self.write_comments(
+                    w,
+                    1,
+                    &with_deprecation(&v.shared().comments, &v.shared().deprecated),
+                )?;
+                match v {
+                    RustEnumVariant::Unit(_) => write!(w, "\t| null"),
+                    RustEnumVariant::Tuple { ty, .. } => {
+                        let r#type = self
+                            .format_type(ty, e.shared().generic_types.as_slice())
+                            .map_err(io::Error::other)?;
+                        write!(w, "\t| {}", r#type)
+                    }
+                    RustEnumVariant::AnonymousStruct { fields, .. } => {
+                        writeln!(w, "\t| {{")?;
+
+                        fields.iter().filter(|f| !f.flattened).try_for_each(|f| {
+                            self.write_field(w, f, e.shared().generic_types.as_slice())
+                        })?;
+
+                        write!(w, "}}")?;
+                        self.write_flattened_types(w, fields, e.shared().generic_types.as_slice())
+                    }
+                }
+            }),
         }
     }
 
@@ -260,7 +528,22 @@ impl TypeScript {
         field: &RustField,
         generic_types: &[String],
     ) -> io::Result<()> {
-        self.write_comments(w, 1, &field.comments)?;
+        if field.id.has_deserialize_aliases() {
+            // Typescript has no deserializer to tell about other names, so document them
+            let mut comments = field.comments.clone();
+            comments.push(format!(
+                "Deserialized from {}",
+                field
+                    .id
+                    .deserialize_names()
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .join(" or ")
+            ));
+            self.write_comments(w, 1, &with_deprecation(&comments, &field.deprecated))?;
+        } else {
+            self.write_comments(w, 1, &with_deprecation(&field.comments, &field.deprecated))?;
+        }
         let ts_ty: String = match field.type_override(SupportedLanguage::TypeScript) {
             Some(type_override) => type_override.to_owned(),
             None => self
@@ -268,7 +551,7 @@ impl TypeScript {
                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
         };
 
-        let optional = field.ty.is_optional() || field.has_default;
+        let optional = field.ty.is_optional() || field.may_be_absent();
         let double_optional = field.ty.is_double_optional();
         let is_readonly = field
             .decorators
@@ -288,6 +571,46 @@ impl TypeScript {
         Ok(())
     }
 
+    /// Write the field that serde adds to a struct with `#[serde(tag)]`, whose
+    /// value is always the name of the struct.
+    fn write_tag_field(&mut self, w: &mut dyn Write, rs: &RustStruct) -> io::Result<()> {
+        match &rs.tag_key {
+            Some(tag_key) => writeln!(
+                w,
+                "\t{}: {:?};",
+                typescript_property_aware_rename(tag_key),
+                rs.id.renamed
+            ),
+            None => Ok(()),
+        }
+    }
+
+    /// Write an intersection with the type of each flattened field. A flattened
+    /// field that may be missing makes all of its type's fields optional.
+    fn write_flattened_types(
+        &mut self,
+        w: &mut dyn Write,
+        fields: &[RustField],
+        generic_types: &[String],
+    ) -> io::Result<()> {
+        for field in fields.iter().filter(|f| f.flattened) {
+            let ts_ty = match field.type_override(SupportedLanguage::TypeScript) {
+                Some(type_override) => type_override.to_owned(),
+                None => self
+                    .format_type(&field.ty, generic_types)
+                    .map_err(io::Error::other)?,
+            };
+
+            if field.ty.is_optional() || field.may_be_absent() {
+                write!(w, " & Partial<{ts_ty}>")?;
+            } else {
+                write!(w, " & {ts_ty}")?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn write_comments(
         &mut self,
         w: &mut dyn Write,
@@ -318,6 +641,19 @@ impl TypeScript {
     }
 }
 
+/// Adds a JSDoc `@deprecated` tag to the comments of a deprecated item, so
+/// editors and linters flag its uses.
+fn with_deprecation(comments: &[String], deprecated: &Option<Deprecation>) -> Vec<String> {
+    let mut comments = comments.to_vec();
+    if let Some(deprecated) = deprecated {
+        comments.push(match deprecated.message() {
+            Some(message) => format!("@deprecated {message}"),
+            None => "@deprecated".to_owned(),
+        });
+    }
+    comments
+}
+
 fn typescript_property_aware_rename(name: &str) -> String {
     if name.chars().any(|c| c == '-') {
         return format!("{:?}", name);
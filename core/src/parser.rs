@@ -2,25 +2,26 @@ use crate::{
     language::{CrateName, SupportedLanguage},
     rename::RenameExt,
     rust_types::{
-        DecoratorMap, FieldDecorator, Id, RustEnum, RustEnumShared, RustEnumVariant,
-        RustEnumVariantShared, RustField, RustItem, RustStruct, RustType, RustTypeAlias,
-        RustTypeParseError,
+        DecoratorMap, Deprecation, FieldDecorator, Id, RustConst, RustConstExpr, RustEnum,
+        RustEnumShared, RustEnumVariant, RustEnumVariantShared, RustField, RustItem, RustStruct,
+        RustType, RustTypeAlias, RustTypeParseError, SpecialRustType,
     },
     target_os_check::accept_target_os,
     visitors::{ImportedType, TypeShareVisitor},
 };
 use itertools::Either;
 use log::debug;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span};
+use quote::ToTokens;
 use std::{
-    collections::{BTreeSet, HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     convert::TryFrom,
     path::PathBuf,
 };
 use syn::{
-    ext::IdentExt, parse::ParseBuffer, punctuated::Punctuated, visit::Visit, Attribute, Expr,
-    ExprLit, Fields, GenericParam, ItemEnum, ItemStruct, ItemType, LitStr, Meta, MetaList,
-    MetaNameValue, Token,
+    ext::IdentExt, parse::ParseBuffer, punctuated::Punctuated, spanned::Spanned, visit::Visit,
+    Attribute, Expr, ExprLit, ExprUnary, Fields, GenericParam, ItemConst, ItemEnum, ItemStruct,
+    ItemType, Lit, LitStr, Meta, MetaList, MetaNameValue, Token, UnOp,
 };
 use thiserror::Error;
 
@@ -61,20 +62,88 @@ pub enum ParseError {
     UnsupportedLanguage(String),
     #[error("unsupported type encountered: {0}")]
     UnsupportedType(String),
-    #[error("tuple structs with more than one field are currently unsupported")]
-    ComplexTupleStruct,
-    #[error("multiple unnamed associated types are not currently supported")]
-    MultipleUnnamedAssociatedTypes,
     #[error("the serde tag attribute is not supported for non-algebraic enums: {enum_ident}")]
     SerdeTagNotAllowed { enum_ident: String },
     #[error("the serde content attribute is not supported for non-algebraic enums: {enum_ident}")]
     SerdeContentNotAllowed { enum_ident: String },
     #[error("serde tag attribute needs to be specified for algebraic enum {enum_ident}. e.g. #[serde(tag = \"type\", content = \"content\")]")]
     SerdeTagRequired { enum_ident: String },
-    #[error("serde content attribute needs to be specified for algebraic enum {enum_ident}. e.g. #[serde(tag = \"type\", content = \"content\")]")]
-    SerdeContentRequired { enum_ident: String },
-    #[error("the serde flatten attribute is not currently supported")]
-    SerdeFlattenNotAllowed,
+    #[error("internally tagged enum {enum_ident} has a tuple variant {variant_ident} that does not wrap a struct or a map")]
+    SerdeInternallyTaggedTupleNotAllowed {
+        enum_ident: String,
+        variant_ident: String,
+    },
+    #[error("serde_repr enum {enum_ident} needs an integer #[repr] attribute")]
+    SerdeReprWithoutIntegerRepr { enum_ident: String },
+    #[error("serde_repr enum {enum_ident} can only have unit variants")]
+    SerdeReprNonUnitVariant { enum_ident: String },
+    #[error("the discriminant of variant {variant_ident} in enum {enum_ident} must be an integer literal")]
+    UnsupportedDiscriminant {
+        enum_ident: String,
+        variant_ident: String,
+    },
+    #[error("the constant {const_ident} must be assigned a literal value")]
+    ConstNotLiteral { const_ident: String },
+    #[error("the constant {const_ident} has unsupported type {const_type}: only numbers, booleans and strings are supported")]
+    UnsupportedConstType {
+        const_ident: String,
+        const_type: String,
+    },
+    #[error("unknown argument `{0}` in #[typeshare] attribute")]
+    UnknownAttributeArgument(String),
+    #[error("the `{argument}` argument of #[typeshare] must be {expected}")]
+    InvalidAttributeArgument {
+        argument: String,
+        expected: &'static str,
+    },
+}
+
+impl ParseError {
+    /// A stable code identifying the kind of error, for tools that consume
+    /// typeshare's diagnostics. Codes are never reused for another kind of error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseError::SynError(_) => "E0001",
+            ParseError::RustTypeParseError(_) => "E0002",
+            ParseError::UnsupportedLanguage(_) => "E0003",
+            ParseError::UnsupportedType(_) => "E0004",
+            ParseError::SerdeTagNotAllowed { .. } => "E0005",
+            ParseError::SerdeContentNotAllowed { .. } => "E0006",
+            ParseError::SerdeTagRequired { .. } => "E0007",
+            ParseError::SerdeInternallyTaggedTupleNotAllowed { .. } => "E0008",
+            ParseError::SerdeReprWithoutIntegerRepr { .. } => "E0009",
+            ParseError::SerdeReprNonUnitVariant { .. } => "E0010",
+            ParseError::UnsupportedDiscriminant { .. } => "E0011",
+            ParseError::ConstNotLiteral { .. } => "E0012",
+            ParseError::UnsupportedConstType { .. } => "E0013",
+            ParseError::UnknownAttributeArgument(_) => "E0014",
+            ParseError::InvalidAttributeArgument { .. } => "E0015",
+        }
+    }
+}
+
+/// Problems found while parsing Rust source input that don't stop types from
+/// being generated.
+#[derive(Debug, Error)]
+#[allow(missing_docs)]
+pub enum ParseWarning {
+    #[error("{type_ident} is serialized as another type by serde than `serialized_as = {serialized_as:?}`")]
+    SerializedAsMismatch {
+        type_ident: String,
+        serialized_as: String,
+    },
+    #[error("{type_ident} is deserialized from `{from}` but serialized as another type")]
+    SerdeConversionMismatch { type_ident: String, from: String },
+}
+
+impl ParseWarning {
+    /// A stable code identifying the kind of warning, like [`ParseError::code`].
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParseWarning::SerializedAsMismatch { .. } => "W0001",
+            ParseWarning::SerdeConversionMismatch { .. } => "W0002",
+        }
+    }
 }
 
 /// Error with it's related data.
@@ -84,6 +153,112 @@ pub struct ErrorInfo {
     pub file_name: String,
     /// The parse error.
     pub error: ParseError,
+    /// Where the error was found in the file.
+    pub location: Option<SourceLocation>,
+    /// The type the error was found in.
+    pub type_name: Option<String>,
+    /// The field or variant the error was found in.
+    pub field_name: Option<String>,
+}
+
+/// Warning with it's related data.
+#[derive(Debug)]
+pub struct WarningInfo {
+    /// The file name being parsed.
+    pub file_name: String,
+    /// The parse warning.
+    pub warning: ParseWarning,
+    /// Where the warning was found in the file.
+    pub location: Option<SourceLocation>,
+    /// The type the warning was found in.
+    pub type_name: Option<String>,
+}
+
+/// The location of some Rust code in its source file. Lines and columns start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// The line the code starts on.
+    pub line: usize,
+    /// The column the code starts at.
+    pub column: usize,
+    /// The line the code ends on.
+    pub end_line: usize,
+    /// The column just past the end of the code.
+    pub end_column: usize,
+}
+
+impl From<Span> for SourceLocation {
+    fn from(span: Span) -> Self {
+        let (start, end) = (span.start(), span.end());
+        Self {
+            line: start.line,
+            column: start.column + 1,
+            end_line: end.line,
+            end_column: end.column + 1,
+        }
+    }
+}
+
+/// A [`ParseError`] along with the span of the Rust code it was found in,
+/// before it is known which file it is in.
+#[derive(Debug)]
+pub(crate) struct SpannedParseError {
+    pub(crate) error: ParseError,
+    /// The span of the field or variant the error was found in, if any.
+    pub(crate) span: Option<Span>,
+    /// The name of the field or variant the error was found in.
+    pub(crate) field_name: Option<String>,
+}
+
+impl From<ParseError> for SpannedParseError {
+    fn from(error: ParseError) -> Self {
+        Self {
+            error,
+            span: None,
+            field_name: None,
+        }
+    }
+}
+
+impl SpannedParseError {
+    fn new(error: ParseError, span: Span) -> Self {
+        Self {
+            error,
+            span: Some(span),
+            field_name: None,
+        }
+    }
+
+    /// Attribute the error to a field, unless it already is to a nested one.
+    /// Errors that already point at something in the field keep their span.
+    fn in_field(self, f: &syn::Field, index: usize) -> Self {
+        let name = f
+            .ident
+            .as_ref()
+            .map_or_else(|| index.to_string(), |ident| ident.unraw().to_string());
+        self.in_member(name, f.ty.span())
+    }
+
+    /// Attribute the error to an enum variant, unless it already is to one of its fields.
+    fn in_variant(self, v: &syn::Variant) -> Self {
+        self.in_member(v.ident.to_string(), v.ident.span())
+    }
+
+    /// Attribute the error to the variant of `e` named `ident`, if it has one.
+    fn in_variant_named(self, e: &ItemEnum, ident: &str) -> Self {
+        match e.variants.iter().find(|v| v.ident == ident) {
+            Some(v) => self.in_variant(v),
+            None => self,
+        }
+    }
+
+    fn in_member(mut self, name: String, span: Span) -> Self {
+        if self.field_name.is_none() {
+            self.field_name = Some(name);
+            self.span = self.span.or(Some(span));
+        }
+        self
+    }
 }
 
 /// The results of parsing Rust source input.
@@ -91,10 +266,14 @@ pub struct ErrorInfo {
 pub struct ParsedData {
     /// Structs defined in the source
     pub structs: BTreeSet<RustStruct>,
+    /// Structs defined in other crates, which flattened fields may refer to
+    pub external_structs: BTreeMap<CrateName, BTreeSet<RustStruct>>,
     /// Enums defined in the source
     pub enums: BTreeSet<RustEnum>,
     /// Type aliases defined in the source
     pub aliases: BTreeSet<RustTypeAlias>,
+    /// Constants defined in the source
+    pub consts: BTreeSet<RustConst>,
     /// Imports used by this file
     pub import_types: HashSet<ImportedType>,
     /// Crate this belongs to.
@@ -105,6 +284,8 @@ pub struct ParsedData {
     pub type_names: HashSet<String>,
     /// Failures during parsing.
     pub errors: Vec<ErrorInfo>,
+    /// Problems during parsing that didn't stop types from being generated.
+    pub warnings: Vec<WarningInfo>,
     /// Using multi file support.
     pub multi_file: bool,
 }
@@ -123,11 +304,14 @@ impl ParsedData {
     /// Add the parsed data from `other` to `self`.
     pub fn add(&mut self, mut other: Self) {
         self.structs.append(&mut other.structs);
+        self.external_structs.append(&mut other.external_structs);
         self.enums.append(&mut other.enums);
         self.aliases.append(&mut other.aliases);
+        self.consts.append(&mut other.consts);
         self.import_types.extend(other.import_types);
         self.type_names.extend(other.type_names);
         self.errors.append(&mut other.errors);
+        self.warnings.append(&mut other.warnings);
 
         self.file_name = other.file_name;
         self.crate_name = other.crate_name;
@@ -148,6 +332,9 @@ impl ParsedData {
                 self.type_names.insert(a.id.renamed.clone());
                 self.aliases.insert(a);
             }
+            RustItem::Const(c) => {
+                self.consts.insert(c);
+            }
         }
     }
 
@@ -156,6 +343,7 @@ impl ParsedData {
         self.structs.is_empty()
             && self.enums.is_empty()
             && self.aliases.is_empty()
+            && self.consts.is_empty()
             && self.errors.is_empty()
     }
 }
@@ -197,7 +385,12 @@ pub fn parse(
 ///
 /// This function can currently return something other than a struct, which is a
 /// hack.
-pub(crate) fn parse_struct(s: &ItemStruct, target_os: &[String]) -> Result<RustItem, ParseError> {
+pub(crate) fn parse_struct(
+    s: &ItemStruct,
+    target_os: &[String],
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<RustItem, SpannedParseError> {
+    validate_typeshare_attrs(&s.attrs)?;
     let serde_rename_all = serde_rename_all(&s.attrs);
 
     let generic_types = s
@@ -213,11 +406,13 @@ pub(crate) fn parse_struct(s: &ItemStruct, target_os: &[String]) -> Result<RustI
     // Check if this struct should be parsed as a type alias.
     // TODO: we shouldn't lie and return a type alias when parsing a struct. this
     // is a temporary hack
-    if let Some(ty) = get_serialized_as_type(&s.attrs) {
+    if let Some(ty) = get_serialized_type(&s.ident, &s.attrs, Some(&s.fields), target_os, warnings)?
+    {
         return Ok(RustItem::Alias(RustTypeAlias {
-            id: get_ident(Some(&s.ident), &s.attrs, &None),
-            r#type: ty.parse()?,
+            id: get_ident(Some(&s.ident), &s.attrs, &SerdeNames::default()),
+            r#type: ty,
             comments: parse_comment_attrs(&s.attrs),
+            deprecated: parse_deprecation(&s.attrs),
             generic_types,
             decorators: get_decorators(&s.attrs),
             is_redacted: is_redacted(&s.attrs),
@@ -230,59 +425,48 @@ pub(crate) fn parse_struct(s: &ItemStruct, target_os: &[String]) -> Result<RustI
             let fields = f
                 .named
                 .iter()
-                .inspect(|field| debug!("\t\tChecking field {:?}", field.ident))
-                .filter(|field| !is_skipped(&field.attrs, target_os))
-                .inspect(|field| debug!("\t\tAccepted field {:?}", field.ident))
-                .map(|f| {
-                    let ty = if let Some(ty) = get_field_type_override(&f.attrs) {
-                        ty.parse()?
-                    } else {
-                        RustType::try_from(&f.ty)?
-                    };
-
-                    if serde_flatten(&f.attrs) {
-                        return Err(ParseError::SerdeFlattenNotAllowed);
-                    }
-
-                    let has_default = serde_default(&f.attrs);
-                    let decorators = get_field_decorators(&f.attrs);
-
-                    Ok(RustField {
-                        id: get_ident(f.ident.as_ref(), &f.attrs, &serde_rename_all),
-                        ty,
-                        comments: parse_comment_attrs(&f.attrs),
-                        has_default,
-                        decorators,
-                    })
+                .enumerate()
+                .inspect(|(_, field)| debug!("\t\tChecking field {:?}", field.ident))
+                .filter(|(_, field)| !is_skipped(&field.attrs, target_os))
+                .inspect(|(_, field)| debug!("\t\tAccepted field {:?}", field.ident))
+                .map(|(i, f)| {
+                    parse_field(f, &serde_rename_all).map_err(|error| error.in_field(f, i))
                 })
-                .collect::<Result<_, ParseError>>()?;
+                .collect::<Result<_, _>>()?;
 
             RustItem::Struct(RustStruct {
-                id: get_ident(Some(&s.ident), &s.attrs, &None),
+                id: get_ident(Some(&s.ident), &s.attrs, &SerdeNames::default()),
                 generic_types,
                 fields,
                 comments: parse_comment_attrs(&s.attrs),
+                deprecated: parse_deprecation(&s.attrs),
                 decorators: get_decorators(&s.attrs),
                 is_redacted: is_redacted(&s.attrs),
+                // A tag with content is only valid on enums, so it isn't a struct tag
+                tag_key: get_tag_key(&s.attrs).filter(|_| get_content_key(&s.attrs).is_none()),
             })
         }
         // Tuple structs
         Fields::Unnamed(f) => {
-            if f.unnamed.len() > 1 {
-                return Err(ParseError::ComplexTupleStruct);
-            }
-            let f = &f.unnamed[0];
-
-            let ty = if let Some(ty) = get_field_type_override(&f.attrs) {
-                ty.parse()?
+            // A newtype struct is serialized as its field, any other tuple
+            // struct as an array of its fields
+            let ty = if f.unnamed.len() == 1 {
+                parse_field_type(&f.unnamed[0], 0)?
             } else {
-                RustType::try_from(&f.ty)?
+                RustType::Special(SpecialRustType::Tuple(
+                    f.unnamed
+                        .iter()
+                        .enumerate()
+                        .map(|(i, f)| parse_field_type(f, i))
+                        .collect::<Result<_, _>>()?,
+                ))
             };
 
             RustItem::Alias(RustTypeAlias {
-                id: get_ident(Some(&s.ident), &s.attrs, &None),
+                id: get_ident(Some(&s.ident), &s.attrs, &SerdeNames::default()),
                 r#type: ty,
                 comments: parse_comment_attrs(&s.attrs),
+                deprecated: parse_deprecation(&s.attrs),
                 generic_types,
                 decorators: get_decorators(&s.attrs),
                 is_redacted: is_redacted(&s.attrs),
@@ -290,12 +474,14 @@ pub(crate) fn parse_struct(s: &ItemStruct, target_os: &[String]) -> Result<RustI
         }
         // Unit structs or `None`
         Fields::Unit => RustItem::Struct(RustStruct {
-            id: get_ident(Some(&s.ident), &s.attrs, &None),
+            id: get_ident(Some(&s.ident), &s.attrs, &SerdeNames::default()),
             generic_types,
             fields: vec![],
             comments: parse_comment_attrs(&s.attrs),
+            deprecated: parse_deprecation(&s.attrs),
             decorators: get_decorators(&s.attrs),
             is_redacted: is_redacted(&s.attrs),
+            tag_key: None,
         }),
     })
 }
@@ -305,7 +491,12 @@ pub(crate) fn parse_struct(s: &ItemStruct, target_os: &[String]) -> Result<RustI
 ///
 /// This function can currently return something other than an enum, which is a
 /// hack.
-pub(crate) fn parse_enum(e: &ItemEnum, target_os: &[String]) -> Result<RustItem, ParseError> {
+pub(crate) fn parse_enum(
+    e: &ItemEnum,
+    target_os: &[String],
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<RustItem, SpannedParseError> {
+    validate_typeshare_attrs(&e.attrs)?;
     let generic_types = e
         .generics
         .params
@@ -317,14 +508,16 @@ pub(crate) fn parse_enum(e: &ItemEnum, target_os: &[String]) -> Result<RustItem,
         .collect();
 
     let serde_rename_all = serde_rename_all(&e.attrs);
+    let serde_rename_all_fields = serde_names(&e.attrs, "rename_all_fields");
 
     // TODO: we shouldn't lie and return a type alias when parsing an enum. this
     // is a temporary hack
-    if let Some(ty) = get_serialized_as_type(&e.attrs) {
+    if let Some(ty) = get_serialized_type(&e.ident, &e.attrs, None, target_os, warnings)? {
         return Ok(RustItem::Alias(RustTypeAlias {
-            id: get_ident(Some(&e.ident), &e.attrs, &None),
-            r#type: ty.parse()?,
+            id: get_ident(Some(&e.ident), &e.attrs, &SerdeNames::default()),
+            r#type: ty,
             comments: parse_comment_attrs(&e.attrs),
+            deprecated: parse_deprecation(&e.attrs),
             generic_types,
             decorators: get_decorators(&e.attrs),
             is_redacted: is_redacted(&e.attrs),
@@ -345,7 +538,10 @@ pub(crate) fn parse_enum(e: &ItemEnum, target_os: &[String]) -> Result<RustItem,
         // Filter out variants we've been told to skip
         .filter(|v| !is_skipped(&v.attrs, target_os))
         .inspect(|v| debug!("\t\taccepted variant {}", v.ident))
-        .map(|v| parse_enum_variant(v, &serde_rename_all, target_os))
+        .map(|v| {
+            parse_enum_variant(v, &serde_rename_all, &serde_rename_all_fields, target_os)
+                .map_err(|error| error.in_variant(v))
+        })
         .collect::<Result<Vec<_>, _>>()?;
 
     // Check if the enum references itself recursively in any of its variants
@@ -358,8 +554,9 @@ pub(crate) fn parse_enum(e: &ItemEnum, target_os: &[String]) -> Result<RustItem,
     });
 
     let shared = RustEnumShared {
-        id: get_ident(Some(&e.ident), &e.attrs, &None),
+        id: get_ident(Some(&e.ident), &e.attrs, &SerdeNames::default()),
         comments: parse_comment_attrs(&e.attrs),
+        deprecated: parse_deprecation(&e.attrs),
         variants,
         decorators: get_decorators(&e.attrs),
         generic_types,
@@ -367,6 +564,17 @@ pub(crate) fn parse_enum(e: &ItemEnum, target_os: &[String]) -> Result<RustItem,
         is_redacted: is_redacted(&e.attrs),
     };
 
+    // Untagged enums only serialize the content of their variants, whatever
+    // kind of variants they have
+    if serde_untagged(&e.attrs) {
+        return Ok(RustItem::Enum(RustEnum::Untagged(shared)));
+    }
+
+    // Enums deriving the `serde_repr` traits are serialized as their discriminants
+    if derives_serde_repr(&e.attrs) {
+        return parse_numeric_enum(e, shared).map(RustItem::Enum);
+    }
+
     // Figure out if we're dealing with a unit enum or an algebraic enum
     if shared
         .variants
@@ -377,64 +585,175 @@ pub(crate) fn parse_enum(e: &ItemEnum, target_os: &[String]) -> Result<RustItem,
         if maybe_tag_key.is_some() {
             return Err(ParseError::SerdeTagNotAllowed {
                 enum_ident: original_enum_ident,
-            });
+            }
+            .into());
         }
         if maybe_content_key.is_some() {
             return Err(ParseError::SerdeContentNotAllowed {
                 enum_ident: original_enum_ident,
-            });
+            }
+            .into());
         }
 
         Ok(RustItem::Enum(RustEnum::Unit(shared)))
     } else {
         // At least one enum variant is either a tuple or an anonymous struct
 
+        // Without a tag or content key serde uses its default, externally
+        // tagged representation
+        if maybe_tag_key.is_none() && maybe_content_key.is_none() {
+            return Ok(RustItem::Enum(RustEnum::ExternallyTagged(shared)));
+        }
+
         let tag_key = maybe_tag_key.ok_or_else(|| ParseError::SerdeTagRequired {
             enum_ident: original_enum_ident.clone(),
         })?;
-        let content_key = maybe_content_key.ok_or_else(|| ParseError::SerdeContentRequired {
-            enum_ident: original_enum_ident.clone(),
-        })?;
 
-        Ok(RustItem::Enum(RustEnum::Algebraic {
-            tag_key,
-            content_key,
-            shared,
-        }))
+        match maybe_content_key {
+            Some(content_key) => Ok(RustItem::Enum(RustEnum::Algebraic {
+                tag_key,
+                content_key,
+                shared,
+            })),
+            // A tag without content means the tag is serialized inside the
+            // variant, which only works for variants serialized as objects
+            None => {
+                if let Some(variant) = shared.variants.iter().find(|v| match v {
+                    RustEnumVariant::Tuple { ty, .. } => !may_be_struct(ty, &shared.generic_types),
+                    _ => false,
+                }) {
+                    let error =
+                        SpannedParseError::from(ParseError::SerdeInternallyTaggedTupleNotAllowed {
+                            enum_ident: original_enum_ident,
+                            variant_ident: variant.shared().id.original.clone(),
+                        });
+                    return Err(error.in_variant_named(e, &variant.shared().id.original));
+                }
+
+                Ok(RustItem::Enum(RustEnum::InternallyTagged {
+                    tag_key,
+                    shared,
+                }))
+            }
+        }
+    }
+}
+
+/// Whether `ty` may name a struct or is a map, which the tuple variant of an
+/// internally tagged enum must wrap for its tag to be serialized inside it.
+/// Other builtin types and the generic parameters of the enum never do.
+fn may_be_struct(ty: &RustType, generic_types: &[String]) -> bool {
+    match ty {
+        RustType::Simple { id } => !generic_types.contains(id),
+        RustType::Generic { .. } => true,
+        RustType::Special(SpecialRustType::HashMap(..) | SpecialRustType::OrderedMap(..)) => true,
+        RustType::Special(_) => false,
     }
 }
 
+/// Parse an enum serialized as integers with `serde_repr`, filling in the
+/// discriminants of its variants.
+fn parse_numeric_enum(
+    e: &ItemEnum,
+    mut shared: RustEnumShared,
+) -> Result<RustEnum, SpannedParseError> {
+    let enum_ident = e.ident.to_string();
+
+    let repr = get_repr_type(&e.attrs).ok_or_else(|| ParseError::SerdeReprWithoutIntegerRepr {
+        enum_ident: enum_ident.clone(),
+    })?;
+
+    // Discriminants are computed over all the variants, including skipped
+    // ones, since a variant without an explicit discriminant follows the
+    // previous one
+    let mut next_discriminant = 0;
+    let mut discriminants = HashMap::new();
+    for v in &e.variants {
+        let discriminant = match &v.discriminant {
+            Some((_, expr)) => match literal_expr(expr) {
+                Some((Lit::Int(int), negated)) => {
+                    let value = int.base10_parse::<i128>().map_err(|error| {
+                        SpannedParseError::from(ParseError::from(error)).in_variant(v)
+                    })?;
+                    if negated {
+                        -value
+                    } else {
+                        value
+                    }
+                }
+                _ => {
+                    return Err(
+                        SpannedParseError::from(ParseError::UnsupportedDiscriminant {
+                            enum_ident,
+                            variant_ident: v.ident.to_string(),
+                        })
+                        .in_variant(v),
+                    )
+                }
+            },
+            None => next_discriminant,
+        };
+        next_discriminant = discriminant + 1;
+        discriminants.insert(v.ident.to_string(), discriminant);
+    }
+
+    for variant in &mut shared.variants {
+        match variant {
+            RustEnumVariant::Unit(variant_shared) => {
+                variant_shared.discriminant =
+                    discriminants.get(&variant_shared.id.original).copied();
+            }
+            _ => {
+                return Err(
+                    SpannedParseError::from(ParseError::SerdeReprNonUnitVariant { enum_ident })
+                        .in_variant_named(e, &variant.shared().id.original),
+                );
+            }
+        }
+    }
+
+    Ok(RustEnum::Numeric { repr, shared })
+}
+
 /// Parse an enum variant.
 fn parse_enum_variant(
     v: &syn::Variant,
-    enum_serde_rename_all: &Option<String>,
+    enum_serde_rename_all: &SerdeNames,
+    enum_serde_rename_all_fields: &SerdeNames,
     target_os: &[String],
-) -> Result<RustEnumVariant, ParseError> {
+) -> Result<RustEnumVariant, SpannedParseError> {
+    validate_typeshare_attrs(&v.attrs)?;
     let shared = RustEnumVariantShared {
         id: get_ident(Some(&v.ident), &v.attrs, enum_serde_rename_all),
         comments: parse_comment_attrs(&v.attrs),
+        deprecated: parse_deprecation(&v.attrs),
+        discriminant: None,
+        is_other: serde_attr(&v.attrs, "other"),
     };
 
     // Get the value of `#[serde(rename_all)]` for this specific variant rather
-    // than the overall enum
+    // than the overall enum, falling back to `#[serde(rename_all_fields)]` of
+    // the enum
     //
-    // The value of the attribute for the enum overall does not apply to enum
-    // variant fields.
-    let variant_serde_rename_all = serde_rename_all(&v.attrs);
+    // The value of `#[serde(rename_all)]` for the enum overall does not apply
+    // to enum variant fields.
+    let variant_serde_rename_all = serde_rename_all(&v.attrs).or(enum_serde_rename_all_fields);
 
     match &v.fields {
         syn::Fields::Unit => Ok(RustEnumVariant::Unit(shared)),
         syn::Fields::Unnamed(associated_type) => {
-            if associated_type.unnamed.len() > 1 {
-                return Err(ParseError::MultipleUnnamedAssociatedTypes);
-            }
-
-            let first_field = associated_type.unnamed.first().unwrap();
-
-            let ty = if let Some(ty) = get_field_type_override(&first_field.attrs) {
-                ty.parse()?
+            // Several fields are serialized as an array, just like a tuple
+            let ty = if associated_type.unnamed.len() == 1 {
+                parse_field_type(associated_type.unnamed.first().unwrap(), 0)?
             } else {
-                RustType::try_from(&first_field.ty)?
+                RustType::Special(SpecialRustType::Tuple(
+                    associated_type
+                        .unnamed
+                        .iter()
+                        .enumerate()
+                        .map(|(i, f)| parse_field_type(f, i))
+                        .collect::<Result<_, _>>()?,
+                ))
             };
 
             Ok(RustEnumVariant::Tuple { ty, shared })
@@ -443,26 +762,12 @@ fn parse_enum_variant(
             fields: fields_named
                 .named
                 .iter()
-                .filter(|f| !is_skipped(&f.attrs, target_os))
-                .map(|f| {
-                    let field_type = if let Some(ty) = get_field_type_override(&f.attrs) {
-                        ty.parse()?
-                    } else {
-                        RustType::try_from(&f.ty)?
-                    };
-
-                    let has_default = serde_default(&f.attrs);
-                    let decorators = get_field_decorators(&f.attrs);
-
-                    Ok(RustField {
-                        id: get_ident(f.ident.as_ref(), &f.attrs, &variant_serde_rename_all),
-                        ty: field_type,
-                        comments: parse_comment_attrs(&f.attrs),
-                        has_default,
-                        decorators,
-                    })
+                .enumerate()
+                .filter(|(_, f)| !is_skipped(&f.attrs, target_os))
+                .map(|(i, f)| {
+                    parse_field(f, &variant_serde_rename_all).map_err(|error| error.in_field(f, i))
                 })
-                .collect::<Result<Vec<_>, ParseError>>()?,
+                .collect::<Result<Vec<_>, _>>()?,
             shared,
         }),
     }
@@ -470,11 +775,12 @@ fn parse_enum_variant(
 
 /// Parses a type alias into a definition that more succinctly represents what
 /// typeshare needs to generate code for other languages.
-pub(crate) fn parse_type_alias(t: &ItemType) -> Result<RustItem, ParseError> {
+pub(crate) fn parse_type_alias(t: &ItemType) -> Result<RustItem, SpannedParseError> {
+    validate_typeshare_attrs(&t.attrs)?;
     let ty = if let Some(ty) = get_serialized_as_type(&t.attrs) {
-        ty.parse()?
+        ty.parse().map_err(ParseError::from)?
     } else {
-        RustType::try_from(t.ty.as_ref())?
+        RustType::try_from(t.ty.as_ref()).map_err(ParseError::from)?
     };
 
     let generic_types = t
@@ -488,17 +794,136 @@ pub(crate) fn parse_type_alias(t: &ItemType) -> Result<RustItem, ParseError> {
         .collect();
 
     Ok(RustItem::Alias(RustTypeAlias {
-        id: get_ident(Some(&t.ident), &t.attrs, &None),
+        id: get_ident(Some(&t.ident), &t.attrs, &SerdeNames::default()),
         r#type: ty,
         comments: parse_comment_attrs(&t.attrs),
+        deprecated: parse_deprecation(&t.attrs),
         generic_types,
         decorators: get_decorators(&t.attrs),
         is_redacted: is_redacted(&t.attrs),
     }))
 }
 
+/// Parses a const item into a definition of a constant that Typeshare can understand.
+pub(crate) fn parse_const(c: &ItemConst) -> Result<RustItem, SpannedParseError> {
+    validate_typeshare_attrs(&c.attrs)?;
+    let const_ident = c.ident.to_string();
+    let r#type = RustType::try_from(c.ty.as_ref()).map_err(ParseError::from)?;
+
+    let (lit, negated) = match literal_expr(&c.expr) {
+        Some(literal) => literal,
+        None => return Err(ParseError::ConstNotLiteral { const_ident }.into()),
+    };
+
+    let expr = match (lit, &r#type) {
+        (
+            Lit::Int(int),
+            RustType::Special(
+                SpecialRustType::I8
+                | SpecialRustType::I16
+                | SpecialRustType::I32
+                | SpecialRustType::I64
+                | SpecialRustType::ISize
+                | SpecialRustType::U8
+                | SpecialRustType::U16
+                | SpecialRustType::U32
+                | SpecialRustType::U64
+                | SpecialRustType::USize,
+            ),
+        ) => {
+            let value = int.base10_parse::<i128>().map_err(ParseError::from)?;
+            RustConstExpr::Int(if negated { -value } else { value })
+        }
+        (Lit::Float(float), RustType::Special(SpecialRustType::F32 | SpecialRustType::F64)) => {
+            let mut value = float.base10_digits().to_string();
+            // `1.` is a valid float in Rust, but not in most other languages
+            if value.ends_with('.') {
+                value.push('0');
+            }
+            RustConstExpr::Float(if negated { format!("-{value}") } else { value })
+        }
+        (Lit::Bool(bool), RustType::Special(SpecialRustType::Bool)) => {
+            RustConstExpr::Bool(bool.value)
+        }
+        (Lit::Str(str), RustType::Special(SpecialRustType::String)) => {
+            RustConstExpr::String(str.value())
+        }
+        _ => {
+            return Err(ParseError::UnsupportedConstType {
+                const_ident,
+                const_type: c.ty.to_token_stream().to_string(),
+            }
+            .into())
+        }
+    };
+
+    Ok(RustItem::Const(RustConst {
+        id: get_ident(Some(&c.ident), &c.attrs, &SerdeNames::default()),
+        r#type,
+        expr,
+        comments: parse_comment_attrs(&c.attrs),
+    }))
+}
+
 // Helpers
 
+/// Parse a named field of a struct or an enum variant.
+fn parse_field(
+    f: &syn::Field,
+    serde_rename_all: &SerdeNames,
+) -> Result<RustField, SpannedParseError> {
+    validate_typeshare_attrs(&f.attrs)?;
+    let ty = if let Some(ty) = get_field_type_override(&f.attrs) {
+        ty.parse().map_err(ParseError::from)?
+    } else {
+        RustType::try_from(&f.ty).map_err(ParseError::from)?
+    };
+
+    Ok(RustField {
+        id: get_ident(f.ident.as_ref(), &f.attrs, serde_rename_all),
+        ty,
+        comments: parse_comment_attrs(&f.attrs),
+        deprecated: parse_deprecation(&f.attrs),
+        has_default: serde_default(&f.attrs),
+        skip_serializing_if: serde_skip_serializing_if(&f.attrs),
+        flattened: serde_flatten(&f.attrs),
+        decorators: get_field_decorators(&f.attrs),
+    })
+}
+
+/// Parse the type of an unnamed field, the `index`th of a tuple struct or variant.
+fn parse_field_type(f: &syn::Field, index: usize) -> Result<RustType, SpannedParseError> {
+    validate_typeshare_attrs(&f.attrs).map_err(|error| error.in_field(f, index))?;
+    (|| {
+        Ok(if let Some(ty) = get_field_type_override(&f.attrs) {
+            ty.parse()?
+        } else {
+            RustType::try_from(&f.ty)?
+        })
+    })()
+    .map_err(|error: ParseError| SpannedParseError::from(error).in_field(f, index))
+}
+
+/// Get the literal from an expression that is a literal or a negated number
+/// literal, along with whether it is negated.
+fn literal_expr(expr: &Expr) -> Option<(&Lit, bool)> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => Some((lit, false)),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match expr.as_ref() {
+            Expr::Lit(ExprLit {
+                lit: lit @ (Lit::Int(_) | Lit::Float(_)),
+                ..
+            }) => Some((lit, true)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 /// Checks the given attrs for `#[typeshare]`
 pub(crate) fn has_typeshare_annotation(attrs: &[syn::Attribute]) -> bool {
     attrs
@@ -507,8 +932,131 @@ pub(crate) fn has_typeshare_annotation(attrs: &[syn::Attribute]) -> bool {
         .any(|segment| segment.ident == TYPESHARE)
 }
 
-pub(crate) fn serde_rename_all(attrs: &[syn::Attribute]) -> Option<String> {
-    get_name_value_meta_items(attrs, "rename_all", SERDE).next()
+pub(crate) fn serde_rename_all(attrs: &[syn::Attribute]) -> SerdeNames {
+    serde_names(attrs, "rename_all")
+}
+
+/// The values of a serde attribute that can differ between serializing and
+/// deserializing, like `rename` or `rename_all`.
+#[derive(Debug, Default)]
+pub(crate) struct SerdeNames {
+    serialize: Option<String>,
+    deserialize: Option<String>,
+}
+
+impl SerdeNames {
+    /// Fall back to the values of `other` for serializing and deserializing
+    /// separately, like serde does for `rename_all` and `rename_all_fields`.
+    fn or(self, other: &SerdeNames) -> SerdeNames {
+        SerdeNames {
+            serialize: self.serialize.or_else(|| other.serialize.clone()),
+            deserialize: self.deserialize.or_else(|| other.deserialize.clone()),
+        }
+    }
+}
+
+/// Get the values of `#[serde(name = "...")]`, which applies to both
+/// serializing and deserializing, or `#[serde(name(serialize = "...", deserialize = "..."))]`.
+fn serde_names(attrs: &[syn::Attribute], name: &str) -> SerdeNames {
+    let mut names = SerdeNames::default();
+    for arg in attrs.iter().flat_map(|attr| get_meta_items(attr, SERDE)) {
+        match arg {
+            Meta::NameValue(name_value) if name_value.path.is_ident(name) => {
+                names.serialize = expr_to_string(&name_value.value);
+                names.deserialize.clone_from(&names.serialize);
+            }
+            Meta::List(list) if list.path.is_ident(name) => {
+                let nested = list
+                    .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                    .into_iter()
+                    .flatten();
+                for nested in nested {
+                    if let Meta::NameValue(name_value) = nested {
+                        if name_value.path.is_ident("serialize") {
+                            names.serialize = expr_to_string(&name_value.value);
+                        } else if name_value.path.is_ident("deserialize") {
+                            names.deserialize = expr_to_string(&name_value.value);
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    names
+}
+
+/// Get the type that a struct or enum is serialized as, which makes it a
+/// type alias.
+///
+/// An explicit `#[typeshare(serialized_as)]` always wins. Otherwise the type is
+/// inferred from `#[serde(into)]`, or from the single field of a
+/// `#[serde(transparent)]` struct. A warning is added to `warnings` when
+/// `serialized_as` disagrees with serde, or when `#[serde(from, try_from)]`,
+/// which only change how the type is deserialized, name another type.
+fn get_serialized_type(
+    ident: &Ident,
+    attrs: &[syn::Attribute],
+    fields: Option<&Fields>,
+    target_os: &[String],
+    warnings: &mut Vec<ParseWarning>,
+) -> Result<Option<RustType>, ParseError> {
+    let into_type = get_name_value_meta_items(attrs, "into", SERDE)
+        .next()
+        .map(|ty| ty.parse::<RustType>())
+        .transpose()?;
+    let serde_type = match into_type {
+        Some(ty) => Some(ty),
+        None => match fields {
+            Some(fields) if serde_attr(attrs, "transparent") => {
+                transparent_field_type(fields, target_os)?
+            }
+            _ => None,
+        },
+    };
+
+    let serialized_type = match get_serialized_as_type(attrs) {
+        Some(serialized_as) => {
+            let ty = serialized_as.parse()?;
+            if serde_type.is_some_and(|serde_type| serde_type != ty) {
+                warnings.push(ParseWarning::SerializedAsMismatch {
+                    type_ident: ident.to_string(),
+                    serialized_as,
+                });
+            }
+            Some(ty)
+        }
+        None => serde_type,
+    };
+
+    if let Some(from) = ["from", "try_from"]
+        .into_iter()
+        .find_map(|name| get_name_value_meta_items(attrs, name, SERDE).next())
+    {
+        if serialized_type.as_ref() != Some(&from.parse()?) {
+            warnings.push(ParseWarning::SerdeConversionMismatch {
+                type_ident: ident.to_string(),
+                from,
+            });
+        }
+    }
+
+    Ok(serialized_type)
+}
+
+/// Get the type of the single field of a `#[serde(transparent)]` struct.
+fn transparent_field_type(
+    fields: &Fields,
+    target_os: &[String],
+) -> Result<Option<RustType>, ParseError> {
+    let mut fields = fields.iter().filter(|f| !is_skipped(&f.attrs, target_os));
+    match (fields.next(), fields.next()) {
+        (Some(field), None) => Ok(Some(match get_field_type_override(&field.attrs) {
+            Some(ty) => ty.parse()?,
+            None => RustType::try_from(&field.ty)?,
+        })),
+        _ => Ok(None),
+    }
 }
 
 pub(crate) fn get_serialized_as_type(attrs: &[syn::Attribute]) -> Option<String> {
@@ -553,17 +1101,24 @@ pub(crate) fn get_meta_items(attr: &syn::Attribute, ident: &str) -> impl Iterato
 fn get_ident(
     ident: Option<&proc_macro2::Ident>,
     attrs: &[syn::Attribute],
-    rename_all: &Option<String>,
+    rename_all: &SerdeNames,
 ) -> Id {
     let original = ident.map_or("???".to_string(), |id| id.to_string().replace("r#", ""));
 
-    let mut renamed = rename_all_to_case(original.clone(), rename_all);
-
-    if let Some(s) = serde_rename(attrs) {
-        renamed = s;
+    let rename = serde_names(attrs, "rename");
+    let renamed = rename
+        .serialize
+        .unwrap_or_else(|| rename_all_to_case(original.clone(), &rename_all.serialize));
+    let deserialize_renamed = rename
+        .deserialize
+        .unwrap_or_else(|| rename_all_to_case(original.clone(), &rename_all.deserialize));
+
+    Id {
+        deserialize_renamed: (deserialize_renamed != renamed).then_some(deserialize_renamed),
+        aliases: get_name_value_meta_items(attrs, "alias", SERDE).collect(),
+        original,
+        renamed,
     }
-
-    Id { original, renamed }
 }
 
 fn rename_all_to_case(original: String, case: &Option<String>) -> String {
@@ -583,10 +1138,6 @@ fn rename_all_to_case(original: String, case: &Option<String>) -> String {
     }
 }
 
-fn serde_rename(attrs: &[syn::Attribute]) -> Option<String> {
-    get_name_value_meta_items(attrs, "rename", SERDE).next()
-}
-
 /// Parses any comment out of the given slice of attributes
 fn parse_comment_attrs(attrs: &[Attribute]) -> Vec<String> {
     attrs
@@ -601,6 +1152,36 @@ fn parse_comment_attrs(attrs: &[Attribute]) -> Vec<String> {
         .collect()
 }
 
+/// Parses `#[deprecated]`, `#[deprecated = "note"]` and
+/// `#[deprecated(since = "...", note = "...")]`.
+fn parse_deprecation(attrs: &[Attribute]) -> Option<Deprecation> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("deprecated"))
+        .map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => Deprecation {
+                since: None,
+                note: expr_to_string(&name_value.value),
+            },
+            Meta::List(list) => {
+                let args = list
+                    .parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)
+                    .unwrap_or_default();
+                let arg = |name: &str| {
+                    args.iter()
+                        .find(|arg| arg.path.is_ident(name))
+                        .and_then(|arg| expr_to_string(&arg.value))
+                };
+
+                Deprecation {
+                    since: arg("since"),
+                    note: arg("note"),
+                }
+            }
+            Meta::Path(_) => Deprecation::default(),
+        })
+}
+
 // `#[typeshare(skip)]` or `#[serde(skip)]`
 fn is_skipped(attrs: &[syn::Attribute], target_os: &[String]) -> bool {
     let typeshare_skip = attrs.iter().any(|attr| {
@@ -631,101 +1212,237 @@ fn serde_default(attrs: &[syn::Attribute]) -> bool {
     serde_attr(attrs, "default")
 }
 
+fn serde_skip_serializing_if(attrs: &[syn::Attribute]) -> bool {
+    get_name_value_meta_items(attrs, "skip_serializing_if", SERDE)
+        .next()
+        .is_some()
+}
+
 fn serde_flatten(attrs: &[syn::Attribute]) -> bool {
     serde_attr(attrs, "flatten")
 }
 
+fn serde_untagged(attrs: &[syn::Attribute]) -> bool {
+    serde_attr(attrs, "untagged")
+}
+
+/// Checks whether the item derives `Serialize_repr` or `Deserialize_repr` from `serde_repr`.
+fn derives_serde_repr(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        get_meta_items(attr, "derive").any(|arg| {
+            matches!(arg, Meta::Path(path) if path.segments.last().is_some_and(|segment| {
+                segment.ident == "Serialize_repr" || segment.ident == "Deserialize_repr"
+            }))
+        })
+    })
+}
+
+/// Get the integer type from `#[repr(...)]`, ignoring other hints like `C`.
+fn get_repr_type(attrs: &[syn::Attribute]) -> Option<SpecialRustType> {
+    attrs
+        .iter()
+        .flat_map(|attr| get_meta_items(attr, "repr"))
+        .find_map(|arg| match arg {
+            Meta::Path(path) => match path.get_ident()?.to_string().parse().ok()? {
+                RustType::Special(
+                    repr @ (SpecialRustType::I8
+                    | SpecialRustType::I16
+                    | SpecialRustType::I32
+                    | SpecialRustType::I64
+                    | SpecialRustType::ISize
+                    | SpecialRustType::U8
+                    | SpecialRustType::U16
+                    | SpecialRustType::U32
+                    | SpecialRustType::U64
+                    | SpecialRustType::USize),
+                ) => Some(repr),
+                _ => None,
+            },
+            _ => None,
+        })
+}
+
 /// Checks the struct or enum for decorators like `#[typeshare(typescript(readonly)]`
 /// Takes a slice of `syn::Attribute`, returns a `HashMap<language, BTreeSet<decorator>>`, where `language` is `SupportedLanguage`
 /// and `decorator` is `FieldDecorator`. Field decorators are ordered in a `BTreeSet` for consistent code generation.
 fn get_field_decorators(
     attrs: &[Attribute],
 ) -> HashMap<SupportedLanguage, BTreeSet<FieldDecorator>> {
-    let languages: HashSet<SupportedLanguage> = SupportedLanguage::all_languages().collect();
-
     attrs
         .iter()
         .flat_map(|attr| get_meta_items(attr, TYPESHARE))
-        .filter_map(|meta| {
-            if let Meta::List(list) = meta {
-                Some(list)
-            } else {
-                None
-            }
-        })
-        .filter_map(|list: MetaList| match list.path.get_ident() {
-            Some(ident) if languages.contains(&ident.try_into().unwrap()) => {
-                Some((ident.try_into().unwrap(), list))
-            }
+        .filter_map(|meta| match meta {
+            Meta::List(list) => Some(list),
             _ => None,
         })
+        .filter_map(|list: MetaList| {
+            let language = SupportedLanguage::try_from(list.path.get_ident()?).ok()?;
+            Some((language, list))
+        })
         .map(|(language, list): (SupportedLanguage, MetaList)| {
             (
                 language,
-                list.parse_args_with(|input: &ParseBuffer| {
-                    let mut res: Vec<Meta> = vec![];
-
-                    loop {
-                        if input.is_empty() {
-                            break;
+                list.parse_args_with(parse_language_args)
+                    .iter()
+                    .flatten()
+                    .filter_map(|nested| match nested {
+                        Meta::Path(path) if path.segments.len() == 1 => {
+                            Some(FieldDecorator::Word(path.get_ident()?.to_string()))
                         }
+                        Meta::NameValue(name_value) => Some(FieldDecorator::NameValue(
+                            name_value.path.get_ident()?.to_string(),
+                            expr_to_string(&name_value.value)?,
+                        )),
+                        // Anything else was already rejected by `validate_typeshare_attrs`
+                        _ => None,
+                    })
+                    .collect::<Vec<FieldDecorator>>(),
+            )
+        })
+        .fold(HashMap::new(), |mut acc, (language, decorators)| {
+            acc.entry(language).or_default().extend(decorators);
+            acc
+        })
+}
 
-                        let ident = input.call(Ident::parse_any)?;
+/// Parses the arguments of a language decorator like `typescript(readonly, type = "any")`,
+/// which are words or names set to a string literal. Names can be keywords like `type`.
+fn parse_language_args(input: &ParseBuffer) -> syn::Result<Vec<Meta>> {
+    let mut res: Vec<Meta> = vec![];
 
-                        // Parse `readonly` or any other single ident optionally followed by a comma
-                        if input.peek(Token![,]) || input.is_empty() {
-                            input.parse::<Token![,]>().unwrap_or_default();
-                            res.push(Meta::Path(ident.into()));
-                            continue;
-                        }
+    loop {
+        if input.is_empty() {
+            break;
+        }
 
-                        if input.is_empty() {
-                            break;
-                        }
+        let ident = input.call(Ident::parse_any)?;
 
-                        // Parse `= "any | undefined"` or any other eq sign followed by a string literal
+        // Parse `readonly` or any other single ident optionally followed by a comma
+        if input.peek(Token![,]) || input.is_empty() {
+            input.parse::<Token![,]>().unwrap_or_default();
+            res.push(Meta::Path(ident.into()));
+            continue;
+        }
 
-                        let eq_token = input.parse::<Token![=]>()?;
+        if input.is_empty() {
+            break;
+        }
 
-                        let value: LitStr = input.parse()?;
-                        res.push(Meta::NameValue(MetaNameValue {
-                            path: ident.into(),
-                            eq_token,
-                            value: Expr::Lit(ExprLit {
-                                attrs: Vec::new(),
-                                lit: value.into(),
-                            }),
-                        }));
+        // Parse `= "any | undefined"` or any other eq sign followed by a string literal
 
-                        if input.is_empty() {
-                            break;
-                        }
+        let eq_token = input.parse::<Token![=]>()?;
+
+        let value: LitStr = input.parse()?;
+        res.push(Meta::NameValue(MetaNameValue {
+            path: ident.into(),
+            eq_token,
+            value: Expr::Lit(ExprLit {
+                attrs: Vec::new(),
+                lit: value.into(),
+            }),
+        }));
+
+        if input.is_empty() {
+            break;
+        }
+
+        input.parse::<Token![,]>()?;
+    }
+    Ok(res)
+}
 
-                        input.parse::<Token![,]>()?;
+/// Checks every argument of the `#[typeshare(...)]` attributes in `attrs`, so
+/// that typos and malformed arguments are reported instead of ignored.
+fn validate_typeshare_attrs(attrs: &[Attribute]) -> Result<(), SpannedParseError> {
+    let syn_error = |error: syn::Error| SpannedParseError::new(error.clone().into(), error.span());
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(TYPESHARE)) {
+        let args = match &attr.meta {
+            Meta::Path(_) => continue,
+            Meta::List(list) => list
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .map_err(syn_error)?,
+            Meta::NameValue(name_value) => {
+                return Err(SpannedParseError::new(
+                    ParseError::UnknownAttributeArgument(
+                        name_value.value.to_token_stream().to_string(),
+                    ),
+                    name_value.span(),
+                ))
+            }
+        };
+
+        for arg in &args {
+            let name = arg.path().to_token_stream().to_string();
+            let invalid = |expected| {
+                SpannedParseError::new(
+                    ParseError::InvalidAttributeArgument {
+                        argument: name.clone(),
+                        expected,
+                    },
+                    arg.span(),
+                )
+            };
+            let language = arg
+                .path()
+                .get_ident()
+                .and_then(|ident| SupportedLanguage::try_from(ident).ok());
+
+            match (name.as_str(), arg) {
+                ("skip" | "redacted", Meta::Path(_)) => (),
+                ("skip" | "redacted", _) => return Err(invalid("a single word")),
+                (
+                    "serialized_as" | "swift" | "swiftGenericConstraints" | "kotlin",
+                    Meta::NameValue(name_value),
+                ) => {
+                    if expr_to_string(&name_value.value).is_none() {
+                        return Err(invalid("set to a string literal"));
                     }
-                    Ok(res)
-                })
-                .iter()
-                .flatten()
-                .filter_map(|nested| match nested {
-                    Meta::Path(path) if path.segments.len() == 1 => {
-                        Some(FieldDecorator::Word(path.get_ident()?.to_string()))
+                }
+                ("serialized_as" | "swiftGenericConstraints", _) => {
+                    return Err(invalid("set to a string literal"))
+                }
+                (_, Meta::List(list)) if language.is_some() => {
+                    for decorator in list
+                        .parse_args_with(parse_language_args)
+                        .map_err(syn_error)?
+                    {
+                        match (
+                            decorator.path().to_token_stream().to_string().as_str(),
+                            &decorator,
+                        ) {
+                            ("readonly", Meta::Path(_)) | ("type", Meta::NameValue(_)) => (),
+                            (decorator_name, _) => {
+                                return Err(SpannedParseError::new(
+                                    ParseError::UnknownAttributeArgument(format!(
+                                        "{name}({decorator_name})"
+                                    )),
+                                    decorator.span(),
+                                ))
+                            }
+                        }
                     }
-                    Meta::NameValue(name_value) => Some(FieldDecorator::NameValue(
-                        name_value.path.get_ident()?.to_string(),
-                        expr_to_string(&name_value.value)?,
-                    )),
-                    // TODO: this should throw a visible error since it suggests a malformed
-                    //       attribute.
-                    _ => None,
-                })
-                .collect::<Vec<FieldDecorator>>(),
-            )
-        })
-        .fold(HashMap::new(), |mut acc, (language, decorators)| {
-            acc.entry(language).or_default().extend(decorators);
-            acc
-        })
+                }
+                (_, _) if language.is_some() => {
+                    return Err(invalid("a list of decorators like `typescript(readonly)`"))
+                }
+                (_, Meta::List(_)) => {
+                    return Err(SpannedParseError::new(
+                        ParseError::UnsupportedLanguage(name),
+                        arg.path().span(),
+                    ))
+                }
+                _ => {
+                    return Err(SpannedParseError::new(
+                        ParseError::UnknownAttributeArgument(name),
+                        arg.span(),
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(())
 }
 
 fn expr_to_string(expr: &Expr) -> Option<String> {
@@ -21,6 +21,28 @@ pub struct Id {
     /// If there is no re-naming going on, this will be identical to
     /// `original`.
     pub renamed: String,
+    /// The name expected when deserializing, if it differs from `renamed`
+    /// because of `#[serde(rename(deserialize = "..."))]` or
+    /// `#[serde(rename_all(deserialize = "..."))]`.
+    pub deserialize_renamed: Option<String>,
+    /// Other names accepted when deserializing, from `#[serde(alias = "...")]`.
+    pub aliases: Vec<String>,
+}
+
+impl Id {
+    /// All the names accepted when deserializing, starting with
+    /// `deserialize_renamed` or else `renamed`.
+    pub fn deserialize_names(&self) -> Vec<&str> {
+        std::iter::once(self.deserialize_renamed.as_ref().unwrap_or(&self.renamed))
+            .chain(&self.aliases)
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Whether the identifier is deserialized from any name other than `renamed`.
+    pub fn has_deserialize_aliases(&self) -> bool {
+        self.deserialize_renamed.is_some() || !self.aliases.is_empty()
+    }
 }
 
 impl std::fmt::Display for Id {
@@ -50,6 +72,11 @@ pub struct RustStruct {
     pub decorators: DecoratorMap,
     /// True if this struct contains data that needs to be redacted
     pub is_redacted: bool,
+    /// The key of the tag from `#[serde(tag = "...")]`, which serde adds to
+    /// the serialized struct with the struct's name as its value.
+    pub tag_key: Option<String>,
+    /// Set if the struct has a `#[deprecated]` attribute.
+    pub deprecated: Option<Deprecation>,
 }
 
 impl PartialEq for RustStruct {
@@ -90,6 +117,8 @@ pub struct RustTypeAlias {
     pub decorators: DecoratorMap,
     /// True if this type alias contains data that needs to be redacted
     pub is_redacted: bool,
+    /// Set if the type alias has a `#[deprecated]` attribute.
+    pub deprecated: Option<Deprecation>,
 }
 
 impl PartialEq for RustTypeAlias {
@@ -112,6 +141,56 @@ impl PartialOrd for RustTypeAlias {
     }
 }
 
+/// Rust constant with a literal value.
+/// ```
+/// pub const MAX_PAYLOAD_SIZE: u32 = 1024;
+/// ```
+#[derive(Debug, Clone)]
+pub struct RustConst {
+    /// The identifier for the constant.
+    pub id: Id,
+    /// The type of the constant. This is always a number, `bool` or string type.
+    pub r#type: RustType,
+    /// The literal value of the constant.
+    pub expr: RustConstExpr,
+    /// Comments that were in the constant source.
+    pub comments: Vec<String>,
+}
+
+impl PartialEq for RustConst {
+    fn eq(&self, other: &Self) -> bool {
+        self.id.original == other.id.original
+    }
+}
+
+impl Eq for RustConst {}
+
+impl Ord for RustConst {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.id.original.cmp(&other.id.original)
+    }
+}
+
+impl PartialOrd for RustConst {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The literal value of a constant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RustConstExpr {
+    /// An integer literal, possibly negated
+    Int(i128),
+    /// A float literal in decimal notation, possibly negated. Always contains a digit
+    /// after the decimal point, if it has one.
+    Float(String),
+    /// A `true` or `false` literal
+    Bool(bool),
+    /// A string literal
+    String(String),
+}
+
 /// Rust field definition.
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustField {
@@ -125,9 +204,40 @@ pub struct RustField {
     /// Even if the field's type is not optional, we need to make it optional
     /// for the languages we generate code for.
     pub has_default: bool,
+    /// This will be true if the field has a `serde(skip_serializing_if)`
+    /// decorator. The field may then be missing from the serialized data,
+    /// which is different from an `Option` being serialized as `null`.
+    pub skip_serializing_if: bool,
+    /// This will be true if the field has a `serde(flatten)` decorator.
+    /// The fields of the flattened type are serialized inline with the
+    /// fields of the enclosing struct.
+    pub flattened: bool,
     /// Language-specific decorators assigned to a given field.
     /// The keys are language names (e.g. SupportedLanguage::TypeScript), the values are field decorators (e.g. readonly)
     pub decorators: HashMap<SupportedLanguage, BTreeSet<FieldDecorator>>,
+    /// Set if the field has a `#[deprecated]` attribute.
+    pub deprecated: Option<Deprecation>,
+}
+
+/// The contents of a `#[deprecated]` attribute.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Deprecation {
+    /// The version the item was deprecated in, from `since = "..."`.
+    pub since: Option<String>,
+    /// Why the item was deprecated, from `note = "..."` or `#[deprecated = "..."]`.
+    pub note: Option<String>,
+}
+
+impl Deprecation {
+    /// A message combining the note and the version, if either is set.
+    pub fn message(&self) -> Option<String> {
+        match (&self.note, &self.since) {
+            (Some(note), Some(since)) => Some(format!("{note} (since {since})")),
+            (Some(note), None) => Some(note.clone()),
+            (None, Some(since)) => Some(format!("Deprecated since {since}")),
+            (None, None) => None,
+        }
+    }
 }
 
 /// A single decorator on a field in Rust code.
@@ -190,8 +300,16 @@ pub enum SpecialRustType {
     Slice(Box<RustType>),
     /// Represents `HashMap<K, V>` from the standard library
     HashMap(Box<RustType>, Box<RustType>),
+    /// Represents `BTreeMap<K, V>` from the standard library or `IndexMap<K, V>`
+    /// from `indexmap`, whose entries are serialized in order
+    OrderedMap(Box<RustType>, Box<RustType>),
+    /// Represents `HashSet<T>` or `BTreeSet<T>` from the standard library or `IndexSet<T>`
+    /// from `indexmap`, serialized as an array
+    Set(Box<RustType>),
     /// Represents `Option<T>` from the standard library
     Option(Box<RustType>),
+    /// Represents a tuple like `(T, U)`, serialized as an array
+    Tuple(Vec<RustType>),
     /// Represents `()`
     Unit,
     /// Represents `String` from the standard library
@@ -237,8 +355,6 @@ pub enum RustTypeParseError {
     UnsupportedType(Vec<String>),
     #[error("Unexpected token when parsing type: `{0}`. This is an internal error, please ping a typeshare developer to resolve this problem.")]
     UnexpectedToken(String),
-    #[error("Tuples are not allowed in typeshare types")]
-    UnexpectedParameterizedTuple,
     #[error("Could not parse numeric literal")]
     NumericLiteral(syn::parse::Error),
 }
@@ -261,7 +377,13 @@ impl TryFrom<&syn::Type> for RustType {
             syn::Type::Tuple(tuple) if tuple.elems.iter().count() == 0 => {
                 Self::Special(SpecialRustType::Unit)
             }
-            syn::Type::Tuple(_) => return Err(RustTypeParseError::UnexpectedParameterizedTuple),
+            syn::Type::Tuple(tuple) => Self::Special(SpecialRustType::Tuple(
+                tuple
+                    .elems
+                    .iter()
+                    .map(Self::try_from)
+                    .collect::<Result<_, _>>()?,
+            )),
             syn::Type::Reference(reference) => Self::try_from(reference.elem.as_ref())?,
             syn::Type::Path(path) => {
                 let segment = path.path.segments.iter().last().unwrap();
@@ -281,7 +403,11 @@ impl TryFrom<&syn::Type> for RustType {
                     _ => Vec::default(),
                 };
                 match id.as_str() {
-                    "Vec" => Self::Special(SpecialRustType::Vec(
+                    // A `VecDeque` is serialized like any other sequence
+                    "Vec" | "VecDeque" => Self::Special(SpecialRustType::Vec(
+                        parameters.into_iter().next().unwrap().into(),
+                    )),
+                    "HashSet" | "BTreeSet" | "IndexSet" => Self::Special(SpecialRustType::Set(
                         parameters.into_iter().next().unwrap().into(),
                     )),
                     "Option" => Self::Special(SpecialRustType::Option(
@@ -294,6 +420,13 @@ impl TryFrom<&syn::Type> for RustType {
                             params.next().unwrap().into(),
                         ))
                     }
+                    "BTreeMap" | "IndexMap" => {
+                        let mut params = parameters.into_iter();
+                        Self::Special(SpecialRustType::OrderedMap(
+                            params.next().unwrap().into(),
+                            params.next().unwrap().into(),
+                        ))
+                    }
                     "str" | "String" => Self::Special(SpecialRustType::String),
                     // These smart pointers can be treated as their inner type since serde can handle it
                     // See impls of serde::Deserialize
@@ -305,12 +438,13 @@ impl TryFrom<&syn::Type> for RustType {
                     "u16" => Self::Special(SpecialRustType::U16),
                     "u32" => Self::Special(SpecialRustType::U32),
                     "U53" => Self::Special(SpecialRustType::U53),
-                    "u64" | "i64" | "usize" | "isize" => {
-                        return Err(RustTypeParseError::UnsupportedType(vec![id]))
-                    }
+                    "u64" => Self::Special(SpecialRustType::U64),
+                    "usize" => Self::Special(SpecialRustType::USize),
                     "i8" => Self::Special(SpecialRustType::I8),
                     "i16" => Self::Special(SpecialRustType::I16),
                     "i32" => Self::Special(SpecialRustType::I32),
+                    "i64" => Self::Special(SpecialRustType::I64),
+                    "isize" => Self::Special(SpecialRustType::ISize),
                     "I54" => Self::Special(SpecialRustType::I54),
                     "f32" => Self::Special(SpecialRustType::F32),
                     "f64" => Self::Special(SpecialRustType::F64),
@@ -396,6 +530,13 @@ impl RustType {
     pub fn is_hash_map(&self) -> bool {
         matches!(self, Self::Special(SpecialRustType::HashMap(_, _)))
     }
+    /// Check if the type is a map, either `HashMap<K, V>` or an ordered map
+    pub fn is_map(&self) -> bool {
+        matches!(
+            self,
+            Self::Special(SpecialRustType::HashMap(_, _) | SpecialRustType::OrderedMap(_, _))
+        )
+    }
     /// Get the generic parameters for this type. Returns an empty iterator if there are none.
     /// For example, `Vec<String>`'s generic parameters would be `[String]`.
     /// Meanwhile, `HashMap<i64, u32>`'s generic parameters would be `[i64, u32]`.
@@ -452,6 +593,12 @@ impl RustField {
                 _ => None,
             })
     }
+
+    /// Whether the field may be missing when decoding, because it has a
+    /// default value or is skipped when serializing.
+    pub fn may_be_absent(&self) -> bool {
+        self.has_default || self.skip_serializing_if
+    }
 }
 
 #[derive(Debug, Error)]
@@ -461,16 +608,33 @@ pub enum RustTypeFormatError {
     GenericsForbiddenInGo(String),
     #[error("Generic type `{0}` cannot be used as a map key in Typescript")]
     GenericKeyForbiddenInTS(String),
+    #[error("Flattened type `{0}` must be a struct known to typeshare or a map")]
+    UnresolvedFlattenedType(String),
+    #[error("Generic type `{0}` cannot be serialized by a generated serializer")]
+    UnsupportedGenericSerializer(String),
+    #[error("Variants `{1}` and `{2}` of untagged enum `{0}` cannot be told apart")]
+    IndistinguishableUntaggedVariants(String, String, String),
+    #[error("Tuple types must be given a name before they are formatted")]
+    UnnamedTuple,
+    #[error("64 bit integer type `{0}` is rejected by the configured 64 bit integer strategy")]
+    Int64Rejected(String),
+    #[error("The `{0}` 64 bit integer strategy is not supported in {1}")]
+    UnsupportedInt64Strategy(String, String),
 }
 
 impl SpecialRustType {
     /// Check if this type is equivalent to or contains `ty` in one of its generic parameters.
     pub fn contains_type(&self, ty: &str) -> bool {
         match &self {
-            Self::Vec(rty) | Self::Array(rty, _) | Self::Slice(rty) | Self::Option(rty) => {
-                rty.contains_type(ty)
+            Self::Vec(rty)
+            | Self::Array(rty, _)
+            | Self::Slice(rty)
+            | Self::Option(rty)
+            | Self::Set(rty) => rty.contains_type(ty),
+            Self::HashMap(rty1, rty2) | Self::OrderedMap(rty1, rty2) => {
+                rty1.contains_type(ty) || rty2.contains_type(ty)
             }
-            Self::HashMap(rty1, rty2) => rty1.contains_type(ty) || rty2.contains_type(ty),
+            Self::Tuple(rtys) => rtys.iter().any(|rty| rty.contains_type(ty)),
             Self::Unit
             | Self::String
             | Self::Char
@@ -503,6 +667,9 @@ impl SpecialRustType {
             Self::Slice(_) => "&[]",
             Self::Option(_) => "Option",
             Self::HashMap(_, _) => "HashMap",
+            Self::OrderedMap(_, _) => "BTreeMap",
+            Self::Set(_) => "HashSet",
+            Self::Tuple(_) => "(..)",
             Self::String => "String",
             Self::Char => "char",
             Self::Bool => "bool",
@@ -524,12 +691,15 @@ impl SpecialRustType {
     /// if there are none.
     pub fn parameters(&self) -> Box<dyn Iterator<Item = &RustType> + '_> {
         match &self {
-            Self::Vec(rtype) | Self::Array(rtype, _) | Self::Slice(rtype) | Self::Option(rtype) => {
-                Box::new(std::iter::once(rtype.as_ref()))
-            }
-            Self::HashMap(rtype1, rtype2) => {
+            Self::Vec(rtype)
+            | Self::Array(rtype, _)
+            | Self::Slice(rtype)
+            | Self::Option(rtype)
+            | Self::Set(rtype) => Box::new(std::iter::once(rtype.as_ref())),
+            Self::HashMap(rtype1, rtype2) | Self::OrderedMap(rtype1, rtype2) => {
                 Box::new([rtype1.as_ref(), rtype2.as_ref()].into_iter())
             }
+            Self::Tuple(rtypes) => Box::new(rtypes.iter()),
             Self::Unit
             | Self::String
             | Self::Char
@@ -591,6 +761,80 @@ pub enum RustEnum {
         /// Shared context for this enum.
         shared: RustEnumShared,
     },
+    /// An algebraic enum using serde's default, externally tagged
+    /// representation. Unit variants are serialized as their name, and
+    /// every other variant as an object with the name as its only key.
+    ///
+    /// An example of such an enum:
+    ///
+    /// ```
+    /// struct AssociatedData { /* ... */ }
+    ///
+    /// enum ExternallyTaggedEnum {
+    ///     UnitVariant,
+    ///     TupleVariant(AssociatedData),
+    ///     AnonymousStruct {
+    ///         field: String,
+    ///     },
+    /// }
+    /// ```
+    ExternallyTagged(RustEnumShared),
+    /// An algebraic enum using serde's internally tagged representation, set
+    /// with `#[serde(tag = "...")]`. The tag is serialized alongside the fields
+    /// of the variant, so tuple variants must wrap a struct or a map.
+    ///
+    /// An example of such an enum:
+    ///
+    /// ```
+    /// struct AssociatedData { /* ... */ }
+    ///
+    /// enum InternallyTaggedEnum {
+    ///     UnitVariant,
+    ///     TupleVariant(AssociatedData),
+    ///     AnonymousStruct {
+    ///         field: String,
+    ///     },
+    /// }
+    /// ```
+    InternallyTagged {
+        /// The parsed value of the `#[serde(tag = "...")]` attribute
+        tag_key: String,
+        /// Shared context for this enum.
+        shared: RustEnumShared,
+    },
+    /// An algebraic enum using serde's untagged representation, set with
+    /// `#[serde(untagged)]`. Only the content of the variant is serialized,
+    /// and unit variants are serialized as `null`. Deserializing tries each
+    /// variant in order.
+    ///
+    /// An example of such an enum:
+    ///
+    /// ```
+    /// enum StringOrNumber {
+    ///     String(String),
+    ///     Number(f64),
+    /// }
+    /// ```
+    Untagged(RustEnumShared),
+    /// A unit enum serialized as the integer discriminants of its variants,
+    /// using `Serialize_repr` and `Deserialize_repr` from `serde_repr`.
+    ///
+    /// An example of such an enum:
+    ///
+    /// ```
+    /// #[repr(u8)]
+    /// enum Priority {
+    ///     Low = 1,
+    ///     Medium,
+    ///     High = 10,
+    /// }
+    /// ```
+    Numeric {
+        /// The integer type from the `#[repr(...)]` attribute
+        repr: SpecialRustType,
+        /// Shared context for this enum.
+        shared: RustEnumShared,
+    },
 }
 
 impl PartialEq for RustEnum {
@@ -617,7 +861,24 @@ impl RustEnum {
     /// Get a reference to the inner shared content
     pub fn shared(&self) -> &RustEnumShared {
         match self {
-            Self::Unit(shared) | Self::Algebraic { shared, .. } => shared,
+            Self::Unit(shared)
+            | Self::Algebraic { shared, .. }
+            | Self::ExternallyTagged(shared)
+            | Self::InternallyTagged { shared, .. }
+            | Self::Untagged(shared)
+            | Self::Numeric { shared, .. } => shared,
+        }
+    }
+
+    /// Get a mutable reference to the inner shared content
+    pub fn shared_mut(&mut self) -> &mut RustEnumShared {
+        match self {
+            Self::Unit(shared)
+            | Self::Algebraic { shared, .. }
+            | Self::ExternallyTagged(shared)
+            | Self::InternallyTagged { shared, .. }
+            | Self::Untagged(shared)
+            | Self::Numeric { shared, .. } => shared,
         }
     }
 }
@@ -642,6 +903,16 @@ pub struct RustEnumShared {
     pub is_recursive: bool,
     /// True if this enum contains data that needs to be redacted
     pub is_redacted: bool,
+    /// Set if the enum has a `#[deprecated]` attribute.
+    pub deprecated: Option<Deprecation>,
+}
+
+impl RustEnumShared {
+    /// Get the variant with `#[serde(other)]`, if any, which unknown variants
+    /// are deserialized as.
+    pub fn other_variant(&self) -> Option<&RustEnumVariant> {
+        self.variants.iter().find(|v| v.shared().is_other)
+    }
 }
 
 /// Parsed information about a Rust enum variant
@@ -651,7 +922,8 @@ pub enum RustEnumVariant {
     Unit(RustEnumVariantShared),
     /// A tuple variant
     Tuple {
-        /// The type of the single tuple field
+        /// The type of the single tuple field, or a tuple of the types of
+        /// all the fields if there are several
         ty: RustType,
         /// Shared context for this enum.
         shared: RustEnumVariantShared,
@@ -683,6 +955,14 @@ pub struct RustEnumVariantShared {
     pub id: Id,
     /// Comments applied to the variant
     pub comments: Vec<String>,
+    /// The discriminant of the variant, which is only set for the variants of
+    /// [`RustEnum::Numeric`] enums
+    pub discriminant: Option<i128>,
+    /// This will be true if the variant has a `serde(other)` decorator, which
+    /// makes it the fallback for unknown variants when deserializing.
+    pub is_other: bool,
+    /// Set if the variant has a `#[deprecated]` attribute.
+    pub deprecated: Option<Deprecation>,
 }
 
 /// An enum that encapsulates units of code generation for Typeshare.
@@ -696,4 +976,6 @@ pub enum RustItem {
     Enum(RustEnum),
     /// A `type` definition or newtype struct.
     Alias(RustTypeAlias),
+    /// A `const` definition.
+    Const(RustConst),
 }
@@ -40,16 +40,21 @@ fn get_dependencies_from_type(
             }
         }
         RustType::Special(special) => match special {
-            SpecialRustType::HashMap(kt, vt) => {
+            SpecialRustType::HashMap(kt, vt) | SpecialRustType::OrderedMap(kt, vt) => {
                 get_dependencies_from_type(kt, types, res, seen);
                 get_dependencies_from_type(vt, types, res, seen);
             }
             SpecialRustType::Option(inner) => {
                 get_dependencies_from_type(inner, types, res, seen);
             }
-            SpecialRustType::Vec(inner) => {
+            SpecialRustType::Vec(inner) | SpecialRustType::Set(inner) => {
                 get_dependencies_from_type(inner, types, res, seen);
             }
+            SpecialRustType::Tuple(elements) => {
+                for element in elements {
+                    get_dependencies_from_type(element, types, res, seen);
+                }
+            }
             _ => {}
         },
     };
@@ -63,12 +68,15 @@ fn get_enum_dependencies(
     seen: &mut HashSet<String>,
 ) {
     match enm {
-        RustEnum::Unit(_) => {}
+        RustEnum::Unit(_) | RustEnum::Numeric { .. } => {}
         RustEnum::Algebraic {
             tag_key: _,
             content_key: _,
             shared,
-        } => {
+        }
+        | RustEnum::ExternallyTagged(shared)
+        | RustEnum::InternallyTagged { shared, .. }
+        | RustEnum::Untagged(shared) => {
             if seen.insert(shared.id.original.to_string()) {
                 res.push(shared.id.original.to_string());
                 for variant in &shared.variants {
@@ -130,6 +138,8 @@ fn get_dependencies(
         RustItem::Enum(en) => get_enum_dependencies(en, types, res, seen),
         RustItem::Struct(strct) => get_struct_dependencies(strct, types, res, seen),
         RustItem::Alias(alias) => get_type_alias_dependencies(alias, types, res, seen),
+        // Constants only have primitive types
+        RustItem::Const(_) => {}
     }
 }
 
@@ -182,7 +192,7 @@ fn toposort_impl(graph: &Vec<Vec<usize>>) -> Vec<usize> {
 }
 
 pub(crate) fn topsort(things: &mut [RustItem]) {
-    let types = HashMap::from_iter(things.iter().map(|thing| {
+    let types = HashMap::from_iter(things.iter().filter_map(|thing| {
         let id = match thing {
             RustItem::Enum(e) => match e {
                 RustEnum::Algebraic {
@@ -190,12 +200,18 @@ pub(crate) fn topsort(things: &mut [RustItem]) {
                     content_key: _,
                     shared,
                 } => shared.id.original.clone(),
-                RustEnum::Unit(shared) => shared.id.original.clone(),
+                RustEnum::Unit(shared)
+                | RustEnum::ExternallyTagged(shared)
+                | RustEnum::InternallyTagged { shared, .. }
+                | RustEnum::Untagged(shared)
+                | RustEnum::Numeric { shared, .. } => shared.id.original.clone(),
             },
             RustItem::Struct(strct) => strct.id.original.clone(),
             RustItem::Alias(ta) => ta.id.original.clone(),
+            // Constants aren't types, and may share their name with one
+            RustItem::Const(_) => return None,
         };
-        (id, thing)
+        Some((id, thing))
     }));
 
     let dag: Vec<Vec<usize>> = things
@@ -2,15 +2,15 @@
 use crate::{
     language::CrateName,
     parser::{
-        has_typeshare_annotation, parse_enum, parse_struct, parse_type_alias, ErrorInfo,
-        ParseError, ParsedData,
+        has_typeshare_annotation, parse_const, parse_enum, parse_struct, parse_type_alias,
+        ErrorInfo, ParseWarning, ParsedData, SpannedParseError, WarningInfo,
     },
     rust_types::{RustEnumVariant, RustItem},
     target_os_check::accept_target_os,
 };
 use log::debug;
 use std::{collections::HashSet, ops::Not, path::PathBuf};
-use syn::{visit::Visit, Attribute, ItemUse, UseTree};
+use syn::{visit::Visit, Attribute, Ident, ItemUse, UseTree};
 
 /// List of some popular crate names that we can ignore
 /// during import parsing.
@@ -42,7 +42,10 @@ const IGNORED_BASE_CRATES: &[&str] = &[
 ];
 
 /// List of reference types or imported types we can ignore during import parsing.
-const IGNORED_TYPES: &[&str] = &["Option", "String", "Vec", "HashMap", "T", "I54", "U53"];
+const IGNORED_TYPES: &[&str] = &[
+    "Option", "String", "Vec", "VecDeque", "HashMap", "BTreeMap", "IndexMap", "HashSet",
+    "BTreeSet", "IndexSet", "T", "I54", "U53",
+];
 
 /// An import visitor that collects all use or
 /// qualified referenced items.
@@ -87,16 +90,37 @@ impl<'a> TypeShareVisitor<'a> {
     }
 
     #[inline]
-    fn collect_result(&mut self, result: Result<RustItem, ParseError>) {
+    fn collect_result(&mut self, ident: &Ident, result: Result<RustItem, SpannedParseError>) {
         match result {
             Ok(data) => self.parsed_data.push(data),
-            Err(error) => self.parsed_data.errors.push(ErrorInfo {
+            Err(SpannedParseError {
+                error,
+                span,
+                field_name,
+            }) => self.parsed_data.errors.push(ErrorInfo {
                 file_name: self.file_path.to_string_lossy().into_owned(),
                 error,
+                // Errors that aren't in a field or variant point at the type
+                location: Some(span.unwrap_or_else(|| ident.span()).into()),
+                type_name: Some(ident.to_string()),
+                field_name,
             }),
         }
     }
 
+    #[inline]
+    fn collect_warnings(&mut self, ident: &Ident, warnings: Vec<ParseWarning>) {
+        let file_name = self.file_path.to_string_lossy();
+        self.parsed_data
+            .warnings
+            .extend(warnings.into_iter().map(|warning| WarningInfo {
+                file_name: file_name.clone().into_owned(),
+                warning,
+                location: Some(ident.span().into()),
+                type_name: Some(ident.to_string()),
+            }));
+    }
+
     /// After collecting all imports we now want to retain only those
     /// that are referenced by the typeshared types.
     fn reconcile_referenced_types(&mut self) {
@@ -266,7 +290,10 @@ impl<'ast, 'a> Visit<'ast> for TypeShareVisitor<'a> {
         debug!("Visiting {}", i.ident);
         if has_typeshare_annotation(&i.attrs) && self.target_os_accepted(&i.attrs) {
             debug!("\tParsing {}", i.ident);
-            self.collect_result(parse_struct(i, self.target_os));
+            let mut warnings = Vec::new();
+            let result = parse_struct(i, self.target_os, &mut warnings);
+            self.collect_warnings(&i.ident, warnings);
+            self.collect_result(&i.ident, result);
         }
 
         syn::visit::visit_item_struct(self, i);
@@ -277,7 +304,10 @@ impl<'ast, 'a> Visit<'ast> for TypeShareVisitor<'a> {
         debug!("Visiting {}", i.ident);
         if has_typeshare_annotation(&i.attrs) && self.target_os_accepted(&i.attrs) {
             debug!("\tParsing {}", i.ident);
-            self.collect_result(parse_enum(i, self.target_os));
+            let mut warnings = Vec::new();
+            let result = parse_enum(i, self.target_os, &mut warnings);
+            self.collect_warnings(&i.ident, warnings);
+            self.collect_result(&i.ident, result);
         }
 
         syn::visit::visit_item_enum(self, i);
@@ -288,12 +318,23 @@ impl<'ast, 'a> Visit<'ast> for TypeShareVisitor<'a> {
         debug!("Visiting {}", i.ident);
         if has_typeshare_annotation(&i.attrs) && self.target_os_accepted(&i.attrs) {
             debug!("\tParsing {}", i.ident);
-            self.collect_result(parse_type_alias(i));
+            self.collect_result(&i.ident, parse_type_alias(i));
         }
 
         syn::visit::visit_item_type(self, i);
     }
 
+    /// Collect rust constants.
+    fn visit_item_const(&mut self, i: &'ast syn::ItemConst) {
+        debug!("Visiting {}", i.ident);
+        if has_typeshare_annotation(&i.attrs) && self.target_os_accepted(&i.attrs) {
+            debug!("\tParsing {}", i.ident);
+            self.collect_result(&i.ident, parse_const(i));
+        }
+
+        syn::visit::visit_item_const(self, i);
+    }
+
     /// Track potentially skipped modules.
     // fn visit_item_mod(&mut self, i: &'ast syn::ItemMod) {
     //     if let Some(target_os) = self.target_os.as_ref() {
@@ -1,8 +1,7 @@
 use std::io::Write;
 use typeshare_core::{
-    language::{CrateTypes, Language, TypeScript},
+    language::{CrateTypes, Int64Strategy, Language, Swift, TypeScript},
     parser::{self, ParseError},
-    rust_types::RustTypeParseError,
     ProcessInputError,
 };
 /// Parse and generate types for a single Rust input file.
@@ -53,12 +52,24 @@ mod blocklisted_types {
         let mut out: Vec<u8> = Vec::new();
         assert!(matches!(
             process_input(&source, &mut TypeScript::default(), &HashMap::new(), &mut out),
-            Err(ProcessInputError::ParseError(
-                ParseError::RustTypeParseError(RustTypeParseError::UnsupportedType(contents))
-            )) if contents == vec![blocklisted_type.to_owned()]
+            Err(ProcessInputError::IoError(error))
+                if error.to_string() == format!("64 bit integer type `{blocklisted_type}` is rejected by the configured 64 bit integer strategy")
         ));
     }
 
+    fn generate_with_strategy(language: &mut dyn Language) -> Result<String, ProcessInputError> {
+        let source = r##"
+    #[typeshare]
+    pub struct Foo {
+        pub bar: u64,
+    }
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        process_input(source, language, &HashMap::new(), &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
     #[test]
     fn test_i64_blocklisted_struct() {
         assert_type_is_blocklisted("i64", "i64");
@@ -93,6 +104,44 @@ mod blocklisted_types {
     fn test_hashmap_blocklisted_struct() {
         assert_type_is_blocklisted("HashMap<String, i64>", "i64");
     }
+
+    #[test]
+    fn test_typescript_int64_strategies() {
+        for (int64_strategy, expected) in [
+            (Int64Strategy::Number, "bar: number;"),
+            (Int64Strategy::BigInt, "bar: bigint;"),
+            (Int64Strategy::String, "bar: string;"),
+        ] {
+            let output = generate_with_strategy(&mut TypeScript {
+                int64_strategy,
+                ..Default::default()
+            })
+            .unwrap();
+            assert!(output.contains(expected), "{output}");
+        }
+    }
+
+    #[test]
+    fn test_swift_int64_strategies() {
+        let output = generate_with_strategy(&mut Swift::default()).unwrap();
+        assert!(output.contains("bar: UInt64"), "{output}");
+
+        let output = generate_with_strategy(&mut Swift {
+            int64_strategy: Int64Strategy::String,
+            ..Default::default()
+        })
+        .unwrap();
+        assert!(output.contains("bar: String"), "{output}");
+
+        assert!(matches!(
+            generate_with_strategy(&mut Swift {
+                int64_strategy: Int64Strategy::BigInt,
+                ..Default::default()
+            }),
+            Err(ProcessInputError::IoError(error))
+                if error.to_string() == "The `bigint` 64 bit integer strategy is not supported in Swift"
+        ));
+    }
 }
 
 mod serde_attributes_on_enums {
@@ -153,4 +202,582 @@ mod serde_attributes_on_enums {
             ProcessInputError::ParseError(ParseError::SerdeTagNotAllowed { enum_ident }) if enum_ident == "Foo"
         ));
     }
+
+    #[test]
+    fn primitive_tuple_not_allowed_on_internally_tagged() {
+        let source = r##"
+    #[typeshare]
+    #[serde(tag = "type")]
+    pub enum Foo {
+        Variant1,
+        Variant2(String),
+    }
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut TypeScript::default(), &HashMap::new(), &mut out).unwrap_err(),
+            ProcessInputError::ParseError(ParseError::SerdeInternallyTaggedTupleNotAllowed { enum_ident, variant_ident }) if enum_ident == "Foo" && variant_ident == "Variant2"
+        ));
+    }
+
+    #[test]
+    fn number_tuple_not_allowed_on_internally_tagged() {
+        let source = r##"
+    #[typeshare]
+    #[serde(tag = "type")]
+    pub enum Foo {
+        Variant1,
+        Variant2(u32),
+    }
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut TypeScript::default(), &HashMap::new(), &mut out).unwrap_err(),
+            ProcessInputError::ParseError(ParseError::SerdeInternallyTaggedTupleNotAllowed { enum_ident, variant_ident }) if enum_ident == "Foo" && variant_ident == "Variant2"
+        ));
+    }
+
+    #[test]
+    fn generic_tuple_not_allowed_on_internally_tagged() {
+        let source = r##"
+    #[typeshare]
+    #[serde(tag = "type")]
+    pub enum Foo<T> {
+        Variant1,
+        Variant2(T),
+    }
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut TypeScript::default(), &HashMap::new(), &mut out).unwrap_err(),
+            ProcessInputError::ParseError(ParseError::SerdeInternallyTaggedTupleNotAllowed { enum_ident, variant_ident }) if enum_ident == "Foo" && variant_ident == "Variant2"
+        ));
+    }
+
+    #[test]
+    fn map_tuple_allowed_on_internally_tagged() {
+        let source = r##"
+    #[typeshare]
+    #[serde(tag = "type")]
+    pub enum Foo {
+        Variant1,
+        Variant2(HashMap<String, String>),
+        Variant3(BTreeMap<String, u32>),
+    }
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        process_input(
+            source,
+            &mut TypeScript::default(),
+            &HashMap::new(),
+            &mut out,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn indistinguishable_untagged_variants_not_allowed() {
+        let source = r##"
+    #[typeshare]
+    #[serde(untagged)]
+    pub enum Foo {
+        Variant1(String),
+        Variant2(u32),
+        Variant3(String),
+    }
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut Swift::default(), &HashMap::new(), &mut out).unwrap_err(),
+            ProcessInputError::IoError(error) if error.to_string() == "Variants `Variant1` and `Variant3` of untagged enum `Foo` cannot be told apart"
+        ));
+    }
+}
+
+mod consts {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn non_literal_value_not_allowed() {
+        let source = r##"
+    #[typeshare]
+    pub const MAX_SIZE: u32 = 1024 * 1024;
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut TypeScript::default(), &HashMap::new(), &mut out).unwrap_err(),
+            ProcessInputError::ParseError(ParseError::ConstNotLiteral { const_ident }) if const_ident == "MAX_SIZE"
+        ));
+    }
+
+    #[test]
+    fn non_primitive_type_not_allowed() {
+        let source = r##"
+    #[typeshare]
+    pub const DEFAULT_ID: ItemId = 1;
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut TypeScript::default(), &HashMap::new(), &mut out).unwrap_err(),
+            ProcessInputError::ParseError(ParseError::UnsupportedConstType { const_ident, const_type })
+                if const_ident == "DEFAULT_ID" && const_type == "ItemId"
+        ));
+    }
+}
+
+mod numeric_enums {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn integer_repr_required() {
+        let source = r##"
+    #[typeshare]
+    #[derive(Serialize_repr, Deserialize_repr)]
+    pub enum Priority {
+        Low = 1,
+        High = 2,
+    }
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut TypeScript::default(), &HashMap::new(), &mut out).unwrap_err(),
+            ProcessInputError::ParseError(ParseError::SerdeReprWithoutIntegerRepr { enum_ident }) if enum_ident == "Priority"
+        ));
+    }
+
+    #[test]
+    fn non_unit_variants_not_allowed() {
+        let source = r##"
+    #[typeshare]
+    #[derive(Serialize_repr, Deserialize_repr)]
+    #[repr(u8)]
+    pub enum Priority {
+        Low,
+        Custom(u8),
+    }
+    "##;
+
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(
+            process_input(source, &mut TypeScript::default(), &HashMap::new(), &mut out).unwrap_err(),
+            ProcessInputError::ParseError(ParseError::SerdeReprNonUnitVariant { enum_ident }) if enum_ident == "Priority"
+        ));
+    }
+}
+
+mod error_locations {
+    use super::*;
+    use typeshare_core::parser::{ErrorInfo, SourceLocation};
+    use typeshare_core::rust_types::{RustType, SpecialRustType};
+
+    fn parse_errors(source: &str) -> Vec<ErrorInfo> {
+        parser::parse(
+            source,
+            "default_name".into(),
+            "file_name".into(),
+            "file_path".into(),
+            &[],
+            false,
+            &[],
+        )
+        .unwrap()
+        .unwrap()
+        .errors
+    }
+
+    #[test]
+    fn field_errors_point_at_the_field_type() {
+        let source = r##"#[typeshare]
+pub struct User {
+    pub name: String,
+    pub callback: fn(),
+}
+"##;
+
+        let errors = parse_errors(source);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].file_name, "file_path");
+        assert_eq!(errors[0].type_name.as_deref(), Some("User"));
+        assert_eq!(errors[0].field_name.as_deref(), Some("callback"));
+        assert_eq!(
+            errors[0].location,
+            Some(SourceLocation {
+                line: 4,
+                column: 19,
+                end_line: 4,
+                end_column: 23,
+            })
+        );
+    }
+
+    #[test]
+    fn variant_errors_point_at_the_variant() {
+        let source = r##"#[typeshare]
+#[derive(Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum Priority {
+    Low = 1,
+    High = LOW + 1,
+}
+"##;
+
+        let errors = parse_errors(source);
+        assert_eq!(errors[0].type_name.as_deref(), Some("Priority"));
+        assert_eq!(errors[0].field_name.as_deref(), Some("High"));
+        assert_eq!(errors[0].location.map(|l| (l.line, l.column)), Some((6, 5)));
+
+        let source = r##"#[typeshare]
+#[derive(Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum Priority {
+    Low = 1,
+    Custom(u8),
+}
+"##;
+
+        let errors = parse_errors(source);
+        assert_eq!(errors[0].type_name.as_deref(), Some("Priority"));
+        assert_eq!(errors[0].field_name.as_deref(), Some("Custom"));
+        assert_eq!(errors[0].location.map(|l| (l.line, l.column)), Some((6, 5)));
+    }
+
+    #[test]
+    fn type_errors_point_at_the_type() {
+        let source = r##"#[typeshare]
+#[serde(tag = "type")]
+pub enum Kind {
+    A,
+    B,
+}
+"##;
+
+        let errors = parse_errors(source);
+        assert_eq!(errors[0].type_name.as_deref(), Some("Kind"));
+        assert_eq!(errors[0].field_name, None);
+        assert_eq!(
+            errors[0].location.map(|l| (l.line, l.column)),
+            Some((3, 10))
+        );
+    }
+
+    #[test]
+    fn serialized_as_mismatch_is_a_warning() {
+        let source = r##"#[typeshare(serialized_as = "String")]
+#[serde(into = "u32")]
+pub struct Id(Uuid);
+"##;
+
+        let parsed = parser::parse(
+            source,
+            "default_name".into(),
+            "file_name".into(),
+            "file_path".into(),
+            &[],
+            false,
+            &[],
+        )
+        .unwrap()
+        .unwrap();
+        assert!(parsed.errors.is_empty());
+        assert_eq!(parsed.aliases.len(), 1);
+        assert_eq!(parsed.warnings.len(), 1);
+        assert_eq!(parsed.warnings[0].warning.code(), "W0001");
+        assert_eq!(parsed.warnings[0].type_name.as_deref(), Some("Id"));
+        assert_eq!(
+            parsed.warnings[0].location.map(|l| (l.line, l.column)),
+            Some((3, 12))
+        );
+    }
+
+    #[test]
+    fn serde_from_alone_keeps_the_type() {
+        let source = r##"#[typeshare]
+#[serde(try_from = "String")]
+pub struct Version {
+    major: u32,
+}
+"##;
+
+        let parsed = parser::parse(
+            source,
+            "default_name".into(),
+            "file_name".into(),
+            "file_path".into(),
+            &[],
+            false,
+            &[],
+        )
+        .unwrap()
+        .unwrap();
+        assert!(parsed.aliases.is_empty());
+        assert_eq!(parsed.structs.len(), 1);
+        assert_eq!(parsed.warnings.len(), 1);
+        assert_eq!(parsed.warnings[0].warning.code(), "W0002");
+        assert_eq!(parsed.warnings[0].type_name.as_deref(), Some("Version"));
+    }
+
+    #[test]
+    fn serde_from_and_into_mismatch_is_a_warning() {
+        let source = r##"#[typeshare]
+#[serde(from = "u32", into = "String")]
+pub struct Id(Uuid);
+"##;
+
+        let parsed = parser::parse(
+            source,
+            "default_name".into(),
+            "file_name".into(),
+            "file_path".into(),
+            &[],
+            false,
+            &[],
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(parsed.aliases.len(), 1);
+        assert_eq!(
+            parsed.aliases.first().map(|alias| &alias.r#type),
+            Some(&RustType::Special(SpecialRustType::String))
+        );
+        assert_eq!(parsed.warnings.len(), 1);
+        assert_eq!(parsed.warnings[0].warning.code(), "W0002");
+    }
+
+    #[test]
+    fn attribute_errors_point_at_the_argument() {
+        let source = r##"#[typeshare]
+pub struct User {
+    #[typeshare(skip = "true")]
+    pub name: String,
+}
+"##;
+
+        let errors = parse_errors(source);
+        assert_eq!(errors[0].error.code(), "E0015");
+        assert_eq!(errors[0].field_name.as_deref(), Some("name"));
+        assert_eq!(
+            errors[0].location.map(|l| (l.line, l.column, l.end_column)),
+            Some((3, 17, 30))
+        );
+    }
+
+    #[test]
+    fn errors_have_codes() {
+        let errors = parse_errors("#[typeshare]\npub struct User {\n    pub callback: fn(),\n}\n");
+        assert_eq!(errors[0].error.code(), "E0002");
+    }
+}
+
+mod typeshare_attributes {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn parse_error(source: &str) -> Option<ParseError> {
+        let mut out: Vec<u8> = Vec::new();
+        match process_input(
+            source,
+            &mut TypeScript::default(),
+            &HashMap::new(),
+            &mut out,
+        ) {
+            Err(ProcessInputError::ParseError(error)) => Some(error),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn unknown_argument_is_an_error() {
+        let source = r##"
+#[typeshare]
+pub struct Foo {
+    #[typeshare(serialised_as = "String")]
+    pub id: Uuid,
+}
+"##;
+        assert!(matches!(
+            parse_error(source),
+            Some(ParseError::UnknownAttributeArgument(argument)) if argument == "serialised_as"
+        ));
+    }
+
+    #[test]
+    fn wrong_value_kind_is_an_error() {
+        let source = r##"
+#[typeshare(serialized_as = String)]
+pub struct Foo(Uuid);
+"##;
+        assert!(matches!(
+            parse_error(source),
+            Some(ParseError::InvalidAttributeArgument { argument, .. }) if argument == "serialized_as"
+        ));
+    }
+
+    #[test]
+    fn unknown_language_is_an_error() {
+        let source = r##"
+#[typeshare]
+pub struct Foo {
+    #[typeshare(python(type = "int"))]
+    pub id: u32,
+}
+"##;
+        assert!(matches!(
+            parse_error(source),
+            Some(ParseError::UnsupportedLanguage(language)) if language == "python"
+        ));
+    }
+
+    #[test]
+    fn unknown_language_decorator_is_an_error() {
+        let source = r##"
+#[typeshare]
+pub enum Foo {
+    Bar {
+        #[typeshare(typescript(readonly, optional))]
+        id: u32,
+    },
+}
+"##;
+        assert!(matches!(
+            parse_error(source),
+            Some(ParseError::UnknownAttributeArgument(argument)) if argument == "typescript(optional)"
+        ));
+    }
+
+    #[test]
+    fn known_arguments_are_accepted() {
+        let source = r##"
+#[typeshare(swift = "Equatable", swiftGenericConstraints = "T: Equatable", kotlin = "JvmInline", redacted)]
+pub struct Foo<T> {
+    #[typeshare(serialized_as = "String", typescript(readonly, type = "any"), swift(type = "Int"))]
+    pub id: T,
+    #[typeshare(skip, typo)]
+    pub skipped: u32,
+}
+"##;
+        assert!(parse_error(source).is_none());
+    }
+}
+
+mod flattened_fields {
+    use super::*;
+    use std::collections::{BTreeMap, HashMap};
+    use typeshare_core::language::Kotlin;
+
+    fn parse_crate(source: &str, crate_name: &str) -> parser::ParsedData {
+        parser::parse(
+            source,
+            crate_name.into(),
+            format!("{crate_name}.kt"),
+            "file_path".into(),
+            &[],
+            true,
+            &[],
+        )
+        .unwrap()
+        .unwrap()
+    }
+
+    const SHARED: &str = r##"#[typeshare]
+pub struct Metadata {
+    pub author: UserId,
+}
+
+#[typeshare]
+pub struct UserId(String);
+"##;
+
+    /// Generates Kotlin for the `documents` crate, which depends on `shared`.
+    fn generate_documents(shared: &str, documents: &str) -> std::io::Result<String> {
+        let shared = parse_crate(shared, "shared");
+        let mut documents = parse_crate(documents, "documents");
+        documents.external_structs =
+            BTreeMap::from([(shared.crate_name.clone(), shared.structs.clone())]);
+        let all_types = HashMap::from([
+            (shared.crate_name.clone(), shared.type_names.clone()),
+            (documents.crate_name.clone(), documents.type_names.clone()),
+        ]);
+
+        let mut out = Vec::new();
+        Kotlin {
+            package: "com.example".into(),
+            no_version_header: true,
+            ..Default::default()
+        }
+        .generate_types(&mut out, &all_types, documents)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn structs_of_other_crates_are_inlined() {
+        let out = generate_documents(
+            SHARED,
+            r##"use shared::Metadata;
+
+#[typeshare]
+pub struct Document {
+    pub title: String,
+    #[serde(flatten)]
+    pub metadata: Metadata,
+}
+"##,
+        )
+        .unwrap();
+
+        assert!(out.contains("\tval author: UserId\n"), "{out}");
+        // The type of the inlined field comes from the other crate too
+        assert!(out.contains("import com.example.shared.UserId\n"), "{out}");
+    }
+
+    #[test]
+    fn tuple_variants_wrapping_structs_of_other_crates_are_inlined() {
+        let out = generate_documents(
+            SHARED,
+            r##"use shared::Metadata;
+
+#[typeshare]
+#[serde(tag = "type")]
+pub enum Event {
+    Created(Metadata),
+}
+"##,
+        )
+        .unwrap();
+
+        assert!(out.contains("\t\tval author: UserId\n"), "{out}");
+        assert!(out.contains("import com.example.shared.UserId\n"), "{out}");
+    }
+
+    #[test]
+    fn tuple_variants_wrapping_unknown_types_are_rejected() {
+        let err = generate_documents(
+            SHARED,
+            r##"#[typeshare]
+#[serde(tag = "type")]
+pub enum Event {
+    Created(Opaque),
+}
+"##,
+        )
+        .unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("Flattened type `Opaque` must be a struct known to typeshare"),
+            "{err}"
+        );
+    }
 }
@@ -429,7 +429,8 @@ tests! {
         },
         kotlin,
         scala,
-        typescript
+        typescript,
+        go
     ];
     can_generate_generic_struct: [
         swift {
@@ -438,7 +439,8 @@ tests! {
         },
         kotlin,
         scala,
-        typescript
+        typescript,
+        go
     ];
     can_generate_generic_type_alias: [
         swift {
@@ -446,11 +448,13 @@ tests! {
         },
         kotlin,
         scala,
-        typescript
+        typescript,
+        go
     ];
     can_generate_slice_of_user_type: [swift, kotlin, scala, typescript, go];
     can_generate_readonly_fields: [
-        typescript
+        typescript,
+        go
     ];
     can_generate_simple_enum: [
         swift {
@@ -463,7 +467,8 @@ tests! {
     ];
     can_generate_bare_string_enum: [swift, kotlin, scala, typescript, go ];
     can_generate_double_option_pattern: [
-        typescript
+        typescript,
+        go
     ];
     can_recognize_types_inside_modules: [
         swift, kotlin, scala, typescript, go
@@ -502,6 +507,35 @@ tests! {
         go
     ];
     can_override_types: [swift, kotlin, scala, typescript, go];
+    can_generate_flattened_fields: [swift, kotlin, scala, typescript, go];
+    can_generate_flattened_maps: [swift, kotlin, scala, typescript, go];
+    can_generate_externally_tagged_enum: [swift, kotlin, scala, typescript, go];
+    can_generate_generic_externally_tagged_enum: [swift, kotlin, scala, typescript, go];
+    can_generate_internally_tagged_enum: [swift, kotlin, scala, typescript, go];
+    can_generate_untagged_enum: [swift, kotlin, scala, typescript, go];
+    can_generate_untagged_enum_with_struct_variants: [swift, kotlin, scala, typescript, go];
+    can_generate_generic_untagged_enum: [swift, kotlin, scala, typescript, go];
+    can_generate_tuple_types: [swift, kotlin, scala, typescript, go];
+    can_generate_generic_tuple_types: [swift, kotlin, scala, typescript, go];
+    can_generate_multi_field_tuple_variants: [swift, kotlin, scala, typescript, go];
+    can_generate_collection_types: [swift, kotlin, scala, typescript, go];
+    can_generate_const: [swift, kotlin, scala, typescript, go];
+    can_generate_numeric_enum: [swift, kotlin, scala, typescript, go];
+    can_infer_serialized_type_from_serde: [swift, kotlin, scala, typescript, go];
+    can_handle_serde_aliases: [swift, kotlin, scala, typescript, go];
+    anonymous_struct_with_rename_all_fields: [swift, kotlin, scala, typescript, go];
+    can_generate_struct_with_tag: [swift, kotlin, scala, typescript, go];
+    can_handle_serde_other: [swift, kotlin, scala, typescript, go];
+    can_generate_deprecated_items: [swift, kotlin, scala, typescript, go];
+    can_generate_64_bit_integers: [
+        swift,
+        kotlin,
+        scala,
+        typescript {
+            int64_strategy: typeshare_core::language::Int64Strategy::BigInt,
+        },
+        go
+    ];
 
     /// Structs
     can_generate_simple_struct_with_a_comment: [kotlin, swift, typescript, scala,  go];
@@ -517,10 +551,11 @@ tests! {
     ];
     // TODO: kotlin and typescript don't appear to support this yet
     generates_empty_structs_and_initializers: [swift, kotlin, scala, typescript, go];
-    test_default_decorators: [swift { default_decorators: vec!["Sendable".into(), "Identifiable".into()]}];
-    test_default_generic_constraints: [swift { default_generic_constraints: typeshare_core::language::GenericConstraints::from_config(vec!["Sendable".into(), "Identifiable".into()]) }];
+    test_default_decorators: [swift { default_decorators: vec!["Sendable".into(), "Identifiable".into()]}, go];
+    test_default_generic_constraints: [swift { default_generic_constraints: typeshare_core::language::GenericConstraints::from_config(vec!["Sendable".into(), "Identifiable".into()]) }, go];
     test_i54_u53_type: [swift, kotlin, scala,  typescript, go];
     test_serde_default_struct: [swift, kotlin, scala,  typescript, go];
+    test_serde_skip_serializing_if: [swift, kotlin, scala, typescript, go];
     test_serde_iso8601: [
         swift {
             prefix: String::new(),
@@ -590,16 +625,16 @@ tests! {
 
     /// Other
     use_correct_integer_types: [swift, kotlin, scala,  typescript, go];
-    // Only swift supports generating types with keywords
-    generate_types_with_keywords: [swift];
+    // Only swift and go support generating types with keywords
+    generate_types_with_keywords: [swift, go];
     // TODO: how is this different from generates_empty_structs_and_initializers?
     use_correct_decoded_variable_name: [swift, kotlin, scala,  typescript, go];
     can_handle_unit_type: [swift { codablevoid_constraints: vec!["Equatable".into()]} , kotlin, scala,  typescript, go];
 
     //3 tests for adding decorators to enums and structs
-    const_enum_decorator: [ swift{ prefix: "OP".to_string(), } ];
-    algebraic_enum_decorator: [ swift{ prefix: "OP".to_string(), } ];
-    struct_decorator: [ kotlin, swift{ prefix: "OP".to_string(), } ];
+    const_enum_decorator: [ swift{ prefix: "OP".to_string(), }, go];
+    algebraic_enum_decorator: [ swift{ prefix: "OP".to_string(), }, go];
+    struct_decorator: [ kotlin, swift{ prefix: "OP".to_string(), }, go];
     serialize_field_as: [kotlin, swift, typescript, scala,  go];
     serialize_type_alias: [kotlin, swift, typescript, scala,  go];
     serialize_anonymous_field_as: [kotlin, swift, typescript, scala,  go];
@@ -621,7 +656,7 @@ tests! {
         go
     ];
     can_generate_anonymous_struct_with_skipped_fields: [swift, kotlin, scala, typescript, go];
-    generic_struct_with_constraints_and_decorators: [swift { codablevoid_constraints: vec!["Equatable".into()] }];
+    generic_struct_with_constraints_and_decorators: [swift { codablevoid_constraints: vec!["Equatable".into()] }, go];
     excluded_by_target_os: [ swift, kotlin, scala, typescript, go ] target_os: ["android", "macos"];
     // excluded_by_target_os_full_module: [swift] target_os: "ios";
 }